@@ -1,8 +1,17 @@
 //! Basic math functions
 
+#[cfg(feature = "fixed")]
 use fixed::types::{I16F48, I18F14, I1F15, I1F31, I1F7, I34F30};
 
-use crate::check_length;
+use core::mem::MaybeUninit;
+
+#[cfg(feature = "fixed")]
+use crate::support::{
+    q15_as_bits, q15_as_bits_mut, q31_as_bits, q31_as_bits_mut, q7_as_bits, q7_as_bits_mut,
+};
+use crate::{assume_init, check_length, Error, Result};
+
+pub mod raw;
 
 /// Calculates the absolute value of multiple values
 ///
@@ -27,11 +36,9 @@ pub fn abs_f32(src: &[f32], dst: &mut [f32]) {
 /// # Panics
 ///
 /// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn abs_q31(src: &[I1F31], dst: &mut [I1F31]) {
-    let length = check_length((src.len(), dst.len()));
-    unsafe {
-        cmsis_dsp_sys::arm_abs_q31(src.as_ptr() as *const _, dst.as_mut_ptr() as *mut _, length);
-    }
+    raw::abs_q31(q31_as_bits(src), q31_as_bits_mut(dst))
 }
 
 /// Calculates the absolute value of multiple values
@@ -42,11 +49,9 @@ pub fn abs_q31(src: &[I1F31], dst: &mut [I1F31]) {
 /// # Panics
 ///
 /// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn abs_q15(src: &[I1F15], dst: &mut [I1F15]) {
-    let length = check_length((src.len(), dst.len()));
-    unsafe {
-        cmsis_dsp_sys::arm_abs_q15(src.as_ptr() as *const _, dst.as_mut_ptr() as *mut _, length);
-    }
+    raw::abs_q15(q15_as_bits(src), q15_as_bits_mut(dst))
 }
 
 /// Calculates the absolute value of multiple values
@@ -57,11 +62,9 @@ pub fn abs_q15(src: &[I1F15], dst: &mut [I1F15]) {
 /// # Panics
 ///
 /// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn abs_q7(src: &[I1F7], dst: &mut [I1F7]) {
-    let length = check_length((src.len(), dst.len()));
-    unsafe {
-        cmsis_dsp_sys::arm_abs_q7(src.as_ptr() as *const _, dst.as_mut_ptr() as *mut _, length);
-    }
+    raw::abs_q7(q7_as_bits(src), q7_as_bits_mut(dst))
 }
 
 /// Calculates the absolute value of multiple values in place
@@ -82,36 +85,27 @@ pub fn abs_in_place_f32(values: &mut [f32]) {
 ///
 /// This is functionally equivalent to performing `values[i] = abs(values[i])` for all values of i
 /// in range.
+#[cfg(feature = "fixed")]
 pub fn abs_in_place_q31(values: &mut [I1F31]) {
-    let length = check_length(values.len());
-    unsafe {
-        let ptr = values.as_mut_ptr();
-        cmsis_dsp_sys::arm_abs_q31(ptr as *const _, ptr as *mut _, length);
-    }
+    raw::abs_in_place_q31(q31_as_bits_mut(values))
 }
 
 /// Calculates the absolute value of multiple values in place
 ///
 /// This is functionally equivalent to performing `values[i] = abs(values[i])` for all values of i
 /// in range.
+#[cfg(feature = "fixed")]
 pub fn abs_in_place_q15(values: &mut [I1F15]) {
-    let length = check_length(values.len());
-    unsafe {
-        let ptr = values.as_mut_ptr();
-        cmsis_dsp_sys::arm_abs_q15(ptr as *const _, ptr as *mut _, length);
-    }
+    raw::abs_in_place_q15(q15_as_bits_mut(values))
 }
 
 /// Calculates the absolute value of multiple values in place
 ///
 /// This is functionally equivalent to performing `values[i] = abs(values[i])` for all values of i
 /// in range.
+#[cfg(feature = "fixed")]
 pub fn abs_in_place_q7(values: &mut [I1F7]) {
-    let length = check_length(values.len());
-    unsafe {
-        let ptr = values.as_mut_ptr();
-        cmsis_dsp_sys::arm_abs_q7(ptr as *const _, ptr as *mut _, length);
-    }
+    raw::abs_in_place_q7(q7_as_bits_mut(values))
 }
 
 /// Adds multiple values
@@ -137,16 +131,9 @@ pub fn add_f32(src1: &[f32], src2: &[f32], dst: &mut [f32]) {
 /// # Panics
 ///
 /// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn add_q31(src1: &[I1F31], src2: &[I1F31], dst: &mut [I1F31]) {
-    let length = check_length((src1.len(), src2.len(), dst.len()));
-    unsafe {
-        cmsis_dsp_sys::arm_add_q31(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            dst.as_mut_ptr() as *mut _,
-            length,
-        );
-    }
+    raw::add_q31(q31_as_bits(src1), q31_as_bits(src2), q31_as_bits_mut(dst))
 }
 
 /// Adds multiple values
@@ -157,16 +144,9 @@ pub fn add_q31(src1: &[I1F31], src2: &[I1F31], dst: &mut [I1F31]) {
 /// # Panics
 ///
 /// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn add_q15(src1: &[I1F15], src2: &[I1F15], dst: &mut [I1F15]) {
-    let length = check_length((src1.len(), src2.len(), dst.len()));
-    unsafe {
-        cmsis_dsp_sys::arm_add_q15(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            dst.as_mut_ptr() as *mut _,
-            length,
-        );
-    }
+    raw::add_q15(q15_as_bits(src1), q15_as_bits(src2), q15_as_bits_mut(dst))
 }
 
 /// Adds multiple values
@@ -177,18 +157,65 @@ pub fn add_q15(src1: &[I1F15], src2: &[I1F15], dst: &mut [I1F15]) {
 /// # Panics
 ///
 /// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn add_q7(src1: &[I1F7], src2: &[I1F7], dst: &mut [I1F7]) {
+    raw::add_q7(q7_as_bits(src1), q7_as_bits(src2), q7_as_bits_mut(dst))
+}
+
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn subtract_f32(src1: &[f32], src2: &[f32], dst: &mut [f32]) {
     let length = check_length((src1.len(), src2.len(), dst.len()));
     unsafe {
-        cmsis_dsp_sys::arm_add_q7(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            dst.as_mut_ptr() as *mut _,
-            length,
-        );
+        cmsis_dsp_sys::arm_sub_f32(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
     }
 }
 
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn subtract_q31(src1: &[I1F31], src2: &[I1F31], dst: &mut [I1F31]) {
+    raw::subtract_q31(q31_as_bits(src1), q31_as_bits(src2), q31_as_bits_mut(dst))
+}
+
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn subtract_q15(src1: &[I1F15], src2: &[I1F15], dst: &mut [I1F15]) {
+    raw::subtract_q15(q15_as_bits(src1), q15_as_bits(src2), q15_as_bits_mut(dst))
+}
+
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn subtract_q7(src1: &[I1F7], src2: &[I1F7], dst: &mut [I1F7]) {
+    raw::subtract_q7(q7_as_bits(src1), q7_as_bits(src2), q7_as_bits_mut(dst))
+}
+
 /// Calculates the dot product of two vectors
 ///
 /// The returned value is the sum of `src1[i] * src2[i]` over all values of i
@@ -214,18 +241,9 @@ pub fn dot_product_f32(src1: &[f32], src2: &[f32]) -> f32 {
 /// # Panics
 ///
 /// This function panics if src1 and src2 do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn dot_product_q31(src1: &[I1F31], src2: &[I1F31]) -> I16F48 {
-    let length = check_length((src1.len(), src2.len()));
-    let mut result = I16F48::from_bits(0);
-    unsafe {
-        cmsis_dsp_sys::arm_dot_prod_q31(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            length,
-            &mut result as *mut _ as *mut _,
-        );
-    }
-    result
+    I16F48::from_bits(raw::dot_product_q31(q31_as_bits(src1), q31_as_bits(src2)))
 }
 
 /// Calculates the dot product of two vectors
@@ -236,18 +254,9 @@ pub fn dot_product_q31(src1: &[I1F31], src2: &[I1F31]) -> I16F48 {
 /// # Panics
 ///
 /// This function panics if src1 and src2 do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn dot_product_q15(src1: &[I1F15], src2: &[I1F15]) -> I34F30 {
-    let length = check_length((src1.len(), src2.len()));
-    let mut result = I34F30::from_bits(0);
-    unsafe {
-        cmsis_dsp_sys::arm_dot_prod_q15(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            length,
-            &mut result as *mut _ as *mut _,
-        );
-    }
-    result
+    I34F30::from_bits(raw::dot_product_q15(q15_as_bits(src1), q15_as_bits(src2)))
 }
 
 /// Calculates the dot product of two vectors
@@ -258,18 +267,9 @@ pub fn dot_product_q15(src1: &[I1F15], src2: &[I1F15]) -> I34F30 {
 /// # Panics
 ///
 /// This function panics if src1 and src2 do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn dot_product_q7(src1: &[I1F7], src2: &[I1F7]) -> I18F14 {
-    let length = check_length((src1.len(), src2.len()));
-    let mut result = I18F14::from_bits(0);
-    unsafe {
-        cmsis_dsp_sys::arm_dot_prod_q7(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            length,
-            &mut result as *mut _ as *mut _,
-        );
-    }
-    result
+    I18F14::from_bits(raw::dot_product_q7(q7_as_bits(src1), q7_as_bits(src2)))
 }
 
 /// Multiplies multiple values
@@ -295,16 +295,9 @@ pub fn multiply_f32(src1: &[f32], src2: &[f32], dst: &mut [f32]) {
 /// # Panics
 ///
 /// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn multiply_q31(src1: &[I1F31], src2: &[I1F31], dst: &mut [I1F31]) {
-    let length = check_length((src1.len(), src2.len(), dst.len()));
-    unsafe {
-        cmsis_dsp_sys::arm_mult_q31(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            dst.as_mut_ptr() as *mut _,
-            length,
-        );
-    }
+    raw::multiply_q31(q31_as_bits(src1), q31_as_bits(src2), q31_as_bits_mut(dst))
 }
 
 /// Multiplies multiple values
@@ -315,16 +308,9 @@ pub fn multiply_q31(src1: &[I1F31], src2: &[I1F31], dst: &mut [I1F31]) {
 /// # Panics
 ///
 /// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn multiply_q15(src1: &[I1F15], src2: &[I1F15], dst: &mut [I1F15]) {
-    let length = check_length((src1.len(), src2.len(), dst.len()));
-    unsafe {
-        cmsis_dsp_sys::arm_mult_q15(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            dst.as_mut_ptr() as *mut _,
-            length,
-        );
-    }
+    raw::multiply_q15(q15_as_bits(src1), q15_as_bits(src2), q15_as_bits_mut(dst))
 }
 
 /// Multiplies multiple values
@@ -335,14 +321,1477 @@ pub fn multiply_q15(src1: &[I1F15], src2: &[I1F15], dst: &mut [I1F15]) {
 /// # Panics
 ///
 /// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "fixed")]
 pub fn multiply_q7(src1: &[I1F7], src2: &[I1F7], dst: &mut [I1F7]) {
-    let length = check_length((src1.len(), src2.len(), dst.len()));
+    raw::multiply_q7(q7_as_bits(src1), q7_as_bits(src2), q7_as_bits_mut(dst))
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn negate_f32(src: &[f32], dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
     unsafe {
-        cmsis_dsp_sys::arm_mult_q7(
-            src1.as_ptr() as *const _,
-            src2.as_ptr() as *const _,
-            dst.as_mut_ptr() as *mut _,
-            length,
-        );
+        cmsis_dsp_sys::arm_negate_f32(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range. This function saturates, so the negation of -1 is the largest positive value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn negate_q31(src: &[I1F31], dst: &mut [I1F31]) {
+    raw::negate_q31(q31_as_bits(src), q31_as_bits_mut(dst))
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range. This function saturates, so the negation of -1 is the largest positive value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn negate_q15(src: &[I1F15], dst: &mut [I1F15]) {
+    raw::negate_q15(q15_as_bits(src), q15_as_bits_mut(dst))
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range. This function saturates, so the negation of -1 is the largest positive value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn negate_q7(src: &[I1F7], dst: &mut [I1F7]) {
+    raw::negate_q7(q7_as_bits(src), q7_as_bits_mut(dst))
+}
+
+/// Negates multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = -values[i]` for all values of i
+/// in range.
+pub fn negate_in_place_f32(values: &mut [f32]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_negate_f32(ptr, ptr, length);
+    }
+}
+
+/// Negates multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = -values[i]` for all values of i
+/// in range. This function saturates.
+#[cfg(feature = "fixed")]
+pub fn negate_in_place_q31(values: &mut [I1F31]) {
+    raw::negate_in_place_q31(q31_as_bits_mut(values))
+}
+
+/// Negates multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = -values[i]` for all values of i
+/// in range. This function saturates.
+#[cfg(feature = "fixed")]
+pub fn negate_in_place_q15(values: &mut [I1F15]) {
+    raw::negate_in_place_q15(q15_as_bits_mut(values))
+}
+
+/// Negates multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = -values[i]` for all values of i
+/// in range. This function saturates.
+#[cfg(feature = "fixed")]
+pub fn negate_in_place_q7(values: &mut [I1F7]) {
+    raw::negate_in_place_q7(q7_as_bits_mut(values))
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn offset_f32(src: &[f32], offset: f32, dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_offset_f32(src.as_ptr(), offset, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn offset_q31(src: &[I1F31], offset: I1F31, dst: &mut [I1F31]) {
+    raw::offset_q31(q31_as_bits(src), offset.to_bits(), q31_as_bits_mut(dst))
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn offset_q15(src: &[I1F15], offset: I1F15, dst: &mut [I1F15]) {
+    raw::offset_q15(q15_as_bits(src), offset.to_bits(), q15_as_bits_mut(dst))
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn offset_q7(src: &[I1F7], offset: I1F7, dst: &mut [I1F7]) {
+    raw::offset_q7(q7_as_bits(src), offset.to_bits(), q7_as_bits_mut(dst))
+}
+
+/// Multiplies multiple values by a constant
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] * scale` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn scale_f32(src: &[f32], scale: f32, dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_scale_f32(src.as_ptr(), scale, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Multiplies multiple values by a constant
+///
+/// The scale factor is `scale_fract * 2^shift`, which allows gains greater than 1. This is
+/// similar to performing `dst[i] = src[i] * scale_fract * 2^shift` for all values of i in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if shift is less than -32 or greater than 30.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn scale_q31(src: &[I1F31], scale_fract: I1F31, shift: i8, dst: &mut [I1F31]) -> Result<()> {
+    raw::scale_q31(
+        q31_as_bits(src),
+        scale_fract.to_bits(),
+        shift,
+        q31_as_bits_mut(dst),
+    )
+}
+
+/// Multiplies multiple values by a constant
+///
+/// The scale factor is `scale_fract * 2^shift`, which allows gains greater than 1. This is
+/// similar to performing `dst[i] = src[i] * scale_fract * 2^shift` for all values of i in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if shift is less than -16 or greater than 15.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn scale_q15(src: &[I1F15], scale_fract: I1F15, shift: i8, dst: &mut [I1F15]) -> Result<()> {
+    raw::scale_q15(
+        q15_as_bits(src),
+        scale_fract.to_bits(),
+        shift,
+        q15_as_bits_mut(dst),
+    )
+}
+
+/// Multiplies multiple values by a constant
+///
+/// The scale factor is `scale_fract * 2^shift`, which allows gains greater than 1. This is
+/// similar to performing `dst[i] = src[i] * scale_fract * 2^shift` for all values of i in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if shift is less than -24 or greater than 7.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn scale_q7(src: &[I1F7], scale_fract: I1F7, shift: i8, dst: &mut [I1F7]) -> Result<()> {
+    raw::scale_q7(
+        q7_as_bits(src),
+        scale_fract.to_bits(),
+        shift,
+        q7_as_bits_mut(dst),
+    )
+}
+
+/// Shifts multiple values left or right by a number of bits
+///
+/// A positive shift_bits shifts left (multiplies by `2^shift_bits`), and a negative shift_bits
+/// shifts right (divides by `2^-shift_bits`). Left shifts saturate on overflow. Right shifts
+/// are arithmetic, so they round toward negative infinity.
+///
+/// This function returns an error if shift_bits is less than -31 or greater than 31.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn shift_q31(src: &[I1F31], shift_bits: i8, dst: &mut [I1F31]) -> Result<()> {
+    raw::shift_q31(q31_as_bits(src), shift_bits, q31_as_bits_mut(dst))
+}
+
+/// Shifts multiple values left or right by a number of bits
+///
+/// A positive shift_bits shifts left (multiplies by `2^shift_bits`), and a negative shift_bits
+/// shifts right (divides by `2^-shift_bits`). Left shifts saturate on overflow. Right shifts
+/// are arithmetic, so they round toward negative infinity.
+///
+/// This function returns an error if shift_bits is less than -15 or greater than 15.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn shift_q15(src: &[I1F15], shift_bits: i8, dst: &mut [I1F15]) -> Result<()> {
+    raw::shift_q15(q15_as_bits(src), shift_bits, q15_as_bits_mut(dst))
+}
+
+/// Shifts multiple values left or right by a number of bits
+///
+/// A positive shift_bits shifts left (multiplies by `2^shift_bits`), and a negative shift_bits
+/// shifts right (divides by `2^-shift_bits`). Left shifts saturate on overflow. Right shifts
+/// are arithmetic, so they round toward negative infinity.
+///
+/// This function returns an error if shift_bits is less than -7 or greater than 7.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn shift_q7(src: &[I1F7], shift_bits: i8, dst: &mut [I1F7]) -> Result<()> {
+    raw::shift_q7(q7_as_bits(src), shift_bits, q7_as_bits_mut(dst))
+}
+
+/// Checks that a shift amount is in a range that the CMSIS-DSP functions handle without
+/// shifting an integer by more than its width
+fn check_shift(shift: i8, min: i8, max: i8) -> Result<()> {
+    if (min..=max).contains(&shift) {
+        Ok(())
+    } else {
+        Err(Error::Argument)
+    }
+}
+
+/// Limits multiple values to a range
+///
+/// This is functionally equivalent to performing `dst[i] = src[i].max(low).min(high)` for all
+/// values of i in range. NaN values are not changed.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, if low is greater than
+/// high, or if low or high is NaN.
+pub fn clip_f32(src: &[f32], low: f32, high: f32, dst: &mut [f32]) {
+    clip_values(src, low, high, dst);
+}
+
+/// Limits multiple values to a range
+///
+/// This is functionally equivalent to performing `dst[i] = src[i].max(low).min(high)` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, or if low is greater than
+/// high.
+#[cfg(feature = "fixed")]
+pub fn clip_q31(src: &[I1F31], low: I1F31, high: I1F31, dst: &mut [I1F31]) {
+    raw::clip_q31(
+        q31_as_bits(src),
+        low.to_bits(),
+        high.to_bits(),
+        q31_as_bits_mut(dst),
+    )
+}
+
+/// Limits multiple values to a range
+///
+/// This is functionally equivalent to performing `dst[i] = src[i].max(low).min(high)` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, or if low is greater than
+/// high.
+#[cfg(feature = "fixed")]
+pub fn clip_q15(src: &[I1F15], low: I1F15, high: I1F15, dst: &mut [I1F15]) {
+    raw::clip_q15(
+        q15_as_bits(src),
+        low.to_bits(),
+        high.to_bits(),
+        q15_as_bits_mut(dst),
+    )
+}
+
+/// Limits multiple values to a range
+///
+/// This is functionally equivalent to performing `dst[i] = src[i].max(low).min(high)` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, or if low is greater than
+/// high.
+#[cfg(feature = "fixed")]
+pub fn clip_q7(src: &[I1F7], low: I1F7, high: I1F7, dst: &mut [I1F7]) {
+    raw::clip_q7(
+        q7_as_bits(src),
+        low.to_bits(),
+        high.to_bits(),
+        q7_as_bits_mut(dst),
+    )
+}
+
+/// Limits values to the range from low to high
+fn clip_values<T: PartialOrd + Copy>(src: &[T], low: T, high: T, dst: &mut [T]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    assert!(low <= high, "Clip lower limit greater than upper limit");
+    for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+        *dst = if src < low {
+            low
+        } else if src > high {
+            high
+        } else {
+            src
+        };
+    }
+}
+
+/// Calculates the bitwise AND of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] & src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn and_u32(src1: &[u32], src2: &[u32], dst: &mut [u32]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_and_u32(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise AND of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] & src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn and_u16(src1: &[u16], src2: &[u16], dst: &mut [u16]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_and_u16(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise AND of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] & src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn and_u8(src1: &[u8], src2: &[u8], dst: &mut [u8]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_and_u8(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise OR of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] | src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn or_u32(src1: &[u32], src2: &[u32], dst: &mut [u32]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_or_u32(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise OR of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] | src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn or_u16(src1: &[u16], src2: &[u16], dst: &mut [u16]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_or_u16(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise OR of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] | src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn or_u8(src1: &[u8], src2: &[u8], dst: &mut [u8]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_or_u8(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise exclusive OR of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] ^ src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn xor_u32(src1: &[u32], src2: &[u32], dst: &mut [u32]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_xor_u32(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise exclusive OR of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] ^ src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn xor_u16(src1: &[u16], src2: &[u16], dst: &mut [u16]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_xor_u16(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise exclusive OR of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] ^ src2[i]` for all values of
+/// i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn xor_u8(src1: &[u8], src2: &[u8], dst: &mut [u8]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_xor_u8(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise NOT of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = !src[i]` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn not_u32(src: &[u32], dst: &mut [u32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_not_u32(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise NOT of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = !src[i]` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn not_u16(src: &[u16], dst: &mut [u16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_not_u16(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the bitwise NOT of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = !src[i]` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn not_u8(src: &[u8], dst: &mut [u8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_not_u8(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Multiplies multiple values by a constant and adds the results to an accumulator
+///
+/// This is functionally equivalent to performing `acc[i] += src[i] * scale` for all values of i
+/// in range. It can be used for weighted sums across several blocks, such as spectral
+/// averaging or mixing, without a temporary buffer.
+///
+/// # Panics
+///
+/// This function panics if src and acc do not have the same length.
+pub fn scale_accumulate_f32(src: &[f32], scale: f32, acc: &mut [f32]) {
+    let _: usize = check_length((src.len(), acc.len()));
+    for (acc, &src) in acc.iter_mut().zip(src.iter()) {
+        *acc += src * scale;
+    }
+}
+
+/// Calculates the absolute value of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = abs(src[i])` for all values of i in
+/// range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn abs_f64(src: &[f64], dst: &mut [f64]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+        *dst = if src < 0.0 { -src } else { src };
+    }
+}
+
+/// Adds multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] + src2[i]` for all values of i
+/// in range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn add_f64(src1: &[f64], src2: &[f64], dst: &mut [f64]) {
+    let _: usize = check_length((src1.len(), src2.len(), dst.len()));
+    for ((dst, &src1), &src2) in dst.iter_mut().zip(src1.iter()).zip(src2.iter()) {
+        *dst = src1 + src2;
+    }
+}
+
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn subtract_f64(src1: &[f64], src2: &[f64], dst: &mut [f64]) {
+    let _: usize = check_length((src1.len(), src2.len(), dst.len()));
+    for ((dst, &src1), &src2) in dst.iter_mut().zip(src1.iter()).zip(src2.iter()) {
+        *dst = src1 - src2;
+    }
+}
+
+/// Multiplies multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] * src2[i]` for all values of i
+/// in range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn multiply_f64(src1: &[f64], src2: &[f64], dst: &mut [f64]) {
+    let _: usize = check_length((src1.len(), src2.len(), dst.len()));
+    for ((dst, &src1), &src2) in dst.iter_mut().zip(src1.iter()).zip(src2.iter()) {
+        *dst = src1 * src2;
+    }
+}
+
+/// Calculates the dot product of two vectors
+///
+/// The returned value is the sum of `src1[i] * src2[i]` over all values of i
+/// in range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src1 and src2 do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn dot_product_f64(src1: &[f64], src2: &[f64]) -> f64 {
+    let _: usize = check_length((src1.len(), src2.len()));
+    src1.iter().zip(src2.iter()).map(|(&a, &b)| a * b).sum()
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn negate_f64(src: &[f64], dst: &mut [f64]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+        *dst = -src;
+    }
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn offset_f64(src: &[f64], offset: f64, dst: &mut [f64]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src + offset;
+    }
+}
+
+/// Multiplies multiple values by a constant
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] * scale` for all values of i
+/// in range.
+///
+/// This function is only available when the `double-precision-fpu` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "double-precision-fpu")]
+pub fn scale_f64(src: &[f64], scale: f64, dst: &mut [f64]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src * scale;
+    }
+}
+
+/// Adds multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + other[i]` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn add_in_place_f32(values: &mut [f32], other: &[f32]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_add_f32(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Adds multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn add_in_place_q31(values: &mut [I1F31], other: &[I1F31]) {
+    raw::add_in_place_q31(q31_as_bits_mut(values), q31_as_bits(other))
+}
+
+/// Adds multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn add_in_place_q15(values: &mut [I1F15], other: &[I1F15]) {
+    raw::add_in_place_q15(q15_as_bits_mut(values), q15_as_bits(other))
+}
+
+/// Adds multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn add_in_place_q7(values: &mut [I1F7], other: &[I1F7]) {
+    raw::add_in_place_q7(q7_as_bits_mut(values), q7_as_bits(other))
+}
+
+/// Subtracts multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] - other[i]` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn subtract_in_place_f32(values: &mut [f32], other: &[f32]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_sub_f32(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Subtracts multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] - other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn subtract_in_place_q31(values: &mut [I1F31], other: &[I1F31]) {
+    raw::subtract_in_place_q31(q31_as_bits_mut(values), q31_as_bits(other))
+}
+
+/// Subtracts multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] - other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn subtract_in_place_q15(values: &mut [I1F15], other: &[I1F15]) {
+    raw::subtract_in_place_q15(q15_as_bits_mut(values), q15_as_bits(other))
+}
+
+/// Subtracts multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] - other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn subtract_in_place_q7(values: &mut [I1F7], other: &[I1F7]) {
+    raw::subtract_in_place_q7(q7_as_bits_mut(values), q7_as_bits(other))
+}
+
+/// Multiplies multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * other[i]` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn multiply_in_place_f32(values: &mut [f32], other: &[f32]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_mult_f32(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Multiplies multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn multiply_in_place_q31(values: &mut [I1F31], other: &[I1F31]) {
+    raw::multiply_in_place_q31(q31_as_bits_mut(values), q31_as_bits(other))
+}
+
+/// Multiplies multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn multiply_in_place_q15(values: &mut [I1F15], other: &[I1F15]) {
+    raw::multiply_in_place_q15(q15_as_bits_mut(values), q15_as_bits(other))
+}
+
+/// Multiplies multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn multiply_in_place_q7(values: &mut [I1F7], other: &[I1F7]) {
+    raw::multiply_in_place_q7(q7_as_bits_mut(values), q7_as_bits(other))
+}
+
+/// Multiplies multiple values by a constant in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * scale` for all values
+/// of i in range.
+pub fn scale_in_place_f32(values: &mut [f32], scale: f32) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_scale_f32(ptr, scale, ptr, length);
+    }
+}
+
+/// Multiplies multiple values by a constant in place
+///
+/// The scale factor is `scale_fract * 2^shift`, as in `scale_q31`. This function saturates on
+/// overflow.
+///
+/// This function returns an error if shift is less than -32 or greater than 30.
+#[cfg(feature = "fixed")]
+pub fn scale_in_place_q31(values: &mut [I1F31], scale_fract: I1F31, shift: i8) -> Result<()> {
+    raw::scale_in_place_q31(q31_as_bits_mut(values), scale_fract.to_bits(), shift)
+}
+
+/// Multiplies multiple values by a constant in place
+///
+/// The scale factor is `scale_fract * 2^shift`, as in `scale_q15`. This function saturates on
+/// overflow.
+///
+/// This function returns an error if shift is less than -16 or greater than 15.
+#[cfg(feature = "fixed")]
+pub fn scale_in_place_q15(values: &mut [I1F15], scale_fract: I1F15, shift: i8) -> Result<()> {
+    raw::scale_in_place_q15(q15_as_bits_mut(values), scale_fract.to_bits(), shift)
+}
+
+/// Multiplies multiple values by a constant in place
+///
+/// The scale factor is `scale_fract * 2^shift`, as in `scale_q7`. This function saturates on
+/// overflow.
+///
+/// This function returns an error if shift is less than -24 or greater than 7.
+#[cfg(feature = "fixed")]
+pub fn scale_in_place_q7(values: &mut [I1F7], scale_fract: I1F7, shift: i8) -> Result<()> {
+    raw::scale_in_place_q7(q7_as_bits_mut(values), scale_fract.to_bits(), shift)
+}
+
+/// Adds a constant offset to multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + offset` for all values
+/// of i in range.
+pub fn offset_in_place_f32(values: &mut [f32], offset: f32) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_offset_f32(ptr, offset, ptr, length);
+    }
+}
+
+/// Adds a constant offset to multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + offset` for all values
+/// of i in range. This function saturates on overflow.
+#[cfg(feature = "fixed")]
+pub fn offset_in_place_q31(values: &mut [I1F31], offset: I1F31) {
+    raw::offset_in_place_q31(q31_as_bits_mut(values), offset.to_bits())
+}
+
+/// Adds a constant offset to multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + offset` for all values
+/// of i in range. This function saturates on overflow.
+#[cfg(feature = "fixed")]
+pub fn offset_in_place_q15(values: &mut [I1F15], offset: I1F15) {
+    raw::offset_in_place_q15(q15_as_bits_mut(values), offset.to_bits())
+}
+
+/// Adds a constant offset to multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + offset` for all values
+/// of i in range. This function saturates on overflow.
+#[cfg(feature = "fixed")]
+pub fn offset_in_place_q7(values: &mut [I1F7], offset: I1F7) {
+    raw::offset_in_place_q7(q7_as_bits_mut(values), offset.to_bits())
+}
+
+/// Limits multiple values to a range in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i].max(low).min(high)` for
+/// all values of i in range. NaN values are not changed.
+///
+/// # Panics
+///
+/// This function panics if low is greater than high, or if low or high is NaN.
+pub fn clip_in_place_f32(values: &mut [f32], low: f32, high: f32) {
+    assert!(low <= high, "Clip lower limit greater than upper limit");
+    for value in values.iter_mut() {
+        if *value < low {
+            *value = low;
+        } else if *value > high {
+            *value = high;
+        }
+    }
+}
+
+/// Limits multiple values to a range in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i].max(low).min(high)` for
+/// all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if low is greater than high.
+#[cfg(feature = "fixed")]
+pub fn clip_in_place_q31(values: &mut [I1F31], low: I1F31, high: I1F31) {
+    raw::clip_in_place_q31(q31_as_bits_mut(values), low.to_bits(), high.to_bits())
+}
+
+/// Limits multiple values to a range in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i].max(low).min(high)` for
+/// all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if low is greater than high.
+#[cfg(feature = "fixed")]
+pub fn clip_in_place_q15(values: &mut [I1F15], low: I1F15, high: I1F15) {
+    raw::clip_in_place_q15(q15_as_bits_mut(values), low.to_bits(), high.to_bits())
+}
+
+/// Limits multiple values to a range in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i].max(low).min(high)` for
+/// all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if low is greater than high.
+#[cfg(feature = "fixed")]
+pub fn clip_in_place_q7(values: &mut [I1F7], low: I1F7, high: I1F7) {
+    raw::clip_in_place_q7(q7_as_bits_mut(values), low.to_bits(), high.to_bits())
+}
+
+/// Calculates the absolute value of multiple values, writing the results into an uninitialized
+/// buffer
+///
+/// This is the same as `abs_f32`, but the contents of dst do not need to be initialized. The
+/// returned slice refers to dst, which holds the results.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn abs_f32_uninit<'d>(src: &[f32], dst: &'d mut [MaybeUninit<f32>]) -> &'d mut [f32] {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_abs_f32(src.as_ptr(), dst.as_mut_ptr() as *mut f32, length);
+        assume_init(dst)
+    }
+}
+
+/// Adds multiple values, writing the results into an uninitialized buffer
+///
+/// This is the same as `add_f32`, but the contents of dst do not need to be initialized. The
+/// returned slice refers to dst, which holds the results.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn add_f32_uninit<'d>(
+    src1: &[f32],
+    src2: &[f32],
+    dst: &'d mut [MaybeUninit<f32>],
+) -> &'d mut [f32] {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_add_f32(
+            src1.as_ptr(),
+            src2.as_ptr(),
+            dst.as_mut_ptr() as *mut f32,
+            length,
+        );
+        assume_init(dst)
+    }
+}
+
+/// Subtracts multiple values, writing the results into an uninitialized buffer
+///
+/// This is the same as `subtract_f32`, but the contents of dst do not need to be initialized. The
+/// returned slice refers to dst, which holds the results.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn subtract_f32_uninit<'d>(
+    src1: &[f32],
+    src2: &[f32],
+    dst: &'d mut [MaybeUninit<f32>],
+) -> &'d mut [f32] {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_sub_f32(
+            src1.as_ptr(),
+            src2.as_ptr(),
+            dst.as_mut_ptr() as *mut f32,
+            length,
+        );
+        assume_init(dst)
+    }
+}
+
+/// Multiplies multiple values, writing the results into an uninitialized buffer
+///
+/// This is the same as `multiply_f32`, but the contents of dst do not need to be initialized. The
+/// returned slice refers to dst, which holds the results.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn multiply_f32_uninit<'d>(
+    src1: &[f32],
+    src2: &[f32],
+    dst: &'d mut [MaybeUninit<f32>],
+) -> &'d mut [f32] {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_mult_f32(
+            src1.as_ptr(),
+            src2.as_ptr(),
+            dst.as_mut_ptr() as *mut f32,
+            length,
+        );
+        assume_init(dst)
+    }
+}
+
+/// Negates multiple values, writing the results into an uninitialized buffer
+///
+/// This is the same as `negate_f32`, but the contents of dst do not need to be initialized. The
+/// returned slice refers to dst, which holds the results.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn negate_f32_uninit<'d>(src: &[f32], dst: &'d mut [MaybeUninit<f32>]) -> &'d mut [f32] {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_negate_f32(src.as_ptr(), dst.as_mut_ptr() as *mut f32, length);
+        assume_init(dst)
+    }
+}
+
+/// Adds a constant offset to multiple values, writing the results into an uninitialized buffer
+///
+/// This is the same as `offset_f32`, but the contents of dst do not need to be initialized. The
+/// returned slice refers to dst, which holds the results.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn offset_f32_uninit<'d>(
+    src: &[f32],
+    offset: f32,
+    dst: &'d mut [MaybeUninit<f32>],
+) -> &'d mut [f32] {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_offset_f32(src.as_ptr(), offset, dst.as_mut_ptr() as *mut f32, length);
+        assume_init(dst)
+    }
+}
+
+/// Multiplies multiple values by a constant, writing the results into an uninitialized buffer
+///
+/// This is the same as `scale_f32`, but the contents of dst do not need to be initialized. The
+/// returned slice refers to dst, which holds the results.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn scale_f32_uninit<'d>(
+    src: &[f32],
+    scale: f32,
+    dst: &'d mut [MaybeUninit<f32>],
+) -> &'d mut [f32] {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_scale_f32(src.as_ptr(), scale, dst.as_mut_ptr() as *mut f32, length);
+        assume_init(dst)
+    }
+}
+
+/// A type of value that the generic basic math functions can operate on
+///
+/// The generic functions such as [`add`] and [`dot_product`] call the CMSIS-DSP function for
+/// their element type, so generic code can work with floating-point and fixed-point values. This
+/// trait is implemented for `f32` and, when the `fixed` feature is enabled, for `I1F31`, `I1F15`,
+/// and `I1F7`. It is sealed.
+pub trait DspElement: Copy + sealed::Sealed {
+    /// The type of a dot product of two vectors of this type
+    type DotProduct;
+
+    /// Calculates the absolute value of multiple values
+    fn abs(src: &[Self], dst: &mut [Self]);
+    /// Calculates the absolute value of multiple values in place
+    fn abs_in_place(values: &mut [Self]);
+    /// Adds multiple values
+    fn add(src1: &[Self], src2: &[Self], dst: &mut [Self]);
+    /// Subtracts multiple values
+    fn subtract(src1: &[Self], src2: &[Self], dst: &mut [Self]);
+    /// Multiplies multiple values
+    fn multiply(src1: &[Self], src2: &[Self], dst: &mut [Self]);
+    /// Calculates the dot product of two vectors
+    fn dot_product(src1: &[Self], src2: &[Self]) -> Self::DotProduct;
+    /// Negates multiple values
+    fn negate(src: &[Self], dst: &mut [Self]);
+    /// Adds a constant offset to multiple values
+    fn offset(src: &[Self], offset: Self, dst: &mut [Self]);
+    /// Limits multiple values to a range
+    fn clip(src: &[Self], low: Self, high: Self, dst: &mut [Self]);
+}
+
+/// Implements DspElement for a type by calling the functions with a type suffix
+macro_rules! dsp_element {
+    ($element:ty, $dot:ty, $abs:ident, $abs_in_place:ident, $add:ident, $subtract:ident,
+        $multiply:ident, $dot_product:ident, $negate:ident, $offset:ident, $clip:ident) => {
+        impl DspElement for $element {
+            type DotProduct = $dot;
+
+            fn abs(src: &[Self], dst: &mut [Self]) {
+                $abs(src, dst)
+            }
+            fn abs_in_place(values: &mut [Self]) {
+                $abs_in_place(values)
+            }
+            fn add(src1: &[Self], src2: &[Self], dst: &mut [Self]) {
+                $add(src1, src2, dst)
+            }
+            fn subtract(src1: &[Self], src2: &[Self], dst: &mut [Self]) {
+                $subtract(src1, src2, dst)
+            }
+            fn multiply(src1: &[Self], src2: &[Self], dst: &mut [Self]) {
+                $multiply(src1, src2, dst)
+            }
+            fn dot_product(src1: &[Self], src2: &[Self]) -> Self::DotProduct {
+                $dot_product(src1, src2)
+            }
+            fn negate(src: &[Self], dst: &mut [Self]) {
+                $negate(src, dst)
+            }
+            fn offset(src: &[Self], offset: Self, dst: &mut [Self]) {
+                $offset(src, offset, dst)
+            }
+            fn clip(src: &[Self], low: Self, high: Self, dst: &mut [Self]) {
+                $clip(src, low, high, dst)
+            }
+        }
+    };
+}
+dsp_element!(
+    f32,
+    f32,
+    abs_f32,
+    abs_in_place_f32,
+    add_f32,
+    subtract_f32,
+    multiply_f32,
+    dot_product_f32,
+    negate_f32,
+    offset_f32,
+    clip_f32
+);
+#[cfg(feature = "fixed")]
+dsp_element!(
+    I1F31,
+    I16F48,
+    abs_q31,
+    abs_in_place_q31,
+    add_q31,
+    subtract_q31,
+    multiply_q31,
+    dot_product_q31,
+    negate_q31,
+    offset_q31,
+    clip_q31
+);
+#[cfg(feature = "fixed")]
+dsp_element!(
+    I1F15,
+    I34F30,
+    abs_q15,
+    abs_in_place_q15,
+    add_q15,
+    subtract_q15,
+    multiply_q15,
+    dot_product_q15,
+    negate_q15,
+    offset_q15,
+    clip_q15
+);
+#[cfg(feature = "fixed")]
+dsp_element!(
+    I1F7,
+    I18F14,
+    abs_q7,
+    abs_in_place_q7,
+    add_q7,
+    subtract_q7,
+    multiply_q7,
+    dot_product_q7,
+    negate_q7,
+    offset_q7,
+    clip_q7
+);
+
+/// Calculates the absolute value of multiple values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn abs<T: DspElement>(src: &[T], dst: &mut [T]) {
+    T::abs(src, dst)
+}
+
+/// Calculates the absolute value of multiple values in place
+pub fn abs_in_place<T: DspElement>(values: &mut [T]) {
+    T::abs_in_place(values)
+}
+
+/// Adds multiple values
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn add<T: DspElement>(src1: &[T], src2: &[T], dst: &mut [T]) {
+    T::add(src1, src2, dst)
+}
+
+/// Subtracts multiple values
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn subtract<T: DspElement>(src1: &[T], src2: &[T], dst: &mut [T]) {
+    T::subtract(src1, src2, dst)
+}
+
+/// Multiplies multiple values
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn multiply<T: DspElement>(src1: &[T], src2: &[T], dst: &mut [T]) {
+    T::multiply(src1, src2, dst)
+}
+
+/// Calculates the dot product of two vectors
+///
+/// For fixed-point values, the result has a wider type with more integer bits, as in
+/// `dot_product_q15`.
+///
+/// # Panics
+///
+/// This function panics if src1 and src2 do not have the same length.
+pub fn dot_product<T: DspElement>(src1: &[T], src2: &[T]) -> T::DotProduct {
+    T::dot_product(src1, src2)
+}
+
+/// Negates multiple values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn negate<T: DspElement>(src: &[T], dst: &mut [T]) {
+    T::negate(src, dst)
+}
+
+/// Adds a constant offset to multiple values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn offset<T: DspElement>(src: &[T], offset: T, dst: &mut [T]) {
+    T::offset(src, offset, dst)
+}
+
+/// Limits multiple values to a range
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, or if low is greater than
+/// high.
+pub fn clip<T: DspElement>(src: &[T], low: T, high: T, dst: &mut [T]) {
+    T::clip(src, low, high, dst)
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    #[cfg(feature = "fixed")]
+    impl Sealed for fixed::types::I1F31 {}
+    #[cfg(feature = "fixed")]
+    impl Sealed for fixed::types::I1F15 {}
+    #[cfg(feature = "fixed")]
+    impl Sealed for fixed::types::I1F7 {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clip_f32_below_inside_above() {
+        let src = [-2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0];
+        let mut dst = [0.0; 7];
+        clip_f32(&src, -1.0, 1.0, &mut dst);
+        assert_eq!(dst, [-1.0, -1.0, -0.5, 0.0, 0.5, 1.0, 1.0]);
+
+        // NaN values are not changed
+        let mut dst = [0.0; 1];
+        clip_f32(&[f32::NAN], -1.0, 1.0, &mut dst);
+        assert!(dst[0].is_nan());
+
+        // A range that contains only one value
+        let mut dst = [0.0; 3];
+        clip_f32(&[-1.0, 0.25, 1.0], 0.25, 0.25, &mut dst);
+        assert_eq!(dst, [0.25; 3]);
+    }
+
+    #[test]
+    fn clip_in_place_f32_below_inside_above() {
+        let mut values = [-2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0];
+        clip_in_place_f32(&mut values, -0.5, 0.5);
+        assert_eq!(values, [-0.5, -0.5, -0.5, 0.0, 0.5, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn clip_fixed_bits_below_inside_above() {
+        let mut dst = [0; 5];
+        raw::clip_q31(
+            &[i32::MIN, -1001, -1000, 1000, i32::MAX],
+            -1000,
+            1000,
+            &mut dst,
+        );
+        assert_eq!(dst, [-1000, -1000, -1000, 1000, 1000]);
+        let mut dst = [0; 5];
+        raw::clip_q15(&[i16::MIN, -101, 0, 101, i16::MAX], -100, 100, &mut dst);
+        assert_eq!(dst, [-100, -100, 0, 100, 100]);
+        let mut dst = [0; 5];
+        raw::clip_q7(&[i8::MIN, -11, 5, 11, i8::MAX], -10, 10, &mut dst);
+        assert_eq!(dst, [-10, -10, 5, 10, 10]);
+    }
+
+    #[test]
+    fn clip_in_place_fixed_bits_below_inside_above() {
+        let mut values = [i32::MIN, -1001, -1000, 1000, i32::MAX];
+        raw::clip_in_place_q31(&mut values, -1000, 1000);
+        assert_eq!(values, [-1000, -1000, -1000, 1000, 1000]);
+        let mut values = [i16::MIN, -101, 0, 101, i16::MAX];
+        raw::clip_in_place_q15(&mut values, -100, 100);
+        assert_eq!(values, [-100, -100, 0, 100, 100]);
+        let mut values = [i8::MIN, -11, 5, 11, i8::MAX];
+        raw::clip_in_place_q7(&mut values, -10, 10);
+        assert_eq!(values, [-10, -10, 5, 10, 10]);
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn clip_fixed_below_inside_above() {
+        let (low, high) = (I1F15::from_num(-0.5), I1F15::from_num(0.25));
+        let src = [I1F15::MIN, I1F15::from_num(0.125), I1F15::MAX];
+        let mut dst = [I1F15::ZERO; 3];
+        clip_q15(&src, low, high, &mut dst);
+        assert_eq!(dst, [low, I1F15::from_num(0.125), high]);
+        let mut values = src;
+        clip_in_place_q15(&mut values, low, high);
+        assert_eq!(values, dst);
+    }
+
+    #[test]
+    #[should_panic(expected = "Clip lower limit greater than upper limit")]
+    fn clip_low_greater_than_high() {
+        let mut dst = [0.0; 1];
+        clip_f32(&[0.0], 1.0, -1.0, &mut dst);
+    }
+
+    #[test]
+    #[should_panic(expected = "Clip lower limit greater than upper limit")]
+    fn clip_in_place_low_greater_than_high() {
+        raw::clip_in_place_q15(&mut [0], 1, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Clip lower limit greater than upper limit")]
+    fn clip_nan_limit() {
+        clip_in_place_f32(&mut [0.0], f32::NAN, 1.0);
     }
 }
@@ -0,0 +1,845 @@
+//! Basic math functions on fixed-point values stored as raw integers
+//!
+//! These functions have the same names and behavior as the fixed-point functions in the parent
+//! module, but they take `i32`, `i16`, and `i8` values that hold the bits of Q1.31, Q1.15, and
+//! Q1.7 values. They are available whether or not the `fixed` feature is enabled.
+
+use super::{check_shift, clip_values};
+use crate::{check_length, Result};
+
+/// Calculates the absolute value of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = abs(src[i])` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn abs_q31(src: &[i32], dst: &mut [i32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_abs_q31(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the absolute value of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = abs(src[i])` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn abs_q15(src: &[i16], dst: &mut [i16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_abs_q15(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the absolute value of multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = abs(src[i])` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn abs_q7(src: &[i8], dst: &mut [i8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_abs_q7(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the absolute value of multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = abs(values[i])` for all values of i
+/// in range.
+pub fn abs_in_place_q31(values: &mut [i32]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_abs_q31(ptr, ptr, length);
+    }
+}
+
+/// Calculates the absolute value of multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = abs(values[i])` for all values of i
+/// in range.
+pub fn abs_in_place_q15(values: &mut [i16]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_abs_q15(ptr, ptr, length);
+    }
+}
+
+/// Calculates the absolute value of multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = abs(values[i])` for all values of i
+/// in range.
+pub fn abs_in_place_q7(values: &mut [i8]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_abs_q7(ptr, ptr, length);
+    }
+}
+
+/// Adds multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] + src2[i]` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn add_q31(src1: &[i32], src2: &[i32], dst: &mut [i32]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_add_q31(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Adds multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] + src2[i]` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn add_q15(src1: &[i16], src2: &[i16], dst: &mut [i16]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_add_q15(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Adds multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] + src2[i]` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn add_q7(src1: &[i8], src2: &[i8], dst: &mut [i8]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_add_q7(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn subtract_q31(src1: &[i32], src2: &[i32], dst: &mut [i32]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_sub_q31(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn subtract_q15(src1: &[i16], src2: &[i16], dst: &mut [i16]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_sub_q15(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Subtracts multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] - src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn subtract_q7(src1: &[i8], src2: &[i8], dst: &mut [i8]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_sub_q7(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the dot product of two vectors
+///
+/// The returned value is the sum of `src1[i] * src2[i]` over all values of i
+/// in range. The result has 48 fractional bits.
+///
+/// # Panics
+///
+/// This function panics if src1 and src2 do not have the same length.
+pub fn dot_product_q31(src1: &[i32], src2: &[i32]) -> i64 {
+    let length = check_length((src1.len(), src2.len()));
+    let mut result = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_dot_prod_q31(src1.as_ptr(), src2.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the dot product of two vectors
+///
+/// The returned value is the sum of `src1[i] * src2[i]` over all values of i
+/// in range. The result has 30 fractional bits.
+///
+/// # Panics
+///
+/// This function panics if src1 and src2 do not have the same length.
+pub fn dot_product_q15(src1: &[i16], src2: &[i16]) -> i64 {
+    let length = check_length((src1.len(), src2.len()));
+    let mut result = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_dot_prod_q15(src1.as_ptr(), src2.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the dot product of two vectors
+///
+/// The returned value is the sum of `src1[i] * src2[i]` over all values of i
+/// in range. The result has 14 fractional bits.
+///
+/// # Panics
+///
+/// This function panics if src1 and src2 do not have the same length.
+pub fn dot_product_q7(src1: &[i8], src2: &[i8]) -> i32 {
+    let length = check_length((src1.len(), src2.len()));
+    let mut result = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_dot_prod_q7(src1.as_ptr(), src2.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Multiplies multiple values
+///
+/// This is similar to performing `dst[i] = src1[i] * src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn multiply_q31(src1: &[i32], src2: &[i32], dst: &mut [i32]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_mult_q31(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Multiplies multiple values
+///
+/// This is similar to performing `dst[i] = src1[i] * src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn multiply_q15(src1: &[i16], src2: &[i16], dst: &mut [i16]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_mult_q15(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Multiplies multiple values
+///
+/// This is similar to performing `dst[i] = src1[i] * src2[i]` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn multiply_q7(src1: &[i8], src2: &[i8], dst: &mut [i8]) {
+    let length = check_length((src1.len(), src2.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_mult_q7(src1.as_ptr(), src2.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range. This function saturates, so the negation of -1 is the largest positive value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn negate_q31(src: &[i32], dst: &mut [i32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_negate_q31(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range. This function saturates, so the negation of -1 is the largest positive value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn negate_q15(src: &[i16], dst: &mut [i16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_negate_q15(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Negates multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = -src[i]` for all values of i in
+/// range. This function saturates, so the negation of -1 is the largest positive value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn negate_q7(src: &[i8], dst: &mut [i8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_negate_q7(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Negates multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = -values[i]` for all values of i
+/// in range. This function saturates.
+pub fn negate_in_place_q31(values: &mut [i32]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_negate_q31(ptr, ptr, length);
+    }
+}
+
+/// Negates multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = -values[i]` for all values of i
+/// in range. This function saturates.
+pub fn negate_in_place_q15(values: &mut [i16]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_negate_q15(ptr, ptr, length);
+    }
+}
+
+/// Negates multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = -values[i]` for all values of i
+/// in range. This function saturates.
+pub fn negate_in_place_q7(values: &mut [i8]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_negate_q7(ptr, ptr, length);
+    }
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn offset_q31(src: &[i32], offset: i32, dst: &mut [i32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_offset_q31(src.as_ptr(), offset, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn offset_q15(src: &[i16], offset: i16, dst: &mut [i16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_offset_q15(src.as_ptr(), offset, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Adds a constant offset to multiple values
+///
+/// This is functionally equivalent to performing `dst[i] = src[i] + offset` for all values of i
+/// in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn offset_q7(src: &[i8], offset: i8, dst: &mut [i8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_offset_q7(src.as_ptr(), offset, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Multiplies multiple values by a constant
+///
+/// The scale factor is `scale_fract * 2^shift`, which allows gains greater than 1. This is
+/// similar to performing `dst[i] = src[i] * scale_fract * 2^shift` for all values of i in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if shift is less than -32 or greater than 30.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn scale_q31(src: &[i32], scale_fract: i32, shift: i8, dst: &mut [i32]) -> Result<()> {
+    check_shift(shift, -32, 30)?;
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_scale_q31(src.as_ptr(), scale_fract, shift, dst.as_mut_ptr(), length);
+    }
+    Ok(())
+}
+
+/// Multiplies multiple values by a constant
+///
+/// The scale factor is `scale_fract * 2^shift`, which allows gains greater than 1. This is
+/// similar to performing `dst[i] = src[i] * scale_fract * 2^shift` for all values of i in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if shift is less than -16 or greater than 15.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn scale_q15(src: &[i16], scale_fract: i16, shift: i8, dst: &mut [i16]) -> Result<()> {
+    check_shift(shift, -16, 15)?;
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_scale_q15(src.as_ptr(), scale_fract, shift, dst.as_mut_ptr(), length);
+    }
+    Ok(())
+}
+
+/// Multiplies multiple values by a constant
+///
+/// The scale factor is `scale_fract * 2^shift`, which allows gains greater than 1. This is
+/// similar to performing `dst[i] = src[i] * scale_fract * 2^shift` for all values of i in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if shift is less than -24 or greater than 7.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn scale_q7(src: &[i8], scale_fract: i8, shift: i8, dst: &mut [i8]) -> Result<()> {
+    check_shift(shift, -24, 7)?;
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_scale_q7(src.as_ptr(), scale_fract, shift, dst.as_mut_ptr(), length);
+    }
+    Ok(())
+}
+
+/// Shifts multiple values left or right by a number of bits
+///
+/// A positive shift_bits shifts left (multiplies by `2^shift_bits`), and a negative shift_bits
+/// shifts right (divides by `2^-shift_bits`). Left shifts saturate on overflow. Right shifts
+/// are arithmetic, so they round toward negative infinity.
+///
+/// This function returns an error if shift_bits is less than -31 or greater than 31.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn shift_q31(src: &[i32], shift_bits: i8, dst: &mut [i32]) -> Result<()> {
+    check_shift(shift_bits, -31, 31)?;
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_shift_q31(src.as_ptr(), shift_bits, dst.as_mut_ptr(), length);
+    }
+    Ok(())
+}
+
+/// Shifts multiple values left or right by a number of bits
+///
+/// A positive shift_bits shifts left (multiplies by `2^shift_bits`), and a negative shift_bits
+/// shifts right (divides by `2^-shift_bits`). Left shifts saturate on overflow. Right shifts
+/// are arithmetic, so they round toward negative infinity.
+///
+/// This function returns an error if shift_bits is less than -15 or greater than 15.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn shift_q15(src: &[i16], shift_bits: i8, dst: &mut [i16]) -> Result<()> {
+    check_shift(shift_bits, -15, 15)?;
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_shift_q15(src.as_ptr(), shift_bits, dst.as_mut_ptr(), length);
+    }
+    Ok(())
+}
+
+/// Shifts multiple values left or right by a number of bits
+///
+/// A positive shift_bits shifts left (multiplies by `2^shift_bits`), and a negative shift_bits
+/// shifts right (divides by `2^-shift_bits`). Left shifts saturate on overflow. Right shifts
+/// are arithmetic, so they round toward negative infinity.
+///
+/// This function returns an error if shift_bits is less than -7 or greater than 7.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn shift_q7(src: &[i8], shift_bits: i8, dst: &mut [i8]) -> Result<()> {
+    check_shift(shift_bits, -7, 7)?;
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_shift_q7(src.as_ptr(), shift_bits, dst.as_mut_ptr(), length);
+    }
+    Ok(())
+}
+
+/// Limits multiple values to a range
+///
+/// This is functionally equivalent to performing `dst[i] = src[i].max(low).min(high)` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, or if low is greater than
+/// high.
+pub fn clip_q31(src: &[i32], low: i32, high: i32, dst: &mut [i32]) {
+    clip_values(src, low, high, dst);
+}
+
+/// Limits multiple values to a range
+///
+/// This is functionally equivalent to performing `dst[i] = src[i].max(low).min(high)` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, or if low is greater than
+/// high.
+pub fn clip_q15(src: &[i16], low: i16, high: i16, dst: &mut [i16]) {
+    clip_values(src, low, high, dst);
+}
+
+/// Limits multiple values to a range
+///
+/// This is functionally equivalent to performing `dst[i] = src[i].max(low).min(high)` for all
+/// values of i in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length, or if low is greater than
+/// high.
+pub fn clip_q7(src: &[i8], low: i8, high: i8, dst: &mut [i8]) {
+    clip_values(src, low, high, dst);
+}
+
+/// Adds multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn add_in_place_q31(values: &mut [i32], other: &[i32]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_add_q31(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Adds multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn add_in_place_q15(values: &mut [i16], other: &[i16]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_add_q15(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Adds multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn add_in_place_q7(values: &mut [i8], other: &[i8]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_add_q7(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Subtracts multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] - other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn subtract_in_place_q31(values: &mut [i32], other: &[i32]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_sub_q31(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Subtracts multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] - other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn subtract_in_place_q15(values: &mut [i16], other: &[i16]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_sub_q15(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Subtracts multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] - other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn subtract_in_place_q7(values: &mut [i8], other: &[i8]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_sub_q7(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Multiplies multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn multiply_in_place_q31(values: &mut [i32], other: &[i32]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_mult_q31(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Multiplies multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn multiply_in_place_q15(values: &mut [i16], other: &[i16]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_mult_q15(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Multiplies multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] * other[i]` for all
+/// values of i in range. This function saturates on overflow.
+///
+/// # Panics
+///
+/// This function panics if values and other do not have the same length.
+pub fn multiply_in_place_q7(values: &mut [i8], other: &[i8]) {
+    let length = check_length((values.len(), other.len()));
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_mult_q7(ptr, other.as_ptr(), ptr, length);
+    }
+}
+
+/// Multiplies multiple values by a constant in place
+///
+/// The scale factor is `scale_fract * 2^shift`, as in `scale_q31`. This function saturates on
+/// overflow.
+///
+/// This function returns an error if shift is less than -32 or greater than 30.
+pub fn scale_in_place_q31(values: &mut [i32], scale_fract: i32, shift: i8) -> Result<()> {
+    check_shift(shift, -32, 30)?;
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_scale_q31(ptr, scale_fract, shift, ptr, length);
+    }
+    Ok(())
+}
+
+/// Multiplies multiple values by a constant in place
+///
+/// The scale factor is `scale_fract * 2^shift`, as in `scale_q15`. This function saturates on
+/// overflow.
+///
+/// This function returns an error if shift is less than -16 or greater than 15.
+pub fn scale_in_place_q15(values: &mut [i16], scale_fract: i16, shift: i8) -> Result<()> {
+    check_shift(shift, -16, 15)?;
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_scale_q15(ptr, scale_fract, shift, ptr, length);
+    }
+    Ok(())
+}
+
+/// Multiplies multiple values by a constant in place
+///
+/// The scale factor is `scale_fract * 2^shift`, as in `scale_q7`. This function saturates on
+/// overflow.
+///
+/// This function returns an error if shift is less than -24 or greater than 7.
+pub fn scale_in_place_q7(values: &mut [i8], scale_fract: i8, shift: i8) -> Result<()> {
+    check_shift(shift, -24, 7)?;
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_scale_q7(ptr, scale_fract, shift, ptr, length);
+    }
+    Ok(())
+}
+
+/// Adds a constant offset to multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + offset` for all values
+/// of i in range. This function saturates on overflow.
+pub fn offset_in_place_q31(values: &mut [i32], offset: i32) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_offset_q31(ptr, offset, ptr, length);
+    }
+}
+
+/// Adds a constant offset to multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + offset` for all values
+/// of i in range. This function saturates on overflow.
+pub fn offset_in_place_q15(values: &mut [i16], offset: i16) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_offset_q15(ptr, offset, ptr, length);
+    }
+}
+
+/// Adds a constant offset to multiple values in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i] + offset` for all values
+/// of i in range. This function saturates on overflow.
+pub fn offset_in_place_q7(values: &mut [i8], offset: i8) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_offset_q7(ptr, offset, ptr, length);
+    }
+}
+
+/// Limits multiple values to a range in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i].max(low).min(high)` for
+/// all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if low is greater than high.
+pub fn clip_in_place_q31(values: &mut [i32], low: i32, high: i32) {
+    assert!(low <= high, "Clip lower limit greater than upper limit");
+    for value in values.iter_mut() {
+        if *value < low {
+            *value = low;
+        } else if *value > high {
+            *value = high;
+        }
+    }
+}
+
+/// Limits multiple values to a range in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i].max(low).min(high)` for
+/// all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if low is greater than high.
+pub fn clip_in_place_q15(values: &mut [i16], low: i16, high: i16) {
+    assert!(low <= high, "Clip lower limit greater than upper limit");
+    for value in values.iter_mut() {
+        if *value < low {
+            *value = low;
+        } else if *value > high {
+            *value = high;
+        }
+    }
+}
+
+/// Limits multiple values to a range in place
+///
+/// This is functionally equivalent to performing `values[i] = values[i].max(low).min(high)` for
+/// all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if low is greater than high.
+pub fn clip_in_place_q7(values: &mut [i8], low: i8, high: i8) {
+    assert!(low <= high, "Clip lower limit greater than upper limit");
+    for value in values.iter_mut() {
+        if *value < low {
+            *value = low;
+        } else if *value > high {
+            *value = high;
+        }
+    }
+}
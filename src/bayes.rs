@@ -0,0 +1,138 @@
+//! Bayesian classifiers
+
+use core::convert::TryInto;
+
+use crate::{check_buffers, Error, Result};
+
+/// A Gaussian naive Bayes classifier
+///
+/// This classifier uses a model trained elsewhere, for example with the scikit-learn
+/// `sklearn.naive_bayes.GaussianNB` class. The means, variances, and class priors of a trained
+/// model are the `theta_`, `var_` (or `sigma_`), and `class_prior_` attributes, and epsilon is
+/// the `epsilon_` attribute.
+pub struct GaussianNaiveBayesF32<M, V, P> {
+    /// The mean of each feature for each class, one class after another
+    means: M,
+    /// The variance of each feature for each class, one class after another
+    variances: V,
+    /// The prior probability of each class
+    priors: P,
+    /// A value added to all variances
+    epsilon: f32,
+    /// The number of values in each input vector
+    dimension: usize,
+}
+
+impl<M, V, P> GaussianNaiveBayesF32<M, V, P>
+where
+    M: AsRef<[f32]>,
+    V: AsRef<[f32]>,
+    P: AsRef<[f32]>,
+{
+    /// Creates a classifier
+    ///
+    /// There must be one prior probability for each class. means and variances contain the
+    /// values for each class, one class after another, so their lengths must both be equal to
+    /// the number of classes multiplied by the number of features.
+    ///
+    /// This function returns an error if there are no classes or no features, or if means and
+    /// variances do not have the same length or have a length that is not a multiple of the
+    /// number of classes.
+    pub fn new(means: M, variances: V, priors: P, epsilon: f32) -> Result<Self> {
+        let dimension = check_bayes_lengths(
+            means.as_ref().len(),
+            variances.as_ref().len(),
+            priors.as_ref().len(),
+        )?;
+        Ok(GaussianNaiveBayesF32 {
+            means,
+            variances,
+            priors,
+            epsilon,
+            dimension,
+        })
+    }
+
+    /// Classifies a vector and returns the index of the most likely class
+    ///
+    /// When this function returns, scores contains the logarithm of the unnormalized
+    /// probability of each class. The returned index is the index of the largest score.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the length of input is not equal to the number of features,
+    /// or if the length of scores is not equal to the number of classes.
+    pub fn predict(&self, input: &[f32], scores: &mut [f32]) -> usize {
+        assert_eq!(
+            input.len(),
+            self.dimension,
+            "Input length not equal to number of features"
+        );
+        let means = self.means.as_ref();
+        let variances = self.variances.as_ref();
+        let priors = self.priors.as_ref();
+        check_buffers(
+            check_bayes_lengths(means.len(), variances.len(), priors.len()).and_then(|dimension| {
+                if dimension == self.dimension {
+                    Ok(())
+                } else {
+                    Err(Error::Length)
+                }
+            }),
+        );
+        assert_eq!(
+            scores.len(),
+            priors.len(),
+            "Scores length not equal to number of classes"
+        );
+        let instance = cmsis_dsp_sys::arm_gaussian_naive_bayes_instance_f32 {
+            vectorDimension: self.dimension as u32,
+            numberOfClasses: priors.len() as u32,
+            theta: means.as_ptr(),
+            sigma: variances.as_ptr(),
+            classPriors: priors.as_ptr(),
+            epsilon: self.epsilon,
+        };
+        let class = unsafe {
+            cmsis_dsp_sys::arm_gaussian_naive_bayes_predict_f32(
+                &instance,
+                input.as_ptr(),
+                scores.as_mut_ptr(),
+            )
+        };
+        class as usize
+    }
+
+    /// Returns the number of classes
+    pub fn classes(&self) -> usize {
+        self.priors.as_ref().len()
+    }
+
+    /// Returns the number of features in each input vector
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Releases the means, variances, and prior probabilities
+    pub fn into_inner(self) -> (M, V, P) {
+        (self.means, self.variances, self.priors)
+    }
+}
+
+/// Checks the lengths of the means, variances, and prior probabilities of a classifier, and
+/// returns the number of features
+fn check_bayes_lengths(
+    means_length: usize,
+    variances_length: usize,
+    classes: usize,
+) -> Result<usize> {
+    let _: u32 = means_length.try_into().map_err(|_| Error::Length)?;
+    if classes == 0
+        || means_length == 0
+        || variances_length != means_length
+        || means_length % classes != 0
+    {
+        return Err(Error::Length);
+    }
+    Ok(means_length / classes)
+}
@@ -1,9 +1,79 @@
 //! Complex number operations
 
-use num_complex::Complex32;
+pub mod interleaved;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I16F48, I1F15, I1F31, I2F14, I2F30, I3F13, I3F29, I8F24};
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
 
 use crate::check_length;
 
+/// A type that stores a complex number as its real part followed by its imaginary part
+///
+/// The functions in this crate that operate on complex numbers accept slices of any type that
+/// implements this trait. It is implemented for two-element arrays (`[re, im]`) and, when the
+/// `num-complex` feature is enabled, for `num_complex::Complex`. Interleaved slices of scalars
+/// can be used with the functions in the `interleaved` module.
+///
+/// Tuples such as `(f32, f32)` do not implement this trait because Rust does not guarantee
+/// the layout of tuples. Use `[f32; 2]` instead.
+///
+/// This trait is sealed. Every implementation has the same layout as `[Self::Scalar; 2]`,
+/// which the functions in this crate rely on when passing complex values to CMSIS-DSP.
+pub trait ComplexLayout: sealed::Sealed + Copy {
+    /// The type of the real and imaginary parts
+    type Scalar: Copy;
+    /// The same complex number representation with a different type of parts
+    type With<S: Copy>: ComplexLayout<Scalar = S>;
+
+    /// Creates a complex number from its real and imaginary parts
+    fn from_parts(re: Self::Scalar, im: Self::Scalar) -> Self;
+    /// Returns the real part
+    fn re(&self) -> Self::Scalar;
+    /// Returns the imaginary part
+    fn im(&self) -> Self::Scalar;
+}
+
+impl<T: Copy> ComplexLayout for [T; 2] {
+    type Scalar = T;
+    type With<S: Copy> = [S; 2];
+
+    fn from_parts(re: T, im: T) -> Self {
+        [re, im]
+    }
+    fn re(&self) -> T {
+        self[0]
+    }
+    fn im(&self) -> T {
+        self[1]
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T: Copy> ComplexLayout for Complex<T> {
+    type Scalar = T;
+    type With<S: Copy> = Complex<S>;
+
+    fn from_parts(re: T, im: T) -> Self {
+        Complex::new(re, im)
+    }
+    fn re(&self) -> T {
+        self.re
+    }
+    fn im(&self) -> T {
+        self.im
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T: Copy> Sealed for [T; 2] {}
+    #[cfg(feature = "num-complex")]
+    impl<T: Copy> Sealed for num_complex::Complex<T> {}
+}
+
 /// Calculates the magnitude of each complex number in a provided source slice, and stores
 /// each result in the corresponding position in the destination slice
 ///
@@ -11,9 +81,469 @@ use crate::check_length;
 ///
 /// This function panics if source.len() is not equal to destination.len(), or if either length
 /// is too large to fit into a 32-bit integer
-pub fn complex_magnitude_f32(source: &[Complex32], destination: &mut [f32]) {
+pub fn complex_magnitude_f32<C: ComplexLayout<Scalar = f32>>(
+    source: &[C],
+    destination: &mut [f32],
+) {
     let length = check_length((source.len(), destination.len()));
     unsafe {
-        cmsis_dsp_sys::arm_cmplx_mag_f32(source.as_ptr() as *const f32, destination.as_mut_ptr(), length);
+        cmsis_dsp_sys::arm_cmplx_mag_f32(
+            source.as_ptr() as *const f32,
+            destination.as_mut_ptr(),
+            length,
+        );
+    }
+}
+
+/// Calculates the magnitude of each complex number in a provided source slice, and stores
+/// each result in the corresponding position in the destination slice
+///
+/// The results have 2 integer bits, so they cannot overflow.
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_q31<C: ComplexLayout<Scalar = I1F31>>(
+    source: &[C],
+    destination: &mut [I2F30],
+) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_q31(
+            source.as_ptr() as *const _,
+            destination.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Calculates the magnitude of each complex number in a provided source slice, and stores
+/// each result in the corresponding position in the destination slice
+///
+/// The results have 2 integer bits, so they cannot overflow.
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_q15<C: ComplexLayout<Scalar = I1F15>>(
+    source: &[C],
+    destination: &mut [I2F14],
+) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_q15(
+            source.as_ptr() as *const _,
+            destination.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Calculates the complex conjugate of each complex number in a provided source slice, and
+/// stores each result in the corresponding position in the destination slice
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+pub fn complex_conjugate_f32<C: ComplexLayout<Scalar = f32>>(source: &[C], destination: &mut [C]) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_conj_f32(
+            source.as_ptr() as *const f32,
+            destination.as_mut_ptr() as *mut f32,
+            length,
+        );
+    }
+}
+
+/// Calculates the complex conjugate of each complex number in a provided source slice, and
+/// stores each result in the corresponding position in the destination slice
+///
+/// The imaginary parts are negated with saturation, so an imaginary part of -1 becomes the
+/// largest positive value.
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_q31<C: ComplexLayout<Scalar = I1F31>>(
+    source: &[C],
+    destination: &mut [C],
+) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_conj_q31(
+            source.as_ptr() as *const _,
+            destination.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Calculates the complex conjugate of each complex number in a provided source slice, and
+/// stores each result in the corresponding position in the destination slice
+///
+/// The imaginary parts are negated with saturation, so an imaginary part of -1 becomes the
+/// largest positive value.
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_q15<C: ComplexLayout<Scalar = I1F15>>(
+    source: &[C],
+    destination: &mut [C],
+) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_conj_q15(
+            source.as_ptr() as *const _,
+            destination.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Replaces each complex number in a slice with its complex conjugate
+pub fn complex_conjugate_in_place_f32<C: ComplexLayout<Scalar = f32>>(values: &mut [C]) {
+    let length = check_length(values.len());
+    // The CMSIS-DSP function reads each value before writing the corresponding output
+    unsafe {
+        let ptr = values.as_mut_ptr() as *mut f32;
+        cmsis_dsp_sys::arm_cmplx_conj_f32(ptr, ptr, length);
+    }
+}
+
+/// Replaces each complex number in a slice with its complex conjugate
+///
+/// The imaginary parts are negated with saturation.
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_in_place_q31<C: ComplexLayout<Scalar = I1F31>>(values: &mut [C]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr() as *mut _;
+        cmsis_dsp_sys::arm_cmplx_conj_q31(ptr, ptr, length);
+    }
+}
+
+/// Replaces each complex number in a slice with its complex conjugate
+///
+/// The imaginary parts are negated with saturation.
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_in_place_q15<C: ComplexLayout<Scalar = I1F15>>(values: &mut [C]) {
+    let length = check_length(values.len());
+    unsafe {
+        let ptr = values.as_mut_ptr() as *mut _;
+        cmsis_dsp_sys::arm_cmplx_conj_q15(ptr, ptr, length);
+    }
+}
+
+/// Calculates the dot product of two complex vectors
+///
+/// The returned value is the sum of `a[i] * b[i]` over all values of i in range. Neither
+/// vector is conjugated. To correlate a signal against a reference, conjugate the reference
+/// first.
+///
+/// # Panics
+///
+/// This function panics if a and b do not have the same length.
+pub fn complex_dot_product_f32<C: ComplexLayout<Scalar = f32>>(a: &[C], b: &[C]) -> C {
+    let length = check_length((a.len(), b.len()));
+    let mut re = 0.0;
+    let mut im = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_dot_prod_f32(
+            a.as_ptr() as *const f32,
+            b.as_ptr() as *const f32,
+            length,
+            &mut re,
+            &mut im,
+        );
+    }
+    C::from_parts(re, im)
+}
+
+/// Calculates the dot product of two complex vectors
+///
+/// The returned value is the sum of `a[i] * b[i]` over all values of i in range. Neither
+/// vector is conjugated.
+///
+/// The products are accumulated in 64-bit integers, with the fractional bits truncated to 48.
+/// The accumulators do not saturate, but overflow is not possible for fewer than 32768
+/// values.
+///
+/// # Panics
+///
+/// This function panics if a and b do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_dot_product_q31<C: ComplexLayout<Scalar = I1F31>>(
+    a: &[C],
+    b: &[C],
+) -> C::With<I16F48> {
+    let length = check_length((a.len(), b.len()));
+    let mut re = 0;
+    let mut im = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_dot_prod_q31(
+            a.as_ptr() as *const _,
+            b.as_ptr() as *const _,
+            length,
+            &mut re,
+            &mut im,
+        );
+    }
+    C::With::<I16F48>::from_parts(I16F48::from_bits(re), I16F48::from_bits(im))
+}
+
+/// Calculates the dot product of two complex vectors
+///
+/// The returned value is the sum of `a[i] * b[i]` over all values of i in range. Neither
+/// vector is conjugated.
+///
+/// The products are accumulated in 64-bit integers, and the results are truncated to 32-bit
+/// values with 24 fractional bits.
+///
+/// # Panics
+///
+/// This function panics if a and b do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_dot_product_q15<C: ComplexLayout<Scalar = I1F15>>(
+    a: &[C],
+    b: &[C],
+) -> C::With<I8F24> {
+    let length = check_length((a.len(), b.len()));
+    let mut re = 0;
+    let mut im = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_dot_prod_q15(
+            a.as_ptr() as *const _,
+            b.as_ptr() as *const _,
+            length,
+            &mut re,
+            &mut im,
+        );
+    }
+    C::With::<I8F24>::from_parts(I8F24::from_bits(re), I8F24::from_bits(im))
+}
+
+/// Calculates the squared magnitude of each complex number in a provided source slice, and
+/// stores each result in the corresponding position in the destination slice
+///
+/// This is faster than calculating the magnitude because it does not calculate a square root.
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+pub fn complex_magnitude_squared_f32<C: ComplexLayout<Scalar = f32>>(
+    source: &[C],
+    destination: &mut [f32],
+) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_squared_f32(
+            source.as_ptr() as *const f32,
+            destination.as_mut_ptr(),
+            length,
+        );
+    }
+}
+
+/// Calculates the squared magnitude of each complex number in a provided source slice, and
+/// stores each result in the corresponding position in the destination slice
+///
+/// The results have 3 integer bits, so they cannot overflow.
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_squared_q31<C: ComplexLayout<Scalar = I1F31>>(
+    source: &[C],
+    destination: &mut [I3F29],
+) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_squared_q31(
+            source.as_ptr() as *const _,
+            destination.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Calculates the squared magnitude of each complex number in a provided source slice, and
+/// stores each result in the corresponding position in the destination slice
+///
+/// The results have 3 integer bits, so they cannot overflow.
+///
+/// # Panics
+///
+/// This function panics if source.len() is not equal to destination.len(), or if either length
+/// is too large to fit into a 32-bit integer
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_squared_q15<C: ComplexLayout<Scalar = I1F15>>(
+    source: &[C],
+    destination: &mut [I3F13],
+) {
+    let length = check_length((source.len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_squared_q15(
+            source.as_ptr() as *const _,
+            destination.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Multiplies two complex vectors element by element
+///
+/// This is functionally equivalent to performing `dst[i] = a[i] * b[i]` for all values of i in
+/// range.
+///
+/// # Panics
+///
+/// This function panics if a, b, and dst do not have the same length.
+pub fn complex_multiply_f32<C: ComplexLayout<Scalar = f32>>(a: &[C], b: &[C], dst: &mut [C]) {
+    let length = check_length((a.len(), b.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_cmplx_f32(
+            a.as_ptr() as *const f32,
+            b.as_ptr() as *const f32,
+            dst.as_mut_ptr() as *mut f32,
+            length,
+        );
+    }
+}
+
+/// Multiplies two complex vectors element by element
+///
+/// This is functionally equivalent to performing `dst[i] = a[i] * b[i]` for all values of i in
+/// range. The results have 3 integer bits, so they cannot overflow.
+///
+/// # Panics
+///
+/// This function panics if a, b, and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_q31<C: ComplexLayout<Scalar = I1F31>>(
+    a: &[C],
+    b: &[C],
+    dst: &mut [C::With<I3F29>],
+) {
+    let length = check_length((a.len(), b.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_cmplx_q31(
+            a.as_ptr() as *const _,
+            b.as_ptr() as *const _,
+            dst.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Multiplies two complex vectors element by element
+///
+/// This is functionally equivalent to performing `dst[i] = a[i] * b[i]` for all values of i in
+/// range. The results have 3 integer bits, so they cannot overflow.
+///
+/// # Panics
+///
+/// This function panics if a, b, and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_q15<C: ComplexLayout<Scalar = I1F15>>(
+    a: &[C],
+    b: &[C],
+    dst: &mut [C::With<I3F13>],
+) {
+    let length = check_length((a.len(), b.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_cmplx_q15(
+            a.as_ptr() as *const _,
+            b.as_ptr() as *const _,
+            dst.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Multiplies each value in a complex vector by the corresponding value in a real vector
+///
+/// This is functionally equivalent to performing `dst[i] = complex[i] * real[i]` for all values
+/// of i in range. This can be used to apply a real window or gain to a complex signal.
+///
+/// # Panics
+///
+/// This function panics if complex, real, and dst do not have the same length.
+pub fn complex_multiply_real_f32<C: ComplexLayout<Scalar = f32>>(
+    complex: &[C],
+    real: &[f32],
+    dst: &mut [C],
+) {
+    let length = check_length((complex.len(), real.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_real_f32(
+            complex.as_ptr() as *const f32,
+            real.as_ptr(),
+            dst.as_mut_ptr() as *mut f32,
+            length,
+        );
+    }
+}
+
+/// Multiplies each value in a complex vector by the corresponding value in a real vector
+///
+/// This is functionally equivalent to performing `dst[i] = complex[i] * real[i]` for all values
+/// of i in range. The results are saturated.
+///
+/// # Panics
+///
+/// This function panics if complex, real, and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_real_q31<C: ComplexLayout<Scalar = I1F31>>(
+    complex: &[C],
+    real: &[I1F31],
+    dst: &mut [C],
+) {
+    let length = check_length((complex.len(), real.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_real_q31(
+            complex.as_ptr() as *const _,
+            real.as_ptr() as *const _,
+            dst.as_mut_ptr() as *mut _,
+            length,
+        );
+    }
+}
+
+/// Multiplies each value in a complex vector by the corresponding value in a real vector
+///
+/// This is functionally equivalent to performing `dst[i] = complex[i] * real[i]` for all values
+/// of i in range. The results are saturated.
+///
+/// # Panics
+///
+/// This function panics if complex, real, and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_real_q15<C: ComplexLayout<Scalar = I1F15>>(
+    complex: &[C],
+    real: &[I1F15],
+    dst: &mut [C],
+) {
+    let length = check_length((complex.len(), real.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_real_q15(
+            complex.as_ptr() as *const _,
+            real.as_ptr() as *const _,
+            dst.as_mut_ptr() as *mut _,
+            length,
+        );
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,302 @@
+//! Complex number operations on interleaved slices
+//!
+//! These functions are equivalent to the functions in the parent module, but they accept
+//! slices of scalars in interleaved format (`[re, im, re, im, ...]`) instead of slices of
+//! `Complex` values. This is convenient for data that comes directly from a DMA transfer or
+//! another library.
+//!
+//! All interleaved slices must have even lengths. Real-valued inputs and outputs, such as
+//! magnitudes, have one value for each complex number.
+//!
+//! The `raw` submodule provides the fixed-point functions for Q1.31 and Q1.15 values stored as
+//! `i32` and `i16`, such as samples read directly from an ADC or codec.
+
+pub mod raw;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I16F48, I1F15, I1F31, I2F14, I2F30, I3F13, I3F29, I8F24};
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+use super::ComplexLayout;
+
+/// Returns a slice of complex values that refers to the same memory as an interleaved slice
+///
+/// This function is only available when the `num-complex` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if the length of values is odd.
+#[cfg(feature = "num-complex")]
+pub fn as_complex<T>(values: &[T]) -> &[Complex<T>] {
+    assert!(values.len() % 2 == 0, "Interleaved slice length is odd");
+    // Complex<T> is repr(C) with two fields of type T, so it has the same layout as [T; 2]
+    unsafe { core::slice::from_raw_parts(values.as_ptr() as *const Complex<T>, values.len() / 2) }
+}
+
+/// Returns a mutable slice of complex values that refers to the same memory as an interleaved
+/// slice
+///
+/// This function is only available when the `num-complex` feature is enabled.
+///
+/// # Panics
+///
+/// This function panics if the length of values is odd.
+#[cfg(feature = "num-complex")]
+pub fn as_complex_mut<T>(values: &mut [T]) -> &mut [Complex<T>] {
+    assert!(values.len() % 2 == 0, "Interleaved slice length is odd");
+    unsafe {
+        core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Complex<T>, values.len() / 2)
+    }
+}
+
+/// Returns a slice of `[re, im]` pairs that refers to the same memory as an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if the length of values is odd.
+fn as_pairs<T>(values: &[T]) -> &[[T; 2]] {
+    assert!(values.len() % 2 == 0, "Interleaved slice length is odd");
+    unsafe { core::slice::from_raw_parts(values.as_ptr() as *const [T; 2], values.len() / 2) }
+}
+
+/// Returns a mutable slice of `[re, im]` pairs that refers to the same memory as an
+/// interleaved slice
+///
+/// # Panics
+///
+/// This function panics if the length of values is odd.
+fn as_pairs_mut<T>(values: &mut [T]) -> &mut [[T; 2]] {
+    assert!(values.len() % 2 == 0, "Interleaved slice length is odd");
+    unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut [T; 2], values.len() / 2) }
+}
+
+/// Calculates the magnitude of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+pub fn complex_magnitude_f32(source: &[f32], destination: &mut [f32]) {
+    super::complex_magnitude_f32(as_pairs(source), destination)
+}
+
+/// Calculates the magnitude of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_q31(source: &[I1F31], destination: &mut [I2F30]) {
+    super::complex_magnitude_q31(as_pairs(source), destination)
+}
+
+/// Calculates the magnitude of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_q15(source: &[I1F15], destination: &mut [I2F14]) {
+    super::complex_magnitude_q15(as_pairs(source), destination)
+}
+
+/// Calculates the squared magnitude of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+pub fn complex_magnitude_squared_f32(source: &[f32], destination: &mut [f32]) {
+    super::complex_magnitude_squared_f32(as_pairs(source), destination)
+}
+
+/// Calculates the squared magnitude of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_squared_q31(source: &[I1F31], destination: &mut [I3F29]) {
+    super::complex_magnitude_squared_q31(as_pairs(source), destination)
+}
+
+/// Calculates the squared magnitude of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+#[cfg(feature = "fixed")]
+pub fn complex_magnitude_squared_q15(source: &[I1F15], destination: &mut [I3F13]) {
+    super::complex_magnitude_squared_q15(as_pairs(source), destination)
+}
+
+/// Calculates the complex conjugate of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source or destination has an odd length, or if they do not have
+/// the same length.
+pub fn complex_conjugate_f32(source: &[f32], destination: &mut [f32]) {
+    super::complex_conjugate_f32(as_pairs(source), as_pairs_mut(destination))
+}
+
+/// Calculates the complex conjugate of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source or destination has an odd length, or if they do not have
+/// the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_q31(source: &[I1F31], destination: &mut [I1F31]) {
+    super::complex_conjugate_q31(as_pairs(source), as_pairs_mut(destination))
+}
+
+/// Calculates the complex conjugate of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source or destination has an odd length, or if they do not have
+/// the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_q15(source: &[I1F15], destination: &mut [I1F15]) {
+    super::complex_conjugate_q15(as_pairs(source), as_pairs_mut(destination))
+}
+
+/// Replaces each complex number in an interleaved slice with its complex conjugate
+///
+/// # Panics
+///
+/// This function panics if values has an odd length.
+pub fn complex_conjugate_in_place_f32(values: &mut [f32]) {
+    super::complex_conjugate_in_place_f32(as_pairs_mut(values))
+}
+
+/// Replaces each complex number in an interleaved slice with its complex conjugate
+///
+/// # Panics
+///
+/// This function panics if values has an odd length.
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_in_place_q31(values: &mut [I1F31]) {
+    super::complex_conjugate_in_place_q31(as_pairs_mut(values))
+}
+
+/// Replaces each complex number in an interleaved slice with its complex conjugate
+///
+/// # Panics
+///
+/// This function panics if values has an odd length.
+#[cfg(feature = "fixed")]
+pub fn complex_conjugate_in_place_q15(values: &mut [I1F15]) {
+    super::complex_conjugate_in_place_q15(as_pairs_mut(values))
+}
+
+/// Calculates the dot product of two interleaved complex vectors
+///
+/// The result can be returned as any complex type, such as `[f32; 2]`.
+///
+/// # Panics
+///
+/// This function panics if a or b has an odd length, or if they do not have the same length.
+pub fn complex_dot_product_f32<C: ComplexLayout<Scalar = f32>>(a: &[f32], b: &[f32]) -> C {
+    let [re, im] = super::complex_dot_product_f32(as_pairs(a), as_pairs(b));
+    C::from_parts(re, im)
+}
+
+/// Calculates the dot product of two interleaved complex vectors
+///
+/// The result can be returned as any complex type, such as `[f32; 2]`.
+///
+/// # Panics
+///
+/// This function panics if a or b has an odd length, or if they do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_dot_product_q31<C: ComplexLayout<Scalar = I16F48>>(a: &[I1F31], b: &[I1F31]) -> C {
+    let [re, im] = super::complex_dot_product_q31(as_pairs(a), as_pairs(b));
+    C::from_parts(re, im)
+}
+
+/// Calculates the dot product of two interleaved complex vectors
+///
+/// The result can be returned as any complex type, such as `[f32; 2]`.
+///
+/// # Panics
+///
+/// This function panics if a or b has an odd length, or if they do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn complex_dot_product_q15<C: ComplexLayout<Scalar = I8F24>>(a: &[I1F15], b: &[I1F15]) -> C {
+    let [re, im] = super::complex_dot_product_q15(as_pairs(a), as_pairs(b));
+    C::from_parts(re, im)
+}
+
+/// Multiplies two interleaved complex vectors element by element
+///
+/// # Panics
+///
+/// This function panics if a, b, or dst has an odd length, or if they do not all have the same
+/// length.
+pub fn complex_multiply_f32(a: &[f32], b: &[f32], dst: &mut [f32]) {
+    super::complex_multiply_f32(as_pairs(a), as_pairs(b), as_pairs_mut(dst))
+}
+
+/// Multiplies two interleaved complex vectors element by element
+///
+/// # Panics
+///
+/// This function panics if a, b, or dst has an odd length, or if they do not all have the same
+/// length.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_q31(a: &[I1F31], b: &[I1F31], dst: &mut [I3F29]) {
+    super::complex_multiply_q31(as_pairs(a), as_pairs(b), as_pairs_mut(dst))
+}
+
+/// Multiplies two interleaved complex vectors element by element
+///
+/// # Panics
+///
+/// This function panics if a, b, or dst has an odd length, or if they do not all have the same
+/// length.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_q15(a: &[I1F15], b: &[I1F15], dst: &mut [I3F13]) {
+    super::complex_multiply_q15(as_pairs(a), as_pairs(b), as_pairs_mut(dst))
+}
+
+/// Multiplies each value in an interleaved complex vector by the corresponding value in a real
+/// vector
+///
+/// # Panics
+///
+/// This function panics if complex or dst has an odd length, if complex and dst do not have
+/// the same length, or if real does not have one value for each complex number.
+pub fn complex_multiply_real_f32(complex: &[f32], real: &[f32], dst: &mut [f32]) {
+    super::complex_multiply_real_f32(as_pairs(complex), real, as_pairs_mut(dst))
+}
+
+/// Multiplies each value in an interleaved complex vector by the corresponding value in a real
+/// vector
+///
+/// # Panics
+///
+/// This function panics if complex or dst has an odd length, if complex and dst do not have
+/// the same length, or if real does not have one value for each complex number.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_real_q31(complex: &[I1F31], real: &[I1F31], dst: &mut [I1F31]) {
+    super::complex_multiply_real_q31(as_pairs(complex), real, as_pairs_mut(dst))
+}
+
+/// Multiplies each value in an interleaved complex vector by the corresponding value in a real
+/// vector
+///
+/// # Panics
+///
+/// This function panics if complex or dst has an odd length, if complex and dst do not have
+/// the same length, or if real does not have one value for each complex number.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_real_q15(complex: &[I1F15], real: &[I1F15], dst: &mut [I1F15]) {
+    super::complex_multiply_real_q15(as_pairs(complex), real, as_pairs_mut(dst))
+}
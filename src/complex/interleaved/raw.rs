@@ -0,0 +1,235 @@
+//! Complex number operations on interleaved fixed-point values stored as raw integers
+//!
+//! These functions have the same names and behavior as the fixed-point functions in the parent
+//! module, but they take interleaved slices of `i32` and `i16` values that hold the bits of
+//! Q1.31 and Q1.15 values. They are available whether or not the `fixed` feature is enabled.
+//!
+//! The formats of the results are the same as in the parent module. For example,
+//! `complex_magnitude_q15` produces Q2.14 values and `complex_dot_product_q31` produces Q16.48
+//! values stored as `i64`.
+
+use super::{as_pairs, as_pairs_mut};
+use crate::check_length;
+use crate::complex::ComplexLayout;
+
+/// Calculates the magnitude of each complex number in an interleaved slice
+///
+/// The results are in Q2.30 format.
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+pub fn complex_magnitude_q31(source: &[i32], destination: &mut [i32]) {
+    let length = check_length((as_pairs(source).len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_q31(source.as_ptr(), destination.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the magnitude of each complex number in an interleaved slice
+///
+/// The results are in Q2.14 format.
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+pub fn complex_magnitude_q15(source: &[i16], destination: &mut [i16]) {
+    let length = check_length((as_pairs(source).len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_q15(source.as_ptr(), destination.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the squared magnitude of each complex number in an interleaved slice
+///
+/// The results are in Q3.29 format.
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+pub fn complex_magnitude_squared_q31(source: &[i32], destination: &mut [i32]) {
+    let length = check_length((as_pairs(source).len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_squared_q31(source.as_ptr(), destination.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the squared magnitude of each complex number in an interleaved slice
+///
+/// The results are in Q3.13 format.
+///
+/// # Panics
+///
+/// This function panics if source has an odd length, or if destination does not have one
+/// value for each complex number in source.
+pub fn complex_magnitude_squared_q15(source: &[i16], destination: &mut [i16]) {
+    let length = check_length((as_pairs(source).len(), destination.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mag_squared_q15(source.as_ptr(), destination.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the complex conjugate of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source or destination has an odd length, or if they do not have
+/// the same length.
+pub fn complex_conjugate_q31(source: &[i32], destination: &mut [i32]) {
+    let length = check_length((as_pairs(source).len(), as_pairs_mut(destination).len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_conj_q31(source.as_ptr(), destination.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the complex conjugate of each complex number in an interleaved slice
+///
+/// # Panics
+///
+/// This function panics if source or destination has an odd length, or if they do not have
+/// the same length.
+pub fn complex_conjugate_q15(source: &[i16], destination: &mut [i16]) {
+    let length = check_length((as_pairs(source).len(), as_pairs_mut(destination).len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_conj_q15(source.as_ptr(), destination.as_mut_ptr(), length);
+    }
+}
+
+/// Replaces each complex number in an interleaved slice with its complex conjugate
+///
+/// # Panics
+///
+/// This function panics if values has an odd length.
+pub fn complex_conjugate_in_place_q31(values: &mut [i32]) {
+    let length = check_length(as_pairs_mut(values).len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_cmplx_conj_q31(ptr, ptr, length);
+    }
+}
+
+/// Replaces each complex number in an interleaved slice with its complex conjugate
+///
+/// # Panics
+///
+/// This function panics if values has an odd length.
+pub fn complex_conjugate_in_place_q15(values: &mut [i16]) {
+    let length = check_length(as_pairs_mut(values).len());
+    unsafe {
+        let ptr = values.as_mut_ptr();
+        cmsis_dsp_sys::arm_cmplx_conj_q15(ptr, ptr, length);
+    }
+}
+
+/// Calculates the dot product of two interleaved complex vectors
+///
+/// The result is in Q16.48 format, and can be returned as any complex type, such as `[i64; 2]`.
+///
+/// # Panics
+///
+/// This function panics if a or b has an odd length, or if they do not have the same length.
+pub fn complex_dot_product_q31<C: ComplexLayout<Scalar = i64>>(a: &[i32], b: &[i32]) -> C {
+    let length = check_length((as_pairs(a).len(), as_pairs(b).len()));
+    let mut re = 0;
+    let mut im = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_dot_prod_q31(a.as_ptr(), b.as_ptr(), length, &mut re, &mut im);
+    }
+    C::from_parts(re, im)
+}
+
+/// Calculates the dot product of two interleaved complex vectors
+///
+/// The result is in Q8.24 format, and can be returned as any complex type, such as `[i32; 2]`.
+///
+/// # Panics
+///
+/// This function panics if a or b has an odd length, or if they do not have the same length.
+pub fn complex_dot_product_q15<C: ComplexLayout<Scalar = i32>>(a: &[i16], b: &[i16]) -> C {
+    let length = check_length((as_pairs(a).len(), as_pairs(b).len()));
+    let mut re = 0;
+    let mut im = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_dot_prod_q15(a.as_ptr(), b.as_ptr(), length, &mut re, &mut im);
+    }
+    C::from_parts(re, im)
+}
+
+/// Multiplies two interleaved complex vectors element by element
+///
+/// The results are in Q3.29 format.
+///
+/// # Panics
+///
+/// This function panics if a, b, or dst has an odd length, or if they do not all have the same
+/// length.
+pub fn complex_multiply_q31(a: &[i32], b: &[i32], dst: &mut [i32]) {
+    let length = check_length((
+        as_pairs(a).len(),
+        as_pairs(b).len(),
+        as_pairs_mut(dst).len(),
+    ));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_cmplx_q31(a.as_ptr(), b.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Multiplies two interleaved complex vectors element by element
+///
+/// The results are in Q3.13 format.
+///
+/// # Panics
+///
+/// This function panics if a, b, or dst has an odd length, or if they do not all have the same
+/// length.
+pub fn complex_multiply_q15(a: &[i16], b: &[i16], dst: &mut [i16]) {
+    let length = check_length((
+        as_pairs(a).len(),
+        as_pairs(b).len(),
+        as_pairs_mut(dst).len(),
+    ));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_cmplx_q15(a.as_ptr(), b.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Multiplies each value in an interleaved complex vector by the corresponding value in a real
+/// vector
+///
+/// # Panics
+///
+/// This function panics if complex or dst has an odd length, if complex and dst do not have
+/// the same length, or if real does not have one value for each complex number.
+pub fn complex_multiply_real_q31(complex: &[i32], real: &[i32], dst: &mut [i32]) {
+    let length = check_length((as_pairs(complex).len(), real.len(), as_pairs_mut(dst).len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_real_q31(
+            complex.as_ptr(),
+            real.as_ptr(),
+            dst.as_mut_ptr(),
+            length,
+        );
+    }
+}
+
+/// Multiplies each value in an interleaved complex vector by the corresponding value in a real
+/// vector
+///
+/// # Panics
+///
+/// This function panics if complex or dst has an odd length, if complex and dst do not have
+/// the same length, or if real does not have one value for each complex number.
+pub fn complex_multiply_real_q15(complex: &[i16], real: &[i16], dst: &mut [i16]) {
+    let length = check_length((as_pairs(complex).len(), real.len(), as_pairs_mut(dst).len()));
+    unsafe {
+        cmsis_dsp_sys::arm_cmplx_mult_real_q15(
+            complex.as_ptr(),
+            real.as_ptr(),
+            dst.as_mut_ptr(),
+            length,
+        );
+    }
+}
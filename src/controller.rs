@@ -0,0 +1,40 @@
+//! Functions for motor control and other control systems
+
+mod motor;
+mod pid;
+
+#[cfg(feature = "fixed")]
+use fixed::types::I1F31;
+
+pub use self::motor::{clarke_f32, inv_clarke_f32, inv_park_f32, park_f32, svpwm_f32};
+pub use self::pid::PidF32;
+#[cfg(feature = "fixed")]
+pub use self::pid::{PidQ15, PidQ31};
+
+/// Calculates the sine and cosine of an angle in degrees
+///
+/// This function returns `(sine, cosine)`. The angle should be in the range [-180, 180].
+pub fn sin_cos_f32(degrees: f32) -> (f32, f32) {
+    let mut sine = 0.0;
+    let mut cosine = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_sin_cos_f32(degrees, &mut sine, &mut cosine);
+    }
+    (sine, cosine)
+}
+
+/// Calculates the sine and cosine of an angle
+///
+/// An input value x represents an angle of `180 * x` degrees, so the range [-1, 1) covers
+/// a full rotation.
+///
+/// This function returns `(sine, cosine)`.
+#[cfg(feature = "fixed")]
+pub fn sin_cos_q31(angle: I1F31) -> (I1F31, I1F31) {
+    let mut sine = 0;
+    let mut cosine = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_sin_cos_q31(angle.to_bits(), &mut sine, &mut cosine);
+    }
+    (I1F31::from_bits(sine), I1F31::from_bits(cosine))
+}
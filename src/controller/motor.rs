@@ -0,0 +1,72 @@
+//! Transforms for field-oriented motor control
+//!
+//! CMSIS-DSP implements the Clarke and Park transforms as inline functions, so these functions
+//! are implemented in Rust in the same way.
+
+use super::sin_cos_f32;
+
+/// 1 / sqrt(3)
+const FRAC_1_SQRT_3: f32 = 0.577_350_26;
+/// 2 / sqrt(3)
+const FRAC_2_SQRT_3: f32 = 1.154_700_5;
+/// sqrt(3) / 2
+const FRAC_SQRT_3_2: f32 = 0.866_025_4;
+
+/// Converts two phase currents of a balanced three-phase system into the stationary
+/// two-axis reference frame
+///
+/// This function returns `(alpha, beta)`.
+pub fn clarke_f32(a: f32, b: f32) -> (f32, f32) {
+    (a, FRAC_1_SQRT_3 * a + FRAC_2_SQRT_3 * b)
+}
+
+/// Converts values in the stationary two-axis reference frame into two phase values of a
+/// balanced three-phase system
+///
+/// This function returns `(a, b)`. The third phase value is `-a - b`.
+pub fn inv_clarke_f32(alpha: f32, beta: f32) -> (f32, f32) {
+    (alpha, -0.5 * alpha + FRAC_SQRT_3_2 * beta)
+}
+
+/// Converts values in the stationary two-axis reference frame into the rotating reference
+/// frame
+///
+/// sin and cos are the sine and cosine of the rotor angle, which can be calculated using
+/// `sin_cos_f32`. This function returns `(d, q)`.
+pub fn park_f32(alpha: f32, beta: f32, sin: f32, cos: f32) -> (f32, f32) {
+    (alpha * cos + beta * sin, -alpha * sin + beta * cos)
+}
+
+/// Converts values in the rotating reference frame into the stationary two-axis reference
+/// frame
+///
+/// sin and cos are the sine and cosine of the rotor angle, which can be calculated using
+/// `sin_cos_f32`. This function returns `(alpha, beta)`.
+pub fn inv_park_f32(d: f32, q: f32, sin: f32, cos: f32) -> (f32, f32) {
+    (d * cos - q * sin, d * sin + q * cos)
+}
+
+/// Calculates space-vector pulse width modulation duty cycles for a three-phase inverter
+///
+/// vd and vq are the voltage commands in the rotating reference frame, as fractions of the DC
+/// bus voltage. degrees is the rotor angle, in the range [-180, 180].
+///
+/// This function returns the duty cycles of phases a, b, and c, each in the range [0, 1].
+/// The output is linear if the magnitude of the voltage command is at most `1 / sqrt(3)`.
+/// Larger commands are limited by clamping the duty cycles.
+pub fn svpwm_f32(vd: f32, vq: f32, degrees: f32) -> [f32; 3] {
+    let (sin, cos) = sin_cos_f32(degrees);
+    let (alpha, beta) = inv_park_f32(vd, vq, sin, cos);
+    let (a, b) = inv_clarke_f32(alpha, beta);
+    let phases = [a, b, -a - b];
+    // Shifting all phases by the midpoint of the largest and smallest values centers the
+    // pulses, which is equivalent to space-vector modulation
+    let max = phases[0].max(phases[1]).max(phases[2]);
+    let min = phases[0].min(phases[1]).min(phases[2]);
+    let offset = 0.5 - (max + min) / 2.0;
+    let mut duty = [0.0; 3];
+    for (duty, phase) in duty.iter_mut().zip(phases.iter()) {
+        *duty = (phase + offset).clamp(0.0, 1.0);
+    }
+    duty
+}
@@ -0,0 +1,255 @@
+//! Proportional-integral-derivative controllers
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31};
+
+/// A proportional-integral-derivative controller that operates on floating-point values
+///
+/// The controller processes one sample at a time. Each call to `process` takes the
+/// current error (the difference between the setpoint and the measured value) and returns
+/// the new controller output.
+pub struct PidF32 {
+    /// The CMSIS-DSP instance, which contains the gains and state
+    instance: cmsis_dsp_sys::arm_pid_instance_f32,
+}
+
+impl PidF32 {
+    /// Creates a controller with the provided proportional, integral, and derivative gains
+    ///
+    /// The state is initialized to zero.
+    pub fn new(kp: f32, ki: f32, kd: f32) -> Self {
+        let mut instance = cmsis_dsp_sys::arm_pid_instance_f32 {
+            A0: 0.0,
+            A1: 0.0,
+            A2: 0.0,
+            state: [0.0; 3],
+            Kp: kp,
+            Ki: ki,
+            Kd: kd,
+        };
+        unsafe {
+            cmsis_dsp_sys::arm_pid_init_f32(&mut instance, 1);
+        }
+        PidF32 { instance }
+    }
+
+    /// Processes one error value and returns the controller output
+    ///
+    /// CMSIS-DSP implements `arm_pid_f32` as an inline function, so this function is
+    /// implemented in Rust in the same way.
+    pub fn process(&mut self, error: f32) -> f32 {
+        let instance = &mut self.instance;
+        let output = instance.A0 * error
+            + instance.A1 * instance.state[0]
+            + instance.A2 * instance.state[1]
+            + instance.state[2];
+        instance.state = [error, instance.state[0], output];
+        output
+    }
+
+    /// Sets the state of this controller to zero
+    pub fn reset(&mut self) {
+        unsafe {
+            cmsis_dsp_sys::arm_pid_reset_f32(&mut self.instance);
+        }
+    }
+
+    /// Changes the gains of this controller without changing its state
+    pub fn set_gains(&mut self, kp: f32, ki: f32, kd: f32) {
+        self.instance.Kp = kp;
+        self.instance.Ki = ki;
+        self.instance.Kd = kd;
+        unsafe {
+            cmsis_dsp_sys::arm_pid_init_f32(&mut self.instance, 0);
+        }
+    }
+
+    /// Returns the proportional gain
+    pub fn kp(&self) -> f32 {
+        self.instance.Kp
+    }
+
+    /// Returns the integral gain
+    pub fn ki(&self) -> f32 {
+        self.instance.Ki
+    }
+
+    /// Returns the derivative gain
+    pub fn kd(&self) -> f32 {
+        self.instance.Kd
+    }
+}
+
+/// A proportional-integral-derivative controller that operates on Q1.31 fixed-point values
+///
+/// The controller processes one sample at a time. Each call to `process` takes the
+/// current error (the difference between the setpoint and the measured value) and returns
+/// the new controller output.
+///
+/// This controller uses a 64-bit accumulator. The output is not saturated, so it may wrap
+/// around on overflow.
+#[cfg(feature = "fixed")]
+pub struct PidQ31 {
+    /// The CMSIS-DSP instance, which contains the gains and state
+    instance: cmsis_dsp_sys::arm_pid_instance_q31,
+}
+
+#[cfg(feature = "fixed")]
+impl PidQ31 {
+    /// Creates a controller with the provided proportional, integral, and derivative gains
+    ///
+    /// The state is initialized to zero.
+    pub fn new(kp: I1F31, ki: I1F31, kd: I1F31) -> Self {
+        let mut instance = cmsis_dsp_sys::arm_pid_instance_q31 {
+            A0: 0,
+            A1: 0,
+            A2: 0,
+            state: [0; 3],
+            Kp: kp.to_bits(),
+            Ki: ki.to_bits(),
+            Kd: kd.to_bits(),
+        };
+        unsafe {
+            cmsis_dsp_sys::arm_pid_init_q31(&mut instance, 1);
+        }
+        PidQ31 { instance }
+    }
+
+    /// Processes one error value and returns the controller output
+    ///
+    /// CMSIS-DSP implements `arm_pid_q31` as an inline function, so this function is
+    /// implemented in Rust in the same way.
+    pub fn process(&mut self, error: I1F31) -> I1F31 {
+        let instance = &mut self.instance;
+        let error = error.to_bits();
+        let accumulator = (i64::from(instance.A0) * i64::from(error))
+            .wrapping_add(i64::from(instance.A1) * i64::from(instance.state[0]))
+            .wrapping_add(i64::from(instance.A2) * i64::from(instance.state[1]));
+        let output = ((accumulator >> 31) as i32).wrapping_add(instance.state[2]);
+        instance.state = [error, instance.state[0], output];
+        I1F31::from_bits(output)
+    }
+
+    /// Sets the state of this controller to zero
+    pub fn reset(&mut self) {
+        unsafe {
+            cmsis_dsp_sys::arm_pid_reset_q31(&mut self.instance);
+        }
+    }
+
+    /// Changes the gains of this controller without changing its state
+    pub fn set_gains(&mut self, kp: I1F31, ki: I1F31, kd: I1F31) {
+        self.instance.Kp = kp.to_bits();
+        self.instance.Ki = ki.to_bits();
+        self.instance.Kd = kd.to_bits();
+        unsafe {
+            cmsis_dsp_sys::arm_pid_init_q31(&mut self.instance, 0);
+        }
+    }
+
+    /// Returns the proportional gain
+    pub fn kp(&self) -> I1F31 {
+        I1F31::from_bits(self.instance.Kp)
+    }
+
+    /// Returns the integral gain
+    pub fn ki(&self) -> I1F31 {
+        I1F31::from_bits(self.instance.Ki)
+    }
+
+    /// Returns the derivative gain
+    pub fn kd(&self) -> I1F31 {
+        I1F31::from_bits(self.instance.Kd)
+    }
+}
+
+/// A proportional-integral-derivative controller that operates on Q1.15 fixed-point values
+///
+/// The controller processes one sample at a time. Each call to `process` takes the
+/// current error (the difference between the setpoint and the measured value) and returns
+/// the new controller output.
+///
+/// This controller uses a 64-bit accumulator and saturates the output.
+///
+/// The layout of `arm_pid_instance_q15` depends on whether CMSIS-DSP was compiled with DSP
+/// instructions, so this controller does not use `arm_pid_init_q15` or `arm_pid_reset_q15`.
+/// Instead, it calculates the derived gains in Rust in the same way, with saturation.
+#[cfg(feature = "fixed")]
+pub struct PidQ15 {
+    /// The derived gains A0, A1, and A2
+    derived: [I1F15; 3],
+    /// The previous two inputs and the previous output
+    state: [I1F15; 3],
+    /// The proportional gain
+    kp: I1F15,
+    /// The integral gain
+    ki: I1F15,
+    /// The derivative gain
+    kd: I1F15,
+}
+
+#[cfg(feature = "fixed")]
+impl PidQ15 {
+    /// Creates a controller with the provided proportional, integral, and derivative gains
+    ///
+    /// The state is initialized to zero.
+    pub fn new(kp: I1F15, ki: I1F15, kd: I1F15) -> Self {
+        let mut pid = PidQ15 {
+            derived: [I1F15::from_bits(0); 3],
+            state: [I1F15::from_bits(0); 3],
+            kp,
+            ki,
+            kd,
+        };
+        pid.set_gains(kp, ki, kd);
+        pid
+    }
+
+    /// Processes one error value and returns the controller output
+    ///
+    /// CMSIS-DSP implements `arm_pid_q15` as an inline function, so this function is
+    /// implemented in Rust in the same way.
+    pub fn process(&mut self, error: I1F15) -> I1F15 {
+        let [a0, a1, a2] = self.derived;
+        let accumulator = i64::from(a0.to_bits()) * i64::from(error.to_bits())
+            + i64::from(a1.to_bits()) * i64::from(self.state[0].to_bits())
+            + i64::from(a2.to_bits()) * i64::from(self.state[1].to_bits())
+            + (i64::from(self.state[2].to_bits()) << 15);
+        let output = (accumulator >> 15).clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+        let output = I1F15::from_bits(output);
+        self.state = [error, self.state[0], output];
+        output
+    }
+
+    /// Sets the state of this controller to zero
+    pub fn reset(&mut self) {
+        self.state = [I1F15::from_bits(0); 3];
+    }
+
+    /// Changes the gains of this controller without changing its state
+    pub fn set_gains(&mut self, kp: I1F15, ki: I1F15, kd: I1F15) {
+        self.kp = kp;
+        self.ki = ki;
+        self.kd = kd;
+        self.derived = [
+            kp.saturating_add(ki).saturating_add(kd),
+            kd.saturating_add(kd).saturating_add(kp).saturating_neg(),
+            kd,
+        ];
+    }
+
+    /// Returns the proportional gain
+    pub fn kp(&self) -> I1F15 {
+        self.kp
+    }
+
+    /// Returns the integral gain
+    pub fn ki(&self) -> I1F15 {
+        self.ki
+    }
+
+    /// Returns the derivative gain
+    pub fn kd(&self) -> I1F15 {
+        self.kd
+    }
+}
@@ -0,0 +1,235 @@
+//! Distance functions
+//!
+//! These functions measure how different two vectors are. They can be used for clustering,
+//! nearest-neighbor classification, and template matching.
+//!
+//! The boolean distance functions operate on `BitVector`s, which store booleans packed into
+//! `u32` words.
+
+mod dtw;
+mod knn;
+
+use core::convert::TryInto;
+
+use crate::{check_buffers, check_length, Error, Result};
+
+pub use self::dtw::{dtw_distance_f32, dtw_init_window, dtw_path_f32, DtwWindow};
+pub use self::knn::{k_nearest_f32, knn_classify_f32, Metric, Neighbor};
+
+/// Defines a distance function that takes two floating-point vectors
+macro_rules! distance_f32 {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $function:ident
+    ) => {
+        $(#[$attr])*
+        ///
+        /// # Panics
+        ///
+        /// This function panics if a and b do not have the same length.
+        pub fn $name(a: &[f32], b: &[f32]) -> f32 {
+            let length = check_length((a.len(), b.len()));
+            unsafe { cmsis_dsp_sys::$function(a.as_ptr(), b.as_ptr(), length) }
+        }
+    };
+}
+
+distance_f32! {
+    /// Calculates the Euclidean distance between two vectors
+    ///
+    /// The returned value is `sqrt(sum((a[i] - b[i])^2))` over all values of i in range.
+    euclidean_distance_f32, arm_euclidean_distance_f32
+}
+distance_f32! {
+    /// Calculates the Bray-Curtis distance between two vectors
+    ///
+    /// The returned value is `sum(|a[i] - b[i]|) / sum(|a[i] + b[i]|)` over all values of i in
+    /// range.
+    braycurtis_distance_f32, arm_braycurtis_distance_f32
+}
+distance_f32! {
+    /// Calculates the Canberra distance between two vectors
+    ///
+    /// The returned value is `sum(|a[i] - b[i]| / (|a[i]| + |b[i]|))` over all values of i in
+    /// range. Terms where `a[i]` and `b[i]` are both zero are skipped.
+    canberra_distance_f32, arm_canberra_distance_f32
+}
+distance_f32! {
+    /// Calculates the Chebyshev distance between two vectors
+    ///
+    /// The returned value is `max(|a[i] - b[i]|)` over all values of i in range.
+    chebyshev_distance_f32, arm_chebyshev_distance_f32
+}
+distance_f32! {
+    /// Calculates the city block (Manhattan) distance between two vectors
+    ///
+    /// The returned value is `sum(|a[i] - b[i]|)` over all values of i in range.
+    cityblock_distance_f32, arm_cityblock_distance_f32
+}
+distance_f32! {
+    /// Calculates the cosine distance between two vectors
+    ///
+    /// The returned value is `1 - dot(a, b) / (|a| * |b|)`.
+    cosine_distance_f32, arm_cosine_distance_f32
+}
+distance_f32! {
+    /// Calculates the Jensen-Shannon distance between two probability distributions
+    ///
+    /// The values in b should be positive, except where the corresponding values in a are
+    /// zero. Otherwise, the result is not meaningful.
+    jensenshannon_distance_f32, arm_jensenshannon_distance_f32
+}
+
+/// Calculates the correlation distance between two vectors
+///
+/// The returned value is one minus the Pearson correlation coefficient of a and b.
+///
+/// This function uses a and b as temporary storage, so their contents are changed.
+///
+/// # Panics
+///
+/// This function panics if a and b do not have the same length.
+pub fn correlation_distance_f32(a: &mut [f32], b: &mut [f32]) -> f32 {
+    let length = check_length((a.len(), b.len()));
+    unsafe { cmsis_dsp_sys::arm_correlation_distance_f32(a.as_mut_ptr(), b.as_mut_ptr(), length) }
+}
+
+/// Calculates the Minkowski distance of the provided order between two vectors
+///
+/// The returned value is `sum(|a[i] - b[i]|^order)^(1 / order)` over all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if a and b do not have the same length, or if order is less than 1.
+pub fn minkowski_distance_f32(a: &[f32], b: &[f32], order: i32) -> f32 {
+    let length = check_length((a.len(), b.len()));
+    assert!(order >= 1, "Minkowski distance order less than 1");
+    unsafe { cmsis_dsp_sys::arm_minkowski_distance_f32(a.as_ptr(), b.as_ptr(), order, length) }
+}
+
+/// Returns the number of u32 words required to store the provided number of booleans
+pub fn bit_vector_words(bits: usize) -> usize {
+    bits.div_ceil(32)
+}
+
+/// A vector of booleans packed into u32 words
+///
+/// The first boolean is the most significant bit of the first word, and the 33rd boolean is
+/// the most significant bit of the second word. If the number of booleans is not a multiple
+/// of 32, the least significant bits of the last word are not used.
+pub struct BitVector<W> {
+    /// The words that contain the booleans
+    words: W,
+    /// The number of booleans
+    bits: usize,
+}
+
+impl<W> BitVector<W>
+where
+    W: AsRef<[u32]>,
+{
+    /// Creates a bit vector that contains the provided number of booleans
+    ///
+    /// This function returns an error if the number of words is not equal to
+    /// `bit_vector_words(bits)`.
+    pub fn new(words: W, bits: usize) -> Result<Self> {
+        let _: u32 = bits.try_into().map_err(|_| Error::Length)?;
+        if words.as_ref().len() != bit_vector_words(bits) {
+            return Err(Error::Length);
+        }
+        Ok(BitVector { words, bits })
+    }
+
+    /// Returns the number of booleans in this vector
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the words that contain the booleans
+    pub fn words(&self) -> &[u32] {
+        self.words.as_ref()
+    }
+
+    /// Returns the boolean at an index
+    ///
+    /// # Panics
+    ///
+    /// This function panics if index is not less than the number of booleans.
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.bits, "Bit index out of range");
+        self.words.as_ref()[index / 32] & (0x8000_0000 >> (index % 32)) != 0
+    }
+
+    /// Releases the words
+    pub fn into_inner(self) -> W {
+        self.words
+    }
+}
+
+/// Defines a distance function that takes two bit vectors
+macro_rules! boolean_distance {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $function:ident
+    ) => {
+        $(#[$attr])*
+        ///
+        /// # Panics
+        ///
+        /// This function panics if a and b do not contain the same number of booleans.
+        pub fn $name<A, B>(a: &BitVector<A>, b: &BitVector<B>) -> f32
+        where
+            A: AsRef<[u32]>,
+            B: AsRef<[u32]>,
+        {
+            let bits = check_length((a.bits(), b.bits()));
+            let (a, b) = (a.words(), b.words());
+            let words = bit_vector_words(bits as usize);
+            check_buffers(if a.len() == words && b.len() == words {
+                Ok(())
+            } else {
+                Err(Error::Length)
+            });
+            unsafe { cmsis_dsp_sys::$function(a.as_ptr(), b.as_ptr(), bits) }
+        }
+    };
+}
+
+boolean_distance! {
+    /// Calculates the Dice dissimilarity between two bit vectors
+    dice_distance, arm_dice_distance
+}
+boolean_distance! {
+    /// Calculates the Hamming distance between two bit vectors
+    ///
+    /// The returned value is the fraction of booleans that differ.
+    hamming_distance, arm_hamming_distance
+}
+boolean_distance! {
+    /// Calculates the Jaccard distance between two bit vectors
+    jaccard_distance, arm_jaccard_distance
+}
+boolean_distance! {
+    /// Calculates the Kulsinski dissimilarity between two bit vectors
+    kulsinski_distance, arm_kulsinski_distance
+}
+boolean_distance! {
+    /// Calculates the Rogers-Tanimoto dissimilarity between two bit vectors
+    rogerstanimoto_distance, arm_rogerstanimoto_distance
+}
+boolean_distance! {
+    /// Calculates the Russell-Rao dissimilarity between two bit vectors
+    russellrao_distance, arm_russellrao_distance
+}
+boolean_distance! {
+    /// Calculates the Sokal-Michener dissimilarity between two bit vectors
+    sokalmichener_distance, arm_sokalmichener_distance
+}
+boolean_distance! {
+    /// Calculates the Sokal-Sneath dissimilarity between two bit vectors
+    sokalsneath_distance, arm_sokalsneath_distance
+}
+boolean_distance! {
+    /// Calculates the Yule dissimilarity between two bit vectors
+    yule_distance, arm_yule_distance
+}
@@ -0,0 +1,262 @@
+//! Dynamic time warping
+
+use crate::matrix::{MatrixMut, MatrixRef};
+use crate::{Error, Result};
+
+/// Shapes of the window that limits which points of two sequences can be matched
+#[derive(Debug, Copy, Clone)]
+pub enum DtwWindow {
+    /// Allows matching query point q with reference point r if `|q - r| <= size`
+    SakoeChiba,
+    /// Allows matching query point q with reference point r if r is within size of the
+    /// diagonal of the distance matrix
+    SlantedBand,
+}
+
+/// Fills a window matrix for use with `dtw_distance_f32`
+///
+/// The window has one row for each point in the query sequence and one column for each point
+/// in the reference sequence. Each element is 1 if the points can be matched, or 0 otherwise.
+pub fn dtw_init_window(window_type: DtwWindow, size: usize, window: &mut MatrixMut<'_, i8>) {
+    let queries = usize::from(window.rows());
+    let references = usize::from(window.columns());
+    for (q, row) in window
+        .data_mut()
+        .chunks_mut(references.max(1))
+        .enumerate()
+        .take(queries)
+    {
+        let center = match window_type {
+            DtwWindow::SakoeChiba => q,
+            DtwWindow::SlantedBand => q * references / queries,
+        };
+        for (r, value) in row.iter_mut().enumerate() {
+            *value = if r.max(center) - r.min(center) <= size {
+                1
+            } else {
+                0
+            };
+        }
+    }
+}
+
+/// Calculates the dynamic time warping distance between two sequences
+///
+/// distances is the matrix of distances between the points of the sequences, with one row for
+/// each point in the query sequence and one column for each point in the reference sequence.
+/// It can be calculated using one of the distance functions. If window is provided, only the
+/// points allowed by the window can be matched.
+///
+/// When this function returns, dtw contains the accumulated cost matrix, which can be used
+/// with `dtw_path_f32`. The returned distance is the total cost normalized by the sum of the
+/// lengths of the sequences.
+///
+/// This function returns `Error::SizeMismatch` if window (if provided) and dtw do not have
+/// the same dimensions as distances, or if distances has no elements.
+pub fn dtw_distance_f32(
+    distances: &MatrixRef<'_, f32>,
+    window: Option<&MatrixRef<'_, i8>>,
+    dtw: &mut MatrixMut<'_, f32>,
+) -> Result<f32> {
+    let queries = usize::from(distances.rows());
+    let references = usize::from(distances.columns());
+    let window_matches = match window {
+        Some(window) => {
+            window.rows() == distances.rows() && window.columns() == distances.columns()
+        }
+        None => true,
+    };
+    if !window_matches
+        || dtw.rows() != distances.rows()
+        || dtw.columns() != distances.columns()
+        || queries == 0
+        || references == 0
+    {
+        return Err(Error::SizeMismatch);
+    }
+    let distance_data = distances.data();
+    let cost = dtw.data_mut();
+    for q in 0..queries {
+        for r in 0..references {
+            let index = q * references + r;
+            if let Some(window) = window {
+                if window.data()[index] == 0 {
+                    cost[index] = f32::MAX;
+                    continue;
+                }
+            }
+            let previous = match (q, r) {
+                (0, 0) => 0.0,
+                (0, _) => cost[index - 1],
+                (_, 0) => cost[index - references],
+                _ => cost[index - references - 1]
+                    .min(cost[index - references])
+                    .min(cost[index - 1]),
+            };
+            cost[index] = distance_data[index] + previous;
+        }
+    }
+    Ok(cost[queries * references - 1] / (queries + references) as f32)
+}
+
+/// Finds the lowest-cost path through an accumulated cost matrix calculated by
+/// `dtw_distance_f32`
+///
+/// Each element of the path is a pair `(query index, reference index)` of matched points. The
+/// path starts at `(0, 0)` and ends at the last point of both sequences. This function writes
+/// the path to the beginning of path and returns its length, which is at most
+/// `rows + columns - 1`.
+///
+/// # Panics
+///
+/// This function panics if dtw has no elements or path is too short.
+pub fn dtw_path_f32(dtw: &MatrixRef<'_, f32>, path: &mut [(u16, u16)]) -> usize {
+    let references = usize::from(dtw.columns());
+    assert!(
+        dtw.rows() != 0 && dtw.columns() != 0,
+        "DTW matrix has no elements"
+    );
+    let cost = dtw.data();
+    let (mut q, mut r) = (dtw.rows() - 1, dtw.columns() - 1);
+    let mut length = 0;
+    loop {
+        path[length] = (q, r);
+        length += 1;
+        if q == 0 && r == 0 {
+            break;
+        }
+        let at = |q: u16, r: u16| cost[usize::from(q) * references + usize::from(r)];
+        if q == 0 {
+            r -= 1;
+        } else if r == 0 {
+            q -= 1;
+        } else {
+            let diagonal = at(q - 1, r - 1);
+            let up = at(q - 1, r);
+            let left = at(q, r - 1);
+            if up < diagonal && up <= left {
+                q -= 1;
+            } else if left < diagonal {
+                r -= 1;
+            } else {
+                q -= 1;
+                r -= 1;
+            }
+        }
+    }
+    path[..length].reverse();
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Distances `|q - r|` between the query [0, 1, 2] and the reference [0, 1, 1, 2]
+    const DISTANCES: [f32; 12] = [
+        0.0, 1.0, 1.0, 2.0, //
+        1.0, 0.0, 0.0, 1.0, //
+        2.0, 1.0, 1.0, 0.0,
+    ];
+
+    #[test]
+    fn distance_and_path() {
+        let distances = MatrixRef::new(3, 4, &DISTANCES).unwrap();
+        let mut cost = [0.0; 12];
+        let distance = dtw_distance_f32(
+            &distances,
+            None,
+            &mut MatrixMut::new(3, 4, &mut cost).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(distance, 0.0);
+        assert_eq!(
+            cost,
+            [
+                0.0, 1.0, 2.0, 4.0, //
+                1.0, 0.0, 0.0, 1.0, //
+                3.0, 1.0, 1.0, 0.0,
+            ]
+        );
+
+        let mut path = [(0, 0); 6];
+        let length = dtw_path_f32(&MatrixRef::new(3, 4, &cost).unwrap(), &mut path);
+        assert_eq!(&path[..length], &[(0, 0), (1, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn distance_is_normalized() {
+        // Query [0, 2] and reference [1]
+        let distances = [1.0, 1.0];
+        let mut cost = [0.0; 2];
+        let distance = dtw_distance_f32(
+            &MatrixRef::new(2, 1, &distances).unwrap(),
+            None,
+            &mut MatrixMut::new(2, 1, &mut cost).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(cost, [1.0, 2.0]);
+        assert_eq!(distance, 2.0 / 3.0);
+
+        let mut path = [(0, 0); 2];
+        let length = dtw_path_f32(&MatrixRef::new(2, 1, &cost).unwrap(), &mut path);
+        assert_eq!(&path[..length], &[(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn windows() {
+        let mut window = [0; 12];
+        dtw_init_window(
+            DtwWindow::SakoeChiba,
+            1,
+            &mut MatrixMut::new(3, 4, &mut window).unwrap(),
+        );
+        assert_eq!(
+            window,
+            [
+                1, 1, 0, 0, //
+                1, 1, 1, 0, //
+                0, 1, 1, 1,
+            ]
+        );
+
+        let mut band = [0; 8];
+        dtw_init_window(
+            DtwWindow::SlantedBand,
+            0,
+            &mut MatrixMut::new(2, 4, &mut band).unwrap(),
+        );
+        assert_eq!(band, [1, 0, 0, 0, 0, 0, 1, 0]);
+
+        // The optimal path stays inside the Sakoe-Chiba window
+        let mut cost = [0.0; 12];
+        let distance = dtw_distance_f32(
+            &MatrixRef::new(3, 4, &DISTANCES).unwrap(),
+            Some(&MatrixRef::new(3, 4, &window).unwrap()),
+            &mut MatrixMut::new(3, 4, &mut cost).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(distance, 0.0);
+        assert_eq!(cost[2], f32::MAX);
+        assert_eq!(cost[8], f32::MAX);
+    }
+
+    #[test]
+    fn size_mismatch() {
+        let distances = MatrixRef::new(3, 4, &DISTANCES).unwrap();
+        let mut cost = [0.0; 12];
+        let result = dtw_distance_f32(
+            &distances,
+            None,
+            &mut MatrixMut::new(4, 3, &mut cost).unwrap(),
+        );
+        assert!(matches!(result, Err(Error::SizeMismatch)));
+        let window = [1; 12];
+        let result = dtw_distance_f32(
+            &distances,
+            Some(&MatrixRef::new(2, 6, &window).unwrap()),
+            &mut MatrixMut::new(3, 4, &mut cost).unwrap(),
+        );
+        assert!(matches!(result, Err(Error::SizeMismatch)));
+    }
+}
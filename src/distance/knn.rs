@@ -0,0 +1,147 @@
+//! k-nearest-neighbor classification
+
+use super::{
+    braycurtis_distance_f32, canberra_distance_f32, chebyshev_distance_f32, cityblock_distance_f32,
+    cosine_distance_f32, euclidean_distance_f32, minkowski_distance_f32,
+};
+use crate::matrix::MatrixRef;
+use crate::{Error, Result};
+
+/// Distance metrics for nearest-neighbor searches
+#[derive(Debug, Copy, Clone)]
+pub enum Metric {
+    /// Euclidean distance
+    Euclidean,
+    /// Bray-Curtis distance
+    BrayCurtis,
+    /// Canberra distance
+    Canberra,
+    /// Chebyshev distance
+    Chebyshev,
+    /// City block (Manhattan) distance
+    CityBlock,
+    /// Cosine distance
+    Cosine,
+    /// Minkowski distance of the provided order, which must be at least 1
+    Minkowski(i32),
+}
+
+impl Metric {
+    /// Calculates the distance between two vectors using this metric
+    ///
+    /// # Panics
+    ///
+    /// This function panics if a and b do not have the same length.
+    pub fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        match *self {
+            Metric::Euclidean => euclidean_distance_f32(a, b),
+            Metric::BrayCurtis => braycurtis_distance_f32(a, b),
+            Metric::Canberra => canberra_distance_f32(a, b),
+            Metric::Chebyshev => chebyshev_distance_f32(a, b),
+            Metric::CityBlock => cityblock_distance_f32(a, b),
+            Metric::Cosine => cosine_distance_f32(a, b),
+            Metric::Minkowski(order) => minkowski_distance_f32(a, b, order),
+        }
+    }
+}
+
+/// A template found by a nearest-neighbor search
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Neighbor {
+    /// The index of the template (its row in the template matrix)
+    pub index: usize,
+    /// The class label of the template
+    pub label: i32,
+    /// The distance between the template and the query
+    pub distance: f32,
+}
+
+/// Finds the templates that are nearest to a query vector
+///
+/// Each row of templates is one template vector, and labels contains the class label of each
+/// template. This function finds up to `neighbors.len()` nearest templates, writes them to
+/// the beginning of neighbors in order of increasing distance, and returns the number of
+/// neighbors found.
+///
+/// This function returns `Error::SizeMismatch` if the length of query is not equal to the
+/// number of columns in templates, or `Error::Length` if the length of labels is not equal
+/// to the number of rows in templates.
+pub fn k_nearest_f32(
+    templates: &MatrixRef<'_, f32>,
+    labels: &[i32],
+    query: &[f32],
+    metric: Metric,
+    neighbors: &mut [Neighbor],
+) -> Result<usize> {
+    if query.len() != usize::from(templates.columns()) {
+        return Err(Error::SizeMismatch);
+    }
+    if labels.len() != usize::from(templates.rows()) {
+        return Err(Error::Length);
+    }
+    let mut found = 0;
+    let columns = query.len().max(1);
+    for (index, (template, &label)) in templates
+        .data()
+        .chunks(columns)
+        .zip(labels.iter())
+        .enumerate()
+    {
+        let distance = metric.distance(template, query);
+        // Insert the new neighbor in order, discarding the farthest neighbor if the list
+        // is full
+        let position = neighbors[..found]
+            .iter()
+            .position(|neighbor| distance < neighbor.distance)
+            .unwrap_or(found);
+        if position == neighbors.len() {
+            continue;
+        }
+        if found < neighbors.len() {
+            found += 1;
+        }
+        neighbors[position..found].rotate_right(1);
+        neighbors[position] = Neighbor {
+            index,
+            label,
+            distance,
+        };
+    }
+    Ok(found)
+}
+
+/// Classifies a query vector by a majority vote of its nearest templates
+///
+/// This function finds the `neighbors.len()` nearest templates using `k_nearest_f32` and
+/// returns the most common label among them. If several labels are equally common, the label
+/// of the nearest template among them is returned. When this function returns, neighbors
+/// contains the nearest templates, as described for `k_nearest_f32`.
+///
+/// This function returns `Ok(None)` if there are no templates or neighbors is empty. It
+/// returns an error in the same cases as `k_nearest_f32`.
+pub fn knn_classify_f32(
+    templates: &MatrixRef<'_, f32>,
+    labels: &[i32],
+    query: &[f32],
+    metric: Metric,
+    neighbors: &mut [Neighbor],
+) -> Result<Option<i32>> {
+    let found = k_nearest_f32(templates, labels, query, metric, neighbors)?;
+    let neighbors = &neighbors[..found];
+    let mut best: Option<(i32, usize)> = None;
+    // Neighbors are sorted by distance, so on a tie the earlier label wins
+    for neighbor in neighbors {
+        let votes = neighbors
+            .iter()
+            .filter(|other| other.label == neighbor.label)
+            .count();
+        let more_votes = match best {
+            Some((_, best_votes)) => votes > best_votes,
+            None => true,
+        };
+        if more_votes {
+            best = Some((neighbor.label, votes));
+        }
+    }
+    Ok(best.map(|(label, _)| label))
+}
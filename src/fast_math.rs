@@ -0,0 +1,427 @@
+//! Fast approximations of mathematical functions
+//!
+//! These functions use lookup tables with linear interpolation. They are usually faster than
+//! the equivalent functions in `libm` or `micromath`, but less accurate.
+//!
+//! The fixed-point trigonometric functions take angles as fractions of a full rotation: an
+//! input value x represents an angle of `2 * pi * x` radians. Because the fixed-point inputs are
+//! in the range [-1, 1), negative values represent the same angles as the corresponding values
+//! in [0, 1). For example, -0.25 and 0.75 both represent `1.5 * pi` radians. The CMSIS-DSP
+//! functions only accept values in [0, 1), so these functions wrap negative values into that
+//! range first.
+
+use core::f32::consts::{FRAC_PI_2, PI};
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31, I3F13, I3F29, I5F11, I6F26};
+
+#[cfg(feature = "fixed")]
+use crate::support::{q15_as_bits, q31_as_bits};
+use crate::{check_length, Error, Result};
+
+pub mod raw;
+
+/// Calculates the sine of an angle in radians
+pub fn sin_f32(x: f32) -> f32 {
+    unsafe { cmsis_dsp_sys::arm_sin_f32(x) }
+}
+
+/// Calculates the cosine of an angle in radians
+pub fn cos_f32(x: f32) -> f32 {
+    unsafe { cmsis_dsp_sys::arm_cos_f32(x) }
+}
+
+/// Calculates the sine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+#[cfg(feature = "fixed")]
+pub fn sin_q31(x: I1F31) -> I1F31 {
+    I1F31::from_bits(raw::sin_q31(x.to_bits()))
+}
+
+/// Calculates the cosine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+#[cfg(feature = "fixed")]
+pub fn cos_q31(x: I1F31) -> I1F31 {
+    I1F31::from_bits(raw::cos_q31(x.to_bits()))
+}
+
+/// Calculates the sine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+#[cfg(feature = "fixed")]
+pub fn sin_q15(x: I1F15) -> I1F15 {
+    I1F15::from_bits(raw::sin_q15(x.to_bits()))
+}
+
+/// Calculates the cosine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+#[cfg(feature = "fixed")]
+pub fn cos_q15(x: I1F15) -> I1F15 {
+    I1F15::from_bits(raw::cos_q15(x.to_bits()))
+}
+
+/// Calculates the square root of a value
+///
+/// CMSIS-DSP implements `arm_sqrt_f32` as an inline function, so this function is implemented
+/// in Rust in the same way, using the C standard library `sqrtf` function.
+///
+/// This function returns an error if x is negative.
+pub fn sqrt_f32(x: f32) -> Result<f32> {
+    if x >= 0.0 {
+        Ok(unsafe { crate::sqrtf(x) })
+    } else {
+        Err(Error::Argument)
+    }
+}
+
+/// Calculates the square root of a value
+///
+/// This function returns an error if x is negative.
+#[cfg(feature = "fixed")]
+pub fn sqrt_q31(x: I1F31) -> Result<I1F31> {
+    raw::sqrt_q31(x.to_bits()).map(I1F31::from_bits)
+}
+
+/// Calculates the square root of a value
+///
+/// This function returns an error if x is negative.
+#[cfg(feature = "fixed")]
+pub fn sqrt_q15(x: I1F15) -> Result<I1F15> {
+    raw::sqrt_q15(x.to_bits()).map(I1F15::from_bits)
+}
+
+/// Calculates the natural logarithm of each value in a block
+///
+/// This is functionally equivalent to performing `dst[i] = ln(src[i])` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn vlog_f32(src: &[f32], dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_vlog_f32(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the exponential of each value in a block
+///
+/// This is functionally equivalent to performing `dst[i] = exp(src[i])` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn vexp_f32(src: &[f32], dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_vexp_f32(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Calculates the natural logarithm of each value in a block of Q1.31 values
+///
+/// The results are in Q6.26 format, which can represent the logarithm of every positive Q1.31
+/// value. The logarithm of zero or a negative value is the minimum Q6.26 value.
+///
+/// This function uses only integer operations.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn vlog_q31(src: &[I1F31], dst: &mut [I6F26]) {
+    // I6F26 has the same representation as i32
+    let dst = unsafe { core::slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut i32, dst.len()) };
+    raw::vlog_q31(q31_as_bits(src), dst)
+}
+
+/// Calculates the natural logarithm of each value in a block of Q1.15 values
+///
+/// The results are in Q5.11 format, which can represent the logarithm of every positive Q1.15
+/// value. The logarithm of zero or a negative value is the minimum Q5.11 value.
+///
+/// This function uses only integer operations.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn vlog_q15(src: &[I1F15], dst: &mut [I5F11]) {
+    // I5F11 has the same representation as i16
+    let dst = unsafe { core::slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut i16, dst.len()) };
+    raw::vlog_q15(q15_as_bits(src), dst)
+}
+
+/// Calculates the angle of the point (x, y), in radians
+///
+/// The result is in the range [-pi, pi]. This function returns `Error::NanInf` if x and y are
+/// both zero or either is NaN.
+///
+/// This function uses a polynomial approximation with a maximum error of about 1e-5 radians.
+pub fn atan2_f32(y: f32, x: f32) -> Result<f32> {
+    if (x == 0.0 && y == 0.0) || x.is_nan() || y.is_nan() {
+        return Err(Error::NanInf);
+    }
+    let (x_abs, y_abs) = (x.abs(), y.abs());
+    let mut angle = if y_abs <= x_abs {
+        atan_unit_f32(y_abs / x_abs)
+    } else {
+        FRAC_PI_2 - atan_unit_f32(x_abs / y_abs)
+    };
+    if x < 0.0 {
+        angle = PI - angle;
+    }
+    if y < 0.0 {
+        angle = -angle;
+    }
+    Ok(angle)
+}
+
+/// Calculates the angle of the point (x, y)
+///
+/// The result is in radians, in Q3.29 format, in the range [-pi, pi]. This function returns
+/// `Error::NanInf` if x and y are both zero.
+///
+/// This function uses only integer operations.
+#[cfg(feature = "fixed")]
+pub fn atan2_q31(y: I1F31, x: I1F31) -> Result<I3F29> {
+    raw::atan2_q31(y.to_bits(), x.to_bits()).map(I3F29::from_bits)
+}
+
+/// Calculates the angle of the point (x, y)
+///
+/// The result is in radians, in Q3.13 format, in the range [-pi, pi]. This function returns
+/// `Error::NanInf` if x and y are both zero.
+///
+/// This function uses only integer operations.
+#[cfg(feature = "fixed")]
+pub fn atan2_q15(y: I1F15, x: I1F15) -> Result<I3F13> {
+    raw::atan2_q15(y.to_bits(), x.to_bits()).map(I3F13::from_bits)
+}
+
+/// The result of a fixed-point division
+///
+/// The value of the quotient is `quotient * 2^shift`. The shift allows quotients with a
+/// magnitude of 1 or more to be represented.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Quotient<T> {
+    /// The quotient, scaled down by 2^shift
+    pub quotient: T,
+    /// The number of bits to shift the quotient left
+    pub shift: u32,
+}
+
+/// Divides two Q1.15 values
+///
+/// This function returns `Error::NanInf` if the denominator is zero.
+///
+/// The quotient is scaled in the same way as `arm_divide_q15` in later versions of CMSIS-DSP.
+#[cfg(feature = "fixed")]
+pub fn divide_q15(numerator: I1F15, denominator: I1F15) -> Result<Quotient<I1F15>> {
+    let Quotient { quotient, shift } = raw::divide_q15(numerator.to_bits(), denominator.to_bits())?;
+    Ok(Quotient {
+        quotient: I1F15::from_bits(quotient),
+        shift,
+    })
+}
+
+/// Divides two Q1.31 values
+///
+/// This function returns `Error::NanInf` if the denominator is zero.
+///
+/// The quotient is scaled in the same way as `arm_divide_q31` in later versions of CMSIS-DSP.
+#[cfg(feature = "fixed")]
+pub fn divide_q31(numerator: I1F31, denominator: I1F31) -> Result<Quotient<I1F31>> {
+    let Quotient { quotient, shift } = raw::divide_q31(numerator.to_bits(), denominator.to_bits())?;
+    Ok(Quotient {
+        quotient: I1F31::from_bits(quotient),
+        shift,
+    })
+}
+
+/// Coefficients of the odd polynomial used to approximate atan(x) for x in [0, 1], from the
+/// x^1 term to the x^11 term
+const ATAN_COEFFICIENTS: [f32; 6] = [
+    0.999_977_26,
+    -0.332_623_47,
+    0.193_543_46,
+    -0.116_432_87,
+    0.052_653_32,
+    -0.011_721_2,
+];
+
+/// Approximates atan(x) for x in [0, 1]
+fn atan_unit_f32(x: f32) -> f32 {
+    let x2 = x * x;
+    let sum = ATAN_COEFFICIENTS
+        .iter()
+        .rev()
+        .fold(0.0, |sum, &coefficient| sum * x2 + coefficient);
+    sum * x
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_4;
+
+    use super::*;
+
+    /// Reference values of atan2(y, x) in radians
+    const ATAN2_REFERENCE: [(f32, f32, f32); 8] = [
+        (0.5, 0.5, FRAC_PI_4),
+        (0.5, -0.5, PI - FRAC_PI_4),
+        (-0.5, -0.5, FRAC_PI_4 - PI),
+        (-0.5, 0.5, -FRAC_PI_4),
+        (0.5, 0.25, 1.107_148_7),
+        (0.125, 0.75, 0.165_148_7),
+        (0.0, -0.5, PI),
+        (-0.5, 0.0, -FRAC_PI_2),
+    ];
+
+    #[test]
+    fn atan2_f32_reference() {
+        for &(y, x, expected) in ATAN2_REFERENCE.iter() {
+            let angle = atan2_f32(y, x).unwrap();
+            assert!(
+                (angle - expected).abs() < 2e-5,
+                "atan2({}, {}) = {}",
+                y,
+                x,
+                angle
+            );
+        }
+        assert!(matches!(atan2_f32(0.0, 0.0), Err(Error::NanInf)));
+        assert!(matches!(atan2_f32(f32::NAN, 1.0), Err(Error::NanInf)));
+    }
+
+    #[test]
+    fn atan2_q31_reference() {
+        for &(y, x, expected) in ATAN2_REFERENCE.iter() {
+            let y = (y * 2_147_483_648.0) as i32;
+            let x = (x * 2_147_483_648.0) as i32;
+            let angle = raw::atan2_q31(y, x).unwrap() as f32 / (1 << 29) as f32;
+            assert!(
+                (angle - expected).abs() < 2e-5,
+                "atan2({}, {}) = {}",
+                y,
+                x,
+                angle
+            );
+        }
+        assert!(matches!(raw::atan2_q31(0, 0), Err(Error::NanInf)));
+        // -1 is a valid input, and the result stays in range
+        let angle = raw::atan2_q31(0, i32::MIN).unwrap() as f32 / (1 << 29) as f32;
+        assert!((angle - PI).abs() < 2e-5);
+    }
+
+    #[test]
+    fn atan2_q15_reference() {
+        for &(y, x, expected) in ATAN2_REFERENCE.iter() {
+            let y = (y * 32768.0) as i16;
+            let x = (x * 32768.0) as i16;
+            let angle = f32::from(raw::atan2_q15(y, x).unwrap()) / (1 << 13) as f32;
+            // The result has 13 fractional bits
+            assert!(
+                (angle - expected).abs() < 2e-4,
+                "atan2({}, {}) = {}",
+                y,
+                x,
+                angle
+            );
+        }
+        assert!(matches!(raw::atan2_q15(0, 0), Err(Error::NanInf)));
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn atan2_fixed_matches_raw() {
+        let y = I1F31::from_num(0.5);
+        let x = I1F31::from_num(-0.25);
+        let angle = atan2_q31(y, x).unwrap();
+        assert_eq!(
+            angle.to_bits(),
+            raw::atan2_q31(y.to_bits(), x.to_bits()).unwrap()
+        );
+        let y = I1F15::from_num(-0.5);
+        let x = I1F15::from_num(0.25);
+        let angle = atan2_q15(y, x).unwrap();
+        assert_eq!(
+            angle.to_bits(),
+            raw::atan2_q15(y.to_bits(), x.to_bits()).unwrap()
+        );
+    }
+
+    #[test]
+    fn divide_q15_reference() {
+        // (numerator, denominator, quotient, shift)
+        let reference = [
+            // 0.5 / 0.25 = 0.5 * 2^2
+            (16384, 8192, 16384, 2),
+            // 0.25 / 0.5 = 0.5
+            (8192, 16384, 16384, 0),
+            // -0.5 / 0.25 = -0.5 * 2^2
+            (-16384, 8192, -16384, 2),
+            // -1 / -1 = 0.5 * 2^1
+            (i16::MIN, i16::MIN, 16384, 1),
+            // 0.375 / -0.75 = -0.5
+            (12288, -24576, -16384, 0),
+            (0, 1000, 0, 0),
+        ];
+        for &(numerator, denominator, quotient, shift) in reference.iter() {
+            let result = raw::divide_q15(numerator, denominator).unwrap();
+            assert_eq!(
+                result,
+                Quotient { quotient, shift },
+                "{} / {}",
+                numerator,
+                denominator
+            );
+        }
+        assert!(matches!(raw::divide_q15(1, 0), Err(Error::NanInf)));
+    }
+
+    #[test]
+    fn divide_q31_reference() {
+        // (numerator, denominator, quotient, shift)
+        let reference = [
+            // 0.5 / 0.25 = 0.5 * 2^2
+            (1 << 30, 1 << 29, 1 << 30, 2),
+            // 0.25 / 0.5 = 0.5
+            (1 << 29, 1 << 30, 1 << 30, 0),
+            // -0.5 / 0.25 = -0.5 * 2^2
+            (-(1 << 30), 1 << 29, -(1 << 30), 2),
+            // -1 / -1 = 0.5 * 2^1
+            (i32::MIN, i32::MIN, 1 << 30, 1),
+            // 0.375 / -0.75 = -0.5
+            (3 << 28, -(3 << 29), -(1 << 30), 0),
+            (0, 1000, 0, 0),
+        ];
+        for &(numerator, denominator, quotient, shift) in reference.iter() {
+            let result = raw::divide_q31(numerator, denominator).unwrap();
+            assert_eq!(
+                result,
+                Quotient { quotient, shift },
+                "{} / {}",
+                numerator,
+                denominator
+            );
+        }
+        assert!(matches!(raw::divide_q31(1, 0), Err(Error::NanInf)));
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn divide_fixed_matches_raw() {
+        let result = divide_q15(I1F15::from_num(0.5), I1F15::from_num(0.25)).unwrap();
+        assert_eq!(result.quotient, I1F15::from_num(0.5));
+        assert_eq!(result.shift, 2);
+        let result = divide_q31(I1F31::from_num(-0.25), I1F31::from_num(0.5)).unwrap();
+        assert_eq!(result.quotient, I1F31::from_num(-0.5));
+        assert_eq!(result.shift, 0);
+    }
+}
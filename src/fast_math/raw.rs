@@ -0,0 +1,345 @@
+//! Fast approximations of mathematical functions on fixed-point values stored as raw integers
+//!
+//! These functions have the same names and behavior as the fixed-point functions in the parent
+//! module, but they take and return `i32` and `i16` values that hold the bits of fixed-point
+//! values. They are available whether or not the `fixed` feature is enabled.
+
+use super::Quotient;
+use crate::{check_length, Error, Result, StatusCode};
+
+/// Calculates the sine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+pub fn sin_q31(x: i32) -> i32 {
+    unsafe { cmsis_dsp_sys::arm_sin_q31(x & 0x7fff_ffff) }
+}
+
+/// Calculates the cosine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+pub fn cos_q31(x: i32) -> i32 {
+    unsafe { cmsis_dsp_sys::arm_cos_q31(x & 0x7fff_ffff) }
+}
+
+/// Calculates the sine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+pub fn sin_q15(x: i16) -> i16 {
+    unsafe { cmsis_dsp_sys::arm_sin_q15(x & 0x7fff) }
+}
+
+/// Calculates the cosine of an angle expressed as a fraction of a full rotation
+///
+/// An input value x represents an angle of `2 * pi * x` radians.
+pub fn cos_q15(x: i16) -> i16 {
+    unsafe { cmsis_dsp_sys::arm_cos_q15(x & 0x7fff) }
+}
+
+/// Calculates the square root of a value
+///
+/// This function returns an error if x is negative.
+pub fn sqrt_q31(x: i32) -> Result<i32> {
+    let mut result = 0;
+    unsafe { cmsis_dsp_sys::arm_sqrt_q31(x, &mut result) }.check_status()?;
+    Ok(result)
+}
+
+/// Calculates the square root of a value
+///
+/// This function returns an error if x is negative.
+pub fn sqrt_q15(x: i16) -> Result<i16> {
+    let mut result = 0;
+    unsafe { cmsis_dsp_sys::arm_sqrt_q15(x, &mut result) }.check_status()?;
+    Ok(result)
+}
+
+/// Calculates the natural logarithm of each value in a block of Q1.31 values
+///
+/// The results are in Q6.26 format, which can represent the logarithm of every positive Q1.31
+/// value. The logarithm of zero or a negative value is the minimum Q6.26 value.
+///
+/// This function uses only integer operations.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn vlog_q31(src: &[i32], dst: &mut [i32]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = match ln_bits(*src, 31) {
+            Some(ln) => (ln >> (LN_FRAC_BITS - 26)) as i32,
+            None => i32::MIN,
+        };
+    }
+}
+
+/// Calculates the natural logarithm of each value in a block of Q1.15 values
+///
+/// The results are in Q5.11 format, which can represent the logarithm of every positive Q1.15
+/// value. The logarithm of zero or a negative value is the minimum Q5.11 value.
+///
+/// This function uses only integer operations.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn vlog_q15(src: &[i16], dst: &mut [i16]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = match ln_bits(i32::from(*src), 15) {
+            Some(ln) => (ln >> (LN_FRAC_BITS - 11)) as i16,
+            None => i16::MIN,
+        };
+    }
+}
+
+/// Calculates the angle of the point (x, y)
+///
+/// The result is in radians, in Q3.29 format, in the range [-pi, pi]. This function returns
+/// `Error::NanInf` if x and y are both zero.
+///
+/// This function uses only integer operations.
+pub fn atan2_q31(y: i32, x: i32) -> Result<i32> {
+    let angle = atan2_bits(y.into(), x.into())?;
+    Ok((angle >> (31 - 29)) as i32)
+}
+
+/// Calculates the angle of the point (x, y)
+///
+/// The result is in radians, in Q3.13 format, in the range [-pi, pi]. This function returns
+/// `Error::NanInf` if x and y are both zero.
+///
+/// This function uses only integer operations.
+pub fn atan2_q15(y: i16, x: i16) -> Result<i16> {
+    let angle = atan2_bits(i64::from(y) << 16, i64::from(x) << 16)?;
+    Ok((angle >> (31 - 13)) as i16)
+}
+
+/// Divides two Q1.15 values
+///
+/// This function returns `Error::NanInf` if the denominator is zero.
+///
+/// The quotient is scaled in the same way as `arm_divide_q15` in later versions of CMSIS-DSP.
+pub fn divide_q15(numerator: i16, denominator: i16) -> Result<Quotient<i16>> {
+    let numerator = i32::from(numerator);
+    let denominator = i32::from(denominator);
+    if denominator == 0 {
+        return Err(Error::NanInf);
+    }
+    let negative = (numerator < 0) != (denominator < 0);
+    let mut quotient = (numerator.abs() << 15) / denominator.abs();
+    let shift = 17u32.saturating_sub(quotient.leading_zeros());
+    quotient >>= shift;
+    if negative {
+        quotient = -quotient;
+    }
+    Ok(Quotient {
+        quotient: quotient as i16,
+        shift,
+    })
+}
+
+/// Divides two Q1.31 values
+///
+/// This function returns `Error::NanInf` if the denominator is zero.
+///
+/// The quotient is scaled in the same way as `arm_divide_q31` in later versions of CMSIS-DSP.
+pub fn divide_q31(numerator: i32, denominator: i32) -> Result<Quotient<i32>> {
+    let numerator = i64::from(numerator);
+    let denominator = i64::from(denominator);
+    if denominator == 0 {
+        return Err(Error::NanInf);
+    }
+    let negative = (numerator < 0) != (denominator < 0);
+    let mut quotient = (numerator.abs() << 31) / denominator.abs();
+    let shift = 33u32.saturating_sub(quotient.leading_zeros());
+    quotient >>= shift;
+    if negative {
+        quotient = -quotient;
+    }
+    Ok(Quotient {
+        quotient: quotient as i32,
+        shift,
+    })
+}
+
+/// The number of fractional bits in the results of ln_bits
+const LN_FRAC_BITS: u32 = 30;
+
+/// Calculates the natural logarithm of a positive fixed-point value
+///
+/// bits is the representation of the value, which has frac_bits fractional bits. The result
+/// has LN_FRAC_BITS fractional bits. This function returns None if the value is not positive.
+fn ln_bits(bits: i32, frac_bits: u32) -> Option<i64> {
+    if bits <= 0 {
+        return None;
+    }
+    // ln(2) with 32 fractional bits
+    const LN_2: i128 = 2_977_044_472;
+    // bits = m * 2^exponent, with m in [1, 2)
+    let exponent = 31 - bits.leading_zeros();
+    // m with LN_FRAC_BITS fractional bits
+    let mut m = (bits as u64) << (LN_FRAC_BITS - exponent);
+    // Calculate log2(m) one bit at a time: squaring m doubles its logarithm
+    let mut log2_m = 0i64;
+    for bit in (0..LN_FRAC_BITS).rev() {
+        m = (m * m) >> LN_FRAC_BITS;
+        if m >= 2 << LN_FRAC_BITS {
+            m >>= 1;
+            log2_m |= 1 << bit;
+        }
+    }
+    let log2 = ((i64::from(exponent) - i64::from(frac_bits)) << LN_FRAC_BITS) + log2_m;
+    Some(((i128::from(log2) * LN_2) >> 32) as i64)
+}
+
+/// ATAN_COEFFICIENTS from the parent module, with 31 fractional bits
+const ATAN_COEFFICIENTS_Q31: [i64; 6] = [
+    2_147_434_814,
+    -714_303_463,
+    415_631_416,
+    -250_037_684,
+    113_072_144,
+    -25_171_085,
+];
+
+/// Calculates the angle of the point (x, y) in radians
+///
+/// x and y have 31 fractional bits and must be in the range [-1, 1]. The result has 31
+/// fractional bits.
+fn atan2_bits(y: i64, x: i64) -> Result<i64> {
+    // pi and pi / 2 with 31 fractional bits
+    const PI_Q31: i64 = 6_746_518_852;
+    const FRAC_PI_2_Q31: i64 = 3_373_259_426;
+    if x == 0 && y == 0 {
+        return Err(Error::NanInf);
+    }
+    let (x_abs, y_abs) = (x.abs(), y.abs());
+    let mut angle = if y_abs <= x_abs {
+        atan_unit_bits((y_abs << 31) / x_abs)
+    } else {
+        FRAC_PI_2_Q31 - atan_unit_bits((x_abs << 31) / y_abs)
+    };
+    if x < 0 {
+        angle = PI_Q31 - angle;
+    }
+    if y < 0 {
+        angle = -angle;
+    }
+    Ok(angle)
+}
+
+/// Approximates atan(x) for x in [0, 1]
+///
+/// x and the result have 31 fractional bits.
+fn atan_unit_bits(x: i64) -> i64 {
+    let x2 = (x * x) >> 31;
+    let sum = ATAN_COEFFICIENTS_Q31
+        .iter()
+        .rev()
+        .fold(0, |sum, &coefficient| ((sum * x2) >> 31) + coefficient);
+    (sum * x) >> 31
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that a result is within one least significant bit of a reference value
+    fn assert_close(actual: i64, expected: i64, input: i32) {
+        assert!(
+            (actual - expected).abs() <= 1,
+            "ln({}) = {}, expected {}",
+            input,
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn ln_bits_reference() {
+        // ln(1) = 0
+        assert_eq!(ln_bits(1, 0), Some(0));
+        assert_eq!(ln_bits(1 << 30, 30), Some(0));
+        // (bits, frac_bits, ln(bits / 2^frac_bits) with 30 fractional bits)
+        let reference = [
+            (2, 0, 744_261_117),
+            (3, 0, 1_179_625_962),
+            (1, 31, -23_072_094_657),
+            (i32::MAX, 31, -1),
+            (1 << 30, 31, -744_261_118),
+            ((1 << 30) - 1, 31, -744_261_119),
+            ((1 << 30) + 1, 31, -744_261_117),
+            (1 << 29, 31, -1_488_522_236),
+            (3 << 29, 31, -308_896_274),
+        ];
+        for &(bits, frac_bits, expected) in reference.iter() {
+            assert_close(ln_bits(bits, frac_bits).unwrap(), expected, bits);
+        }
+        assert_eq!(ln_bits(0, 31), None);
+        assert_eq!(ln_bits(-1, 31), None);
+        assert_eq!(ln_bits(i32::MIN, 31), None);
+    }
+
+    #[test]
+    fn vlog_q31_reference() {
+        // (input, ln(input) in Q6.26)
+        let reference: [(i32, i32); 7] = [
+            // The smallest positive value, 2^-31
+            (1, -1_442_005_917),
+            // The largest value, 1 - 2^-31
+            (i32::MAX, -1),
+            // Values near 0.5
+            (1 << 30, -46_516_320),
+            ((1 << 30) - 1, -46_516_320),
+            ((1 << 30) + 1, -46_516_320),
+            (1 << 29, -93_032_640),
+            (3 << 29, -19_306_018),
+        ];
+        let mut src = [0; 7];
+        for (src, &(input, _)) in src.iter_mut().zip(reference.iter()) {
+            *src = input;
+        }
+        let mut dst = [0; 7];
+        vlog_q31(&src, &mut dst);
+        for (&(input, expected), &actual) in reference.iter().zip(dst.iter()) {
+            assert_close(actual.into(), expected.into(), input);
+        }
+
+        // Zero and negative values produce the minimum value
+        let mut dst = [0; 3];
+        vlog_q31(&[0, -1, i32::MIN], &mut dst);
+        assert_eq!(dst, [i32::MIN; 3]);
+    }
+
+    #[test]
+    fn vlog_q15_reference() {
+        // (input, ln(input) in Q5.11)
+        let reference: [(i16, i16); 7] = [
+            // The smallest positive value, 2^-15
+            (1, -21_294),
+            // The largest value, 1 - 2^-15
+            (i16::MAX, -1),
+            // Values near 0.5
+            (1 << 14, -1_420),
+            ((1 << 14) - 1, -1_420),
+            ((1 << 14) + 1, -1_420),
+            (1 << 13, -2_840),
+            (3 << 13, -590),
+        ];
+        let mut src = [0; 7];
+        for (src, &(input, _)) in src.iter_mut().zip(reference.iter()) {
+            *src = input;
+        }
+        let mut dst = [0; 7];
+        vlog_q15(&src, &mut dst);
+        for (&(input, expected), &actual) in reference.iter().zip(dst.iter()) {
+            assert_close(actual.into(), expected.into(), input.into());
+        }
+
+        // Zero and negative values produce the minimum value
+        let mut dst = [0; 3];
+        vlog_q15(&[0, -1, i16::MIN], &mut dst);
+        assert_eq!(dst, [i16::MIN; 3]);
+    }
+}
@@ -0,0 +1,92 @@
+//! Filtering functions
+//!
+//! The filter types in this module can own or borrow their coefficients and state buffers.
+//! Any type that implements `AsRef<[T]>` (for coefficients) or `AsMut<[T]>` (for state) can
+//! be used, including arrays, slices, and references to arrays.
+//!
+//! The lengths of the buffers are checked when a filter is created, and checked again each time
+//! the filter runs. If `as_ref` or `as_mut` returns a slice with a different length from the one
+//! that was checked when the filter was created, the filter panics.
+
+mod adaptive;
+mod biquad;
+mod convolution;
+pub mod design;
+mod fir;
+mod lattice;
+mod multichannel;
+mod multirate;
+pub mod raw;
+mod sparse;
+
+pub use self::adaptive::{LmsFilterF32, NlmsFilterF32};
+#[cfg(feature = "fixed")]
+pub use self::adaptive::{LmsFilterQ15, LmsFilterQ31, NlmsFilterQ15, NlmsFilterQ31};
+pub use self::biquad::{
+    BiquadCascadeDf1F32, BiquadCascadeDf2TF32, BiquadCascadeDf2TF64, BiquadCascadeStereoDf2TF32,
+    PostShift,
+};
+#[cfg(feature = "fixed")]
+pub use self::biquad::{BiquadCascadeDf1Q15, BiquadCascadeDf1Q31, BiquadCascadeDf1Q31x64};
+pub use self::convolution::{
+    conv_f32, conv_length, correlate_f32, correlate_length, correlate_peak_lag_f32,
+    fft_convolver_buffer_length, FftConvolverF32,
+};
+pub use self::fir::{fir_state_length, FirF32};
+#[cfg(feature = "fixed")]
+pub use self::fir::{FirQ15, FirQ31, FirQ7};
+pub use self::lattice::{iir_lattice_state_length, IirLatticeF32};
+#[cfg(feature = "fixed")]
+pub use self::lattice::{IirLatticeQ15, IirLatticeQ31};
+pub use self::multichannel::{FirMultiChannel, MultiChannel};
+pub use self::multirate::{
+    fir_decimator_state_length, fir_interpolator_state_length, resampler_buffer_length,
+    FirDecimatorF32, FirInterpolatorF32, ResamplerF32,
+};
+pub use self::sparse::{fir_sparse_state_length, FirSparseF32};
+#[cfg(feature = "fixed")]
+pub use self::sparse::{FirSparseQ15, FirSparseQ31, FirSparseQ7};
+
+/// A filter that processes blocks of samples
+///
+/// This trait is implemented by the filter types in this module that take one block of input
+/// samples and produce one block of output samples, so processing chains can be written
+/// generically.
+///
+/// The adaptive (LMS and normalized LMS) filters do not implement this trait, because adapting
+/// the coefficients requires a reference signal (the desired output) in addition to the input,
+/// and each block also produces an error signal. They implement [`AdaptiveFilter`] instead.
+/// The sparse filters need a scratch buffer and do not implement either trait.
+pub trait Filter<T> {
+    /// Filters a block of samples
+    ///
+    /// The valid lengths of input and output depend on the filter type, and are the same as for
+    /// the inherent `process` method of that type.
+    ///
+    /// # Panics
+    ///
+    /// Implementations panic if input or output does not have a valid length.
+    fn process(&mut self, input: &[T], output: &mut [T]);
+
+    /// Sets the state of this filter to zero, without changing its coefficients
+    fn reset(&mut self);
+}
+
+/// An adaptive filter that processes blocks of samples and updates its coefficients
+///
+/// This trait is implemented by the least mean square filter types in this module.
+pub trait AdaptiveFilter<T> {
+    /// Filters a block of samples and adapts the coefficients
+    ///
+    /// reference is the desired output. For each sample, error is set to the difference between
+    /// the reference and the output, and the coefficients are updated to reduce the error.
+    ///
+    /// # Panics
+    ///
+    /// Implementations panic if input, reference, output, and error do not all have the same
+    /// length, or if the length is greater than the block size of the filter.
+    fn process(&mut self, input: &[T], reference: &[T], output: &mut [T], error: &mut [T]);
+
+    /// Sets the state of this filter to zero, without changing its coefficients
+    fn reset(&mut self);
+}
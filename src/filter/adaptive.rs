@@ -0,0 +1,536 @@
+//! Adaptive filters
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31};
+
+#[cfg(feature = "fixed")]
+use super::biquad::PostShift;
+use super::fir::check_fir_lengths;
+use super::AdaptiveFilter;
+#[cfg(feature = "fixed")]
+use crate::Error;
+use crate::{check_buffers, check_length, Result};
+
+/// Defines the methods that all least mean square filter types share
+///
+/// The saved fields are copied from the CMSIS-DSP instance back into the filter after each block.
+macro_rules! lms_methods {
+    ($name:ident, $element:ty, $function:ident $(, saved: $($saved:ident),+)?) => {
+        impl<C, S> $name<C, S>
+        where
+            C: AsRef<[$element]> + AsMut<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            /// Filters a block of samples and adapts the coefficients
+            ///
+            /// For each input sample, this function calculates the filter output, subtracts it
+            /// from the corresponding reference sample to get the error, and updates the
+            /// coefficients to reduce the error.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input, reference, output, and error do not all have the
+            /// same length, or if the length is greater than the block size of this filter.
+            pub fn process(
+                &mut self,
+                input: &[$element],
+                reference: &[$element],
+                output: &mut [$element],
+                error: &mut [$element],
+            ) {
+                let length = check_length((input.len(), reference.len(), output.len()));
+                assert_eq!(input.len(), error.len());
+                assert!(input.len() <= self.block_size, "Block too large for filter");
+                let mut instance = self.instance();
+                unsafe {
+                    // The reference pointer is mutable, but the CMSIS-DSP function does not write
+                    // through it
+                    cmsis_dsp_sys::$function(
+                        &mut instance,
+                        input.as_ptr() as *const _,
+                        reference.as_ptr() as *mut _,
+                        output.as_mut_ptr() as *mut _,
+                        error.as_mut_ptr() as *mut _,
+                        length,
+                    );
+                }
+                $($(self.$saved = instance.$saved;)+)?
+            }
+
+            /// Sets all state values to zero
+            ///
+            /// This does not change the coefficients.
+            pub fn reset(&mut self) {
+                for value in self.state.as_mut() {
+                    *value = Default::default();
+                }
+                $($(self.$saved = Default::default();)+)?
+            }
+
+            /// Returns the current coefficients of this filter, in time-reversed order
+            pub fn coefficients(&self) -> &[$element] {
+                self.coefficients.as_ref()
+            }
+
+            /// Returns a mutable reference to the coefficients of this filter, in time-reversed
+            /// order
+            pub fn coefficients_mut(&mut self) -> &mut [$element] {
+                self.coefficients.as_mut()
+            }
+
+            /// Returns the maximum number of samples that can be processed at once
+            pub fn block_size(&self) -> usize {
+                self.block_size
+            }
+
+            /// Releases the coefficients and state buffer
+            pub fn into_inner(self) -> (C, S) {
+                (self.coefficients, self.state)
+            }
+        }
+
+        impl<C, S> AdaptiveFilter<$element> for $name<C, S>
+        where
+            C: AsRef<[$element]> + AsMut<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            fn process(
+                &mut self,
+                input: &[$element],
+                reference: &[$element],
+                output: &mut [$element],
+                error: &mut [$element],
+            ) {
+                Self::process(self, input, reference, output, error)
+            }
+
+            fn reset(&mut self) {
+                Self::reset(self)
+            }
+        }
+    };
+}
+
+/// A least mean square adaptive filter that operates on floating-point values
+///
+/// As in CMSIS-DSP, the coefficients are stored in time-reversed order:
+/// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`. The initial coefficients are
+/// usually all zero.
+pub struct LmsFilterF32<C, S> {
+    /// Coefficients, which are updated by each call to process
+    coefficients: C,
+    /// State buffer
+    state: S,
+    /// Step size
+    mu: f32,
+    /// The maximum number of samples that can be processed at once
+    block_size: usize,
+}
+
+impl<C, S> LmsFilterF32<C, S>
+where
+    C: AsRef<[f32]> + AsMut<[f32]>,
+    S: AsMut<[f32]>,
+{
+    /// Creates a filter
+    ///
+    /// mu is the step size, which controls how quickly the coefficients adapt.
+    /// block_size is the maximum number of samples that can be processed in one call to
+    /// `process`. The length of state must be equal to
+    /// `fir_state_length(coefficients.len(), block_size)`. The state is initialized to zero.
+    ///
+    /// This function returns an error if there are no coefficients, if block_size is zero, or if
+    /// state has the wrong length.
+    pub fn new(coefficients: C, mut state: S, mu: f32, block_size: usize) -> Result<Self> {
+        check_fir_lengths(
+            coefficients.as_ref().len(),
+            state.as_mut().len(),
+            block_size,
+        )?;
+        let mut filter = LmsFilterF32 {
+            coefficients,
+            state,
+            mu,
+            block_size,
+        };
+        filter.reset();
+        Ok(filter)
+    }
+
+    /// Returns the step size
+    pub fn mu(&self) -> f32 {
+        self.mu
+    }
+
+    /// Sets the step size
+    pub fn set_mu(&mut self, mu: f32) {
+        self.mu = mu;
+    }
+
+    /// Creates a CMSIS-DSP instance that refers to the coefficients and state
+    ///
+    /// The CMSIS-DSP filter functions read the state pointer from the instance each time
+    /// they run, so a new instance can be created for each block.
+    fn instance(&mut self) -> cmsis_dsp_sys::arm_lms_instance_f32 {
+        let coefficients = self.coefficients.as_mut();
+        let state = self.state.as_mut();
+        check_buffers(check_fir_lengths(
+            coefficients.len(),
+            state.len(),
+            self.block_size,
+        ));
+        cmsis_dsp_sys::arm_lms_instance_f32 {
+            numTaps: coefficients.len() as u16,
+            pState: state.as_mut_ptr(),
+            pCoeffs: coefficients.as_mut_ptr(),
+            mu: self.mu,
+        }
+    }
+}
+
+lms_methods!(LmsFilterF32, f32, arm_lms_f32);
+
+/// Defines a fixed-point least mean square filter type
+#[cfg(feature = "fixed")]
+macro_rules! fixed_lms_filter {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $instance:ident, $function:ident, max_post_shift: $max:expr
+    ) => {
+        $(#[$attr])*
+        ///
+        /// As in CMSIS-DSP, the coefficients are stored in time-reversed order:
+        /// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`. The initial coefficients are
+        /// usually all zero.
+        ///
+        /// The post shift allows the coefficients to represent values outside the range [-1, 1):
+        /// the output of the filter is shifted left by the post shift.
+        pub struct $name<C, S> {
+            /// Coefficients, which are updated by each call to process
+            coefficients: C,
+            /// State buffer
+            state: S,
+            /// Step size
+            mu: $element,
+            /// Shift applied to the filter output
+            post_shift: PostShift,
+            /// The maximum number of samples that can be processed at once
+            block_size: usize,
+        }
+
+        impl<C, S> $name<C, S>
+        where
+            C: AsRef<[$element]> + AsMut<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            /// The largest supported post shift, in bits
+            pub const MAX_POST_SHIFT: u8 = $max;
+
+            /// Creates a filter
+            ///
+            /// mu is the step size, which controls how quickly the coefficients adapt.
+            /// block_size is the maximum number of samples that can be processed in one call to
+            /// `process`. The length of state must be equal to
+            /// `fir_state_length(coefficients.len(), block_size)`. The state is initialized to
+            /// zero.
+            ///
+            /// This function returns an error if there are no coefficients, if block_size is
+            /// zero, if state has the wrong length, or if post_shift is greater than
+            /// `MAX_POST_SHIFT`.
+            pub fn new(
+                coefficients: C,
+                mut state: S,
+                mu: $element,
+                post_shift: PostShift,
+                block_size: usize,
+            ) -> Result<Self> {
+                check_fir_lengths(coefficients.as_ref().len(), state.as_mut().len(), block_size)?;
+                if post_shift.bits() > Self::MAX_POST_SHIFT {
+                    return Err(Error::Argument);
+                }
+                let mut filter = $name {
+                    coefficients,
+                    state,
+                    mu,
+                    post_shift,
+                    block_size,
+                };
+                filter.reset();
+                Ok(filter)
+            }
+
+            /// Returns the step size
+            pub fn mu(&self) -> $element {
+                self.mu
+            }
+
+            /// Sets the step size
+            pub fn set_mu(&mut self, mu: $element) {
+                self.mu = mu;
+            }
+
+            /// Returns the post shift of this filter
+            pub fn post_shift(&self) -> PostShift {
+                self.post_shift
+            }
+
+            /// Creates a CMSIS-DSP instance that refers to the coefficients and state
+            fn instance(&mut self) -> cmsis_dsp_sys::$instance {
+                let coefficients = self.coefficients.as_mut();
+                let state = self.state.as_mut();
+                check_buffers(check_fir_lengths(coefficients.len(), state.len(), self.block_size));
+                cmsis_dsp_sys::$instance {
+                    numTaps: coefficients.len() as u16,
+                    pState: state.as_mut_ptr() as *mut _,
+                    pCoeffs: coefficients.as_mut_ptr() as *mut _,
+                    mu: self.mu.to_bits(),
+                    postShift: self.post_shift.bits().into(),
+                }
+            }
+        }
+
+        lms_methods!($name, $element, $function);
+    };
+}
+
+#[cfg(feature = "fixed")]
+fixed_lms_filter! {
+    /// A least mean square adaptive filter that operates on Q1.31 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results.
+    LmsFilterQ31, I1F31, arm_lms_instance_q31, arm_lms_q31, max_post_shift: 31
+}
+#[cfg(feature = "fixed")]
+fixed_lms_filter! {
+    /// A least mean square adaptive filter that operates on Q1.15 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results.
+    LmsFilterQ15, I1F15, arm_lms_instance_q15, arm_lms_q15, max_post_shift: 15
+}
+
+/// A normalized least mean square adaptive filter that operates on floating-point values
+///
+/// This filter divides the step size by the energy of the input signal in the filter state,
+/// which makes its convergence less dependent on the input level than [`LmsFilterF32`].
+///
+/// As in CMSIS-DSP, the coefficients are stored in time-reversed order:
+/// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`. The initial coefficients are
+/// usually all zero.
+pub struct NlmsFilterF32<C, S> {
+    /// Coefficients, which are updated by each call to process
+    coefficients: C,
+    /// State buffer
+    state: S,
+    /// Step size
+    mu: f32,
+    /// Energy of the input samples in the state buffer
+    energy: f32,
+    /// The oldest input sample in the state buffer at the end of the previous block
+    x0: f32,
+    /// The maximum number of samples that can be processed at once
+    block_size: usize,
+}
+
+impl<C, S> NlmsFilterF32<C, S>
+where
+    C: AsRef<[f32]> + AsMut<[f32]>,
+    S: AsMut<[f32]>,
+{
+    /// Creates a filter
+    ///
+    /// mu is the step size, which controls how quickly the coefficients adapt. It should be
+    /// between 0 and 2. block_size is the maximum number of samples that can be processed in
+    /// one call to `process`. The length of state must be equal to
+    /// `fir_state_length(coefficients.len(), block_size)`. The state is initialized to zero.
+    ///
+    /// This function returns an error if there are no coefficients, if block_size is zero, or if
+    /// state has the wrong length.
+    pub fn new(coefficients: C, mut state: S, mu: f32, block_size: usize) -> Result<Self> {
+        check_fir_lengths(
+            coefficients.as_ref().len(),
+            state.as_mut().len(),
+            block_size,
+        )?;
+        let mut filter = NlmsFilterF32 {
+            coefficients,
+            state,
+            mu,
+            energy: 0.0,
+            x0: 0.0,
+            block_size,
+        };
+        filter.reset();
+        Ok(filter)
+    }
+
+    /// Returns the step size
+    pub fn mu(&self) -> f32 {
+        self.mu
+    }
+
+    /// Sets the step size
+    pub fn set_mu(&mut self, mu: f32) {
+        self.mu = mu;
+    }
+
+    /// Creates a CMSIS-DSP instance that refers to the coefficients and state
+    ///
+    /// The energy and x0 fields are updated by each call to the filter function, so they are
+    /// copied back into this filter after each block.
+    fn instance(&mut self) -> cmsis_dsp_sys::arm_lms_norm_instance_f32 {
+        let coefficients = self.coefficients.as_mut();
+        let state = self.state.as_mut();
+        check_buffers(check_fir_lengths(
+            coefficients.len(),
+            state.len(),
+            self.block_size,
+        ));
+        cmsis_dsp_sys::arm_lms_norm_instance_f32 {
+            numTaps: coefficients.len() as u16,
+            pState: state.as_mut_ptr(),
+            pCoeffs: coefficients.as_mut_ptr(),
+            mu: self.mu,
+            energy: self.energy,
+            x0: self.x0,
+        }
+    }
+}
+
+lms_methods!(NlmsFilterF32, f32, arm_lms_norm_f32, saved: energy, x0);
+
+/// Defines a fixed-point normalized least mean square filter type
+#[cfg(feature = "fixed")]
+macro_rules! fixed_nlms_filter {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $raw:ty, $instance:ident, $function:ident, $table:ident,
+        max_post_shift: $max:expr
+    ) => {
+        $(#[$attr])*
+        ///
+        /// This filter divides the step size by the energy of the input signal in the filter
+        /// state, which makes its convergence less dependent on the input level.
+        ///
+        /// As in CMSIS-DSP, the coefficients are stored in time-reversed order:
+        /// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`. The initial coefficients are
+        /// usually all zero.
+        ///
+        /// The post shift allows the coefficients to represent values outside the range [-1, 1):
+        /// the output of the filter is shifted left by the post shift.
+        pub struct $name<C, S> {
+            /// Coefficients, which are updated by each call to process
+            coefficients: C,
+            /// State buffer
+            state: S,
+            /// Step size
+            mu: $element,
+            /// Shift applied to the filter output
+            post_shift: PostShift,
+            /// Energy of the input samples in the state buffer
+            energy: $raw,
+            /// The oldest input sample in the state buffer at the end of the previous block
+            x0: $raw,
+            /// The maximum number of samples that can be processed at once
+            block_size: usize,
+        }
+
+        impl<C, S> $name<C, S>
+        where
+            C: AsRef<[$element]> + AsMut<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            /// The largest supported post shift, in bits
+            pub const MAX_POST_SHIFT: u8 = $max;
+
+            /// Creates a filter
+            ///
+            /// mu is the step size, which controls how quickly the coefficients adapt.
+            /// block_size is the maximum number of samples that can be processed in one call to
+            /// `process`. The length of state must be equal to
+            /// `fir_state_length(coefficients.len(), block_size)`. The state is initialized to
+            /// zero.
+            ///
+            /// This function returns an error if there are no coefficients, if block_size is
+            /// zero, if state has the wrong length, or if post_shift is greater than
+            /// `MAX_POST_SHIFT`.
+            pub fn new(
+                coefficients: C,
+                mut state: S,
+                mu: $element,
+                post_shift: PostShift,
+                block_size: usize,
+            ) -> Result<Self> {
+                check_fir_lengths(coefficients.as_ref().len(), state.as_mut().len(), block_size)?;
+                if post_shift.bits() > Self::MAX_POST_SHIFT {
+                    return Err(Error::Argument);
+                }
+                let mut filter = $name {
+                    coefficients,
+                    state,
+                    mu,
+                    post_shift,
+                    energy: 0,
+                    x0: 0,
+                    block_size,
+                };
+                filter.reset();
+                Ok(filter)
+            }
+
+            /// Returns the step size
+            pub fn mu(&self) -> $element {
+                self.mu
+            }
+
+            /// Sets the step size
+            pub fn set_mu(&mut self, mu: $element) {
+                self.mu = mu;
+            }
+
+            /// Returns the post shift of this filter
+            pub fn post_shift(&self) -> PostShift {
+                self.post_shift
+            }
+
+            /// Creates a CMSIS-DSP instance that refers to the coefficients, state, and
+            /// reciprocal table
+            ///
+            /// The energy and x0 fields are updated by each call to the filter function, so they
+            /// are copied back into this filter after each block.
+            fn instance(&mut self) -> cmsis_dsp_sys::$instance {
+                let coefficients = self.coefficients.as_mut();
+                let state = self.state.as_mut();
+                check_buffers(check_fir_lengths(coefficients.len(), state.len(), self.block_size));
+                cmsis_dsp_sys::$instance {
+                    numTaps: coefficients.len() as u16,
+                    pState: state.as_mut_ptr() as *mut _,
+                    pCoeffs: coefficients.as_mut_ptr() as *mut _,
+                    mu: self.mu.to_bits(),
+                    postShift: self.post_shift.bits(),
+                    recipTable: unsafe { cmsis_dsp_sys::$table.as_ptr() },
+                    energy: self.energy,
+                    x0: self.x0,
+                }
+            }
+        }
+
+        lms_methods!($name, $element, $function, saved: energy, x0);
+    };
+}
+
+#[cfg(feature = "fixed")]
+fixed_nlms_filter! {
+    /// A normalized least mean square adaptive filter that operates on Q1.31 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results.
+    NlmsFilterQ31, I1F31, i32, arm_lms_norm_instance_q31, arm_lms_norm_q31, armRecipTableQ31,
+    max_post_shift: 31
+}
+#[cfg(feature = "fixed")]
+fixed_nlms_filter! {
+    /// A normalized least mean square adaptive filter that operates on Q1.15 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results.
+    NlmsFilterQ15, I1F15, i16, arm_lms_norm_instance_q15, arm_lms_norm_q15, armRecipTableQ15,
+    max_post_shift: 15
+}
@@ -0,0 +1,597 @@
+//! Biquad cascade infinite impulse response filters
+
+use core::convert::TryInto;
+use core::mem::MaybeUninit;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31, I1F63};
+
+use super::Filter;
+use crate::{assume_init, check_buffers, check_length, Error, Result};
+
+/// Defines a biquad cascade filter type for one element type and structure
+macro_rules! biquad_filter {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $instance:ident, $function:ident,
+        state_per_stage: $state_per_stage:expr, channels: $channels:expr
+    ) => {
+        $(#[$attr])*
+        pub struct $name<C, S> {
+            /// Coefficients
+            coefficients: C,
+            /// State buffer
+            state: S,
+        }
+
+        impl<C, S> $name<C, S>
+        where
+            C: AsRef<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            /// The number of state values required for each stage
+            pub const STATE_PER_STAGE: usize = $state_per_stage;
+            /// The largest supported number of stages
+            pub const MAX_STAGES: usize = 255;
+
+            /// Creates a filter
+            ///
+            /// The coefficients contain 5 values for each stage. The length of state must be
+            /// equal to `STATE_PER_STAGE` multiplied by the number of stages. The state is
+            /// initialized to zero.
+            ///
+            /// This function returns an error if the number of coefficients is zero or not a
+            /// multiple of 5, if there are more than `MAX_STAGES` stages, or if state has the wrong
+            /// length.
+            pub fn new(coefficients: C, mut state: S) -> Result<Self> {
+                check_biquad_lengths(
+                    coefficients.as_ref().len(),
+                    5,
+                    state.as_mut().len(),
+                    Self::STATE_PER_STAGE,
+                    Self::MAX_STAGES,
+                )?;
+                let mut filter = $name {
+                    coefficients,
+                    state,
+                };
+                filter.reset();
+                Ok(filter)
+            }
+
+            /// Filters a block of samples
+            ///
+            /// If this filter processes more than one channel, the input and output samples are
+            /// interleaved.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input and output do not have the same length, or if the
+            /// length is not a multiple of the number of channels.
+            pub fn process(&mut self, input: &[$element], output: &mut [$element]) {
+                self.process_inner(input, output);
+            }
+
+            /// Filters a block of samples, placing the results in an uninitialized output
+            /// buffer
+            ///
+            /// This is the same as `process`, but the contents of output do not need to be
+            /// initialized. The returned slice refers to output, which holds the results.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input and output do not have the same length, or if the
+            /// length is not a multiple of the number of channels.
+            pub fn process_uninit<'o>(
+                &mut self,
+                input: &[$element],
+                output: &'o mut [MaybeUninit<$element>],
+            ) -> &'o mut [$element] {
+                self.process_inner(input, output);
+                unsafe { assume_init(output) }
+            }
+
+            /// Filters a block of samples, writing every element of output
+            ///
+            /// The output elements can be initialized or uninitialized.
+            fn process_inner<O>(&mut self, input: &[$element], output: &mut [O]) {
+                let length: u32 = check_length((input.len(), output.len()));
+                let channels: usize = $channels;
+                assert!(
+                    input.len() % channels == 0,
+                    "Length not a multiple of the number of channels"
+                );
+                let frames = length / $channels;
+                let coefficients = self.coefficients.as_ref();
+                let state = self.state.as_mut();
+                check_buffers(check_biquad_lengths(
+                    coefficients.len(),
+                    5,
+                    state.len(),
+                    Self::STATE_PER_STAGE,
+                    Self::MAX_STAGES,
+                ));
+                let stages = coefficients.len() / 5;
+                let instance = cmsis_dsp_sys::$instance {
+                    // The number of stages was checked above
+                    numStages: stages.try_into().expect("Too many stages"),
+                    pState: state.as_mut_ptr() as *mut _,
+                    pCoeffs: coefficients.as_ptr() as *const _,
+                };
+                unsafe {
+                    cmsis_dsp_sys::$function(
+                        &instance,
+                        input.as_ptr() as *const _,
+                        output.as_mut_ptr() as *mut _,
+                        frames,
+                    );
+                }
+            }
+
+            /// Sets all state values to zero
+            pub fn reset(&mut self) {
+                for value in self.state.as_mut() {
+                    *value = Default::default();
+                }
+            }
+
+            /// Returns the number of stages in this filter
+            pub fn stages(&self) -> usize {
+                self.coefficients.as_ref().len() / 5
+            }
+
+            /// Returns the coefficients of this filter
+            pub fn coefficients(&self) -> &[$element] {
+                self.coefficients.as_ref()
+            }
+
+            /// Releases the coefficients and state buffer
+            pub fn into_inner(self) -> (C, S) {
+                (self.coefficients, self.state)
+            }
+        }
+
+        impl<C, S> Filter<$element> for $name<C, S>
+        where
+            C: AsRef<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            fn process(&mut self, input: &[$element], output: &mut [$element]) {
+                Self::process(self, input, output)
+            }
+
+            fn reset(&mut self) {
+                Self::reset(self)
+            }
+        }
+    };
+}
+
+biquad_filter! {
+    /// A cascade of biquad filters using the direct form I structure, which operates on
+    /// floating-point values
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]` and calculates
+    /// `y[n] = b0 * x[n] + b1 * x[n-1] + b2 * x[n-2] + a1 * y[n-1] + a2 * y[n-2]`.
+    /// The feedback coefficients a1 and a2 have the opposite sign of the coefficients produced by
+    /// many filter design tools (for example, MATLAB and SciPy).
+    ///
+    /// Each stage uses 4 state values: `[x[n-1], x[n-2], y[n-1], y[n-2]]`.
+    BiquadCascadeDf1F32, f32, arm_biquad_casd_df1_inst_f32, arm_biquad_cascade_df1_f32,
+    state_per_stage: 4, channels: 1
+}
+biquad_filter! {
+    /// A cascade of biquad filters using the direct form II transposed structure, which
+    /// operates on floating-point values
+    ///
+    /// This structure has better numerical behavior than direct form I for floating-point values,
+    /// and uses less state.
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]` and calculates
+    /// `y[n] = b0 * x[n] + b1 * x[n-1] + b2 * x[n-2] + a1 * y[n-1] + a2 * y[n-2]`.
+    /// The feedback coefficients a1 and a2 have the opposite sign of the coefficients produced by
+    /// many filter design tools (for example, MATLAB and SciPy).
+    ///
+    /// Each stage uses 2 state values.
+    BiquadCascadeDf2TF32, f32, arm_biquad_cascade_df2T_instance_f32, arm_biquad_cascade_df2T_f32,
+    state_per_stage: 2, channels: 1
+}
+biquad_filter! {
+    /// A cascade of biquad filters using the direct form II transposed structure, which
+    /// operates on double-precision floating-point values
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]` and calculates
+    /// `y[n] = b0 * x[n] + b1 * x[n-1] + b2 * x[n-2] + a1 * y[n-1] + a2 * y[n-2]`.
+    /// The feedback coefficients a1 and a2 have the opposite sign of the coefficients produced by
+    /// many filter design tools (for example, MATLAB and SciPy).
+    ///
+    /// Each stage uses 2 state values.
+    BiquadCascadeDf2TF64, f64, arm_biquad_cascade_df2T_instance_f64, arm_biquad_cascade_df2T_f64,
+    state_per_stage: 2, channels: 1
+}
+biquad_filter! {
+    /// A cascade of biquad filters using the direct form II transposed structure, which
+    /// operates on two channels of floating-point values
+    ///
+    /// The same coefficients are applied to both channels. The input and output samples are
+    /// interleaved: `[left, right, left, right, ...]`.
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]` and calculates
+    /// `y[n] = b0 * x[n] + b1 * x[n-1] + b2 * x[n-2] + a1 * y[n-1] + a2 * y[n-2]`.
+    /// The feedback coefficients a1 and a2 have the opposite sign of the coefficients produced by
+    /// many filter design tools (for example, MATLAB and SciPy).
+    ///
+    /// Each stage uses 4 state values (2 for each channel).
+    BiquadCascadeStereoDf2TF32, f32, arm_biquad_cascade_stereo_df2T_instance_f32,
+    arm_biquad_cascade_stereo_df2T_f32, state_per_stage: 4, channels: 2
+}
+
+/// Defines a method that filters one sample, for a biquad cascade type that processes one
+/// channel
+macro_rules! biquad_process_sample {
+    ($name:ident, $element:ty) => {
+        impl<C, S> $name<C, S>
+        where
+            C: AsRef<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            /// Filters one sample
+            ///
+            /// This is equivalent to calling `process` with one input sample and one output
+            /// sample, which is convenient when samples arrive one at a time.
+            pub fn process_sample(&mut self, input: $element) -> $element {
+                let mut output = [0.0];
+                self.process(&[input], &mut output);
+                output[0]
+            }
+        }
+    };
+}
+
+biquad_process_sample!(BiquadCascadeDf1F32, f32);
+biquad_process_sample!(BiquadCascadeDf2TF32, f32);
+biquad_process_sample!(BiquadCascadeDf2TF64, f64);
+
+/// A shift applied to the output of a fixed-point biquad cascade
+///
+/// Fixed-point coefficients can only represent values in the range [-1, 1), but biquad
+/// coefficients are often larger. To represent them, all coefficients are divided by
+/// `2^bits` before conversion to fixed-point, and each stage shifts its accumulated result
+/// left by `bits` bits to compensate.
+///
+/// For example, coefficients in the range [-2, 2) can be used with a post shift of 1 bit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct PostShift(u8);
+
+impl PostShift {
+    /// A post shift of zero bits, for coefficients in the range [-1, 1)
+    pub const NONE: PostShift = PostShift(0);
+
+    /// Creates a post shift of the provided number of bits
+    ///
+    /// This function returns an error if bits is greater than 31. Each filter type also has its
+    /// own, possibly smaller, maximum.
+    pub fn new(bits: u8) -> Result<Self> {
+        if bits <= 31 {
+            Ok(PostShift(bits))
+        } else {
+            Err(Error::Argument)
+        }
+    }
+
+    /// Returns the number of bits to shift
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns the value that coefficients must be divided by before they are converted to
+    /// fixed-point (`2^bits`)
+    pub fn scale(&self) -> f32 {
+        (1u32 << self.0) as f32
+    }
+}
+
+/// Defines a fixed-point direct form I biquad cascade filter type
+macro_rules! fixed_biquad_filter {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $coefficient:ty, $state:ty, $sample:ty, $instance:ident, $function:ident,
+        coefficients_per_stage: $coefficients_per_stage:expr, max_stages: $max_stages:expr,
+        max_post_shift: $max_post_shift:expr
+    ) => {
+        $(#[$attr])*
+        pub struct $name<C, S> {
+            /// Coefficients
+            coefficients: C,
+            /// State buffer
+            state: S,
+            /// Shift applied to the output of each stage
+            post_shift: PostShift,
+        }
+
+        impl<C, S> $name<C, S>
+        where
+            C: AsRef<[$coefficient]>,
+            S: AsMut<[$state]>,
+        {
+            /// The number of coefficients for each stage
+            pub const COEFFICIENTS_PER_STAGE: usize = $coefficients_per_stage;
+            /// The number of state values required for each stage
+            pub const STATE_PER_STAGE: usize = 4;
+            /// The largest supported number of stages
+            pub const MAX_STAGES: usize = $max_stages;
+            /// The largest supported post shift, in bits
+            pub const MAX_POST_SHIFT: u8 = $max_post_shift;
+
+            /// Creates a filter
+            ///
+            /// The coefficients contain `COEFFICIENTS_PER_STAGE` values for each stage, and must
+            /// have been scaled according to post_shift. The length of state must be
+            /// equal to `STATE_PER_STAGE` multiplied by the number of stages. The state is
+            /// initialized to zero.
+            ///
+            /// This function returns an error if the number of coefficients is zero or not a
+            /// multiple of `COEFFICIENTS_PER_STAGE`, if there are more than `MAX_STAGES` stages,
+            /// if state has the wrong length, or if post_shift is greater than `MAX_POST_SHIFT`.
+            pub fn new(coefficients: C, mut state: S, post_shift: PostShift) -> Result<Self> {
+                check_biquad_lengths(
+                    coefficients.as_ref().len(),
+                    Self::COEFFICIENTS_PER_STAGE,
+                    state.as_mut().len(),
+                    Self::STATE_PER_STAGE,
+                    Self::MAX_STAGES,
+                )?;
+                if post_shift.bits() > Self::MAX_POST_SHIFT {
+                    return Err(Error::Argument);
+                }
+                let mut filter = $name {
+                    coefficients,
+                    state,
+                    post_shift,
+                };
+                filter.reset();
+                Ok(filter)
+            }
+
+            /// Filters a block of samples
+            ///
+            /// This function saturates on overflow.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input and output do not have the same length.
+            pub fn process(&mut self, input: &[$sample], output: &mut [$sample]) {
+                let length: u32 = check_length((input.len(), output.len()));
+                unsafe {
+                    cmsis_dsp_sys::$function(
+                        &self.instance(),
+                        input.as_ptr() as *const _,
+                        output.as_mut_ptr() as *mut _,
+                        length,
+                    );
+                }
+            }
+
+            /// Filters a block of samples, placing the results in an uninitialized output
+            /// buffer
+            ///
+            /// This is the same as `process`, but the contents of output do not need to be
+            /// initialized. The returned slice refers to output, which holds the results.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input and output do not have the same length.
+            pub fn process_uninit<'o>(
+                &mut self,
+                input: &[$sample],
+                output: &'o mut [MaybeUninit<$sample>],
+            ) -> &'o mut [$sample] {
+                let length: u32 = check_length((input.len(), output.len()));
+                unsafe {
+                    cmsis_dsp_sys::$function(
+                        &self.instance(),
+                        input.as_ptr() as *const _,
+                        output.as_mut_ptr() as *mut _,
+                        length,
+                    );
+                    assume_init(output)
+                }
+            }
+
+            /// Filters one sample
+            ///
+            /// This is equivalent to calling `process` with one input sample and one output
+            /// sample, which is convenient when samples arrive one at a time.
+            pub fn process_sample(&mut self, input: $sample) -> $sample {
+                let mut output = [Default::default()];
+                self.process(&[input], &mut output);
+                output[0]
+            }
+
+            /// Sets all state values to zero
+            pub fn reset(&mut self) {
+                for value in self.state.as_mut() {
+                    *value = Default::default();
+                }
+            }
+
+            /// Returns the number of stages in this filter
+            pub fn stages(&self) -> usize {
+                self.coefficients.as_ref().len() / Self::COEFFICIENTS_PER_STAGE
+            }
+
+            /// Returns the coefficients of this filter
+            pub fn coefficients(&self) -> &[$coefficient] {
+                self.coefficients.as_ref()
+            }
+
+            /// Returns the post shift of this filter
+            pub fn post_shift(&self) -> PostShift {
+                self.post_shift
+            }
+
+            /// Releases the coefficients and state buffer
+            pub fn into_inner(self) -> (C, S) {
+                (self.coefficients, self.state)
+            }
+
+            /// Creates a CMSIS instance structure that refers to this filter
+            fn instance(&mut self) -> cmsis_dsp_sys::$instance {
+                let coefficients = self.coefficients.as_ref();
+                let state = self.state.as_mut();
+                check_buffers(check_biquad_lengths(
+                    coefficients.len(),
+                    Self::COEFFICIENTS_PER_STAGE,
+                    state.len(),
+                    Self::STATE_PER_STAGE,
+                    Self::MAX_STAGES,
+                ));
+                let stages = coefficients.len() / Self::COEFFICIENTS_PER_STAGE;
+                cmsis_dsp_sys::$instance {
+                    // The number of stages was checked above
+                    numStages: stages.try_into().expect("Too many stages"),
+                    pState: state.as_mut_ptr() as *mut _,
+                    pCoeffs: coefficients.as_ptr() as *const _,
+                    postShift: self.post_shift.bits() as _,
+                }
+            }
+        }
+
+        impl<C, S> Filter<$sample> for $name<C, S>
+        where
+            C: AsRef<[$coefficient]>,
+            S: AsMut<[$state]>,
+        {
+            fn process(&mut self, input: &[$sample], output: &mut [$sample]) {
+                Self::process(self, input, output)
+            }
+
+            fn reset(&mut self) {
+                Self::reset(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+fixed_biquad_filter! {
+    /// A cascade of biquad filters using the direct form I structure, which operates on Q1.15
+    /// values
+    ///
+    /// Each stage has 6 coefficients in the order `[b0, 0, b1, b2, a1, a2]`. The zero is
+    /// required by the vectorized implementation. The feedback coefficients a1 and a2 have the
+    /// opposite sign of the coefficients produced by many filter design tools.
+    ///
+    /// Each stage uses 4 state values: `[x[n-1], x[n-2], y[n-1], y[n-2]]`. Intermediate results
+    /// are accumulated in 64 bits.
+    BiquadCascadeDf1Q15, I1F15, I1F15, I1F15, arm_biquad_casd_df1_inst_q15,
+    arm_biquad_cascade_df1_q15, coefficients_per_stage: 6, max_stages: 127, max_post_shift: 15
+}
+#[cfg(feature = "fixed")]
+fixed_biquad_filter! {
+    /// A cascade of biquad filters using the direct form I structure, which operates on Q1.31
+    /// values
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]`. The feedback
+    /// coefficients a1 and a2 have the opposite sign of the coefficients produced by many filter
+    /// design tools.
+    ///
+    /// Each stage uses 4 state values: `[x[n-1], x[n-2], y[n-1], y[n-2]]`. Intermediate results
+    /// are accumulated in 64 bits.
+    BiquadCascadeDf1Q31, I1F31, I1F31, I1F31, arm_biquad_casd_df1_inst_q31,
+    arm_biquad_cascade_df1_q31, coefficients_per_stage: 5, max_stages: 255, max_post_shift: 30
+}
+#[cfg(feature = "fixed")]
+fixed_biquad_filter! {
+    /// A cascade of biquad filters using the direct form I structure, which operates on Q1.31
+    /// values with 64-bit state for higher precision
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]`. The feedback
+    /// coefficients a1 and a2 have the opposite sign of the coefficients produced by many filter
+    /// design tools.
+    ///
+    /// Each stage uses 4 state values: `[x[n-1], x[n-2], y[n-1], y[n-2]]`. The outputs of each
+    /// stage are kept in Q1.63 format, which reduces quantization noise in filters with poles
+    /// close to the unit circle.
+    BiquadCascadeDf1Q31x64, I1F31, I1F63, I1F31, arm_biquad_cas_df1_32x64_ins_q31,
+    arm_biquad_cas_df1_32x64_q31, coefficients_per_stage: 5, max_stages: 255,
+    max_post_shift: 31
+}
+
+#[cfg(feature = "fixed")]
+impl<C, S> BiquadCascadeDf1Q15<C, S>
+where
+    C: AsRef<[I1F15]>,
+    S: AsMut<[I1F15]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator. To avoid overflow, the input should be scaled down by
+    /// 2 bits and be in the range [-0.25, 0.25).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length.
+    pub fn process_fast(&mut self, input: &[I1F15], output: &mut [I1F15]) {
+        let length: u32 = check_length((input.len(), output.len()));
+        unsafe {
+            cmsis_dsp_sys::arm_biquad_cascade_df1_fast_q15(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<C, S> BiquadCascadeDf1Q31<C, S>
+where
+    C: AsRef<[I1F31]>,
+    S: AsMut<[I1F31]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator, and the results of multiplications are truncated to
+    /// Q2.30. To avoid overflow, the input should be scaled down by 2 bits and be in the range
+    /// [-0.25, 0.25).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length.
+    pub fn process_fast(&mut self, input: &[I1F31], output: &mut [I1F31]) {
+        let length: u32 = check_length((input.len(), output.len()));
+        unsafe {
+            cmsis_dsp_sys::arm_biquad_cascade_df1_fast_q31(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
+
+/// Checks the coefficient and state lengths of a biquad cascade
+fn check_biquad_lengths(
+    coefficients_length: usize,
+    coefficients_per_stage: usize,
+    state_length: usize,
+    state_per_stage: usize,
+    max_stages: usize,
+) -> Result<()> {
+    if coefficients_length == 0 || coefficients_length % coefficients_per_stage != 0 {
+        return Err(Error::Length);
+    }
+    let stages = coefficients_length / coefficients_per_stage;
+    if stages > max_stages {
+        return Err(Error::Length);
+    }
+    if state_length != stages * state_per_stage {
+        return Err(Error::Length);
+    }
+    Ok(())
+}
+
+pub(super) mod raw;
@@ -0,0 +1,103 @@
+//! Biquad filters that operate on fixed-point values stored as raw integers
+
+use core::convert::TryInto;
+use core::mem::MaybeUninit;
+
+use super::{check_biquad_lengths, PostShift};
+use crate::filter::Filter;
+use crate::{assume_init, check_buffers, check_length, Error, Result};
+
+fixed_biquad_filter! {
+    /// A cascade of biquad filters using the direct form I structure, which operates on Q1.15
+    /// values stored as raw integers
+    ///
+    /// Each stage has 6 coefficients in the order `[b0, 0, b1, b2, a1, a2]`. The zero is
+    /// required by the vectorized implementation. The feedback coefficients a1 and a2 have the
+    /// opposite sign of the coefficients produced by many filter design tools.
+    ///
+    /// Each stage uses 4 state values: `[x[n-1], x[n-2], y[n-1], y[n-2]]`. Intermediate results
+    /// are accumulated in 64 bits.
+    BiquadCascadeDf1Q15, i16, i16, i16, arm_biquad_casd_df1_inst_q15,
+    arm_biquad_cascade_df1_q15, coefficients_per_stage: 6, max_stages: 127, max_post_shift: 15
+}
+fixed_biquad_filter! {
+    /// A cascade of biquad filters using the direct form I structure, which operates on Q1.31
+    /// values stored as raw integers
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]`. The feedback
+    /// coefficients a1 and a2 have the opposite sign of the coefficients produced by many filter
+    /// design tools.
+    ///
+    /// Each stage uses 4 state values: `[x[n-1], x[n-2], y[n-1], y[n-2]]`. Intermediate results
+    /// are accumulated in 64 bits.
+    BiquadCascadeDf1Q31, i32, i32, i32, arm_biquad_casd_df1_inst_q31,
+    arm_biquad_cascade_df1_q31, coefficients_per_stage: 5, max_stages: 255, max_post_shift: 30
+}
+fixed_biquad_filter! {
+    /// A cascade of biquad filters using the direct form I structure, which operates on Q1.31
+    /// values stored as raw integers with 64-bit state for higher precision
+    ///
+    /// Each stage has 5 coefficients in the order `[b0, b1, b2, a1, a2]`. The feedback
+    /// coefficients a1 and a2 have the opposite sign of the coefficients produced by many filter
+    /// design tools.
+    ///
+    /// Each stage uses 4 state values: `[x[n-1], x[n-2], y[n-1], y[n-2]]`. The outputs of each
+    /// stage are kept in Q1.63 format, which reduces quantization noise in filters with poles
+    /// close to the unit circle.
+    BiquadCascadeDf1Q31x64, i32, i64, i32, arm_biquad_cas_df1_32x64_ins_q31,
+    arm_biquad_cas_df1_32x64_q31, coefficients_per_stage: 5, max_stages: 255,
+    max_post_shift: 31
+}
+
+impl<C, S> BiquadCascadeDf1Q15<C, S>
+where
+    C: AsRef<[i16]>,
+    S: AsMut<[i16]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator. To avoid overflow, the input should be scaled down by
+    /// 2 bits and be in the range [-0.25, 0.25).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length.
+    pub fn process_fast(&mut self, input: &[i16], output: &mut [i16]) {
+        let length: u32 = check_length((input.len(), output.len()));
+        unsafe {
+            cmsis_dsp_sys::arm_biquad_cascade_df1_fast_q15(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
+
+impl<C, S> BiquadCascadeDf1Q31<C, S>
+where
+    C: AsRef<[i32]>,
+    S: AsMut<[i32]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator, and the results of multiplications are truncated to
+    /// Q2.30. To avoid overflow, the input should be scaled down by 2 bits and be in the range
+    /// [-0.25, 0.25).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length.
+    pub fn process_fast(&mut self, input: &[i32], output: &mut [i32]) {
+        let length: u32 = check_length((input.len(), output.len()));
+        unsafe {
+            cmsis_dsp_sys::arm_biquad_cascade_df1_fast_q31(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
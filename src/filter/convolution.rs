@@ -0,0 +1,295 @@
+//! Convolution and correlation
+
+use core::convert::TryInto;
+
+use super::Filter;
+use crate::basic::add_f32;
+use crate::transform::FloatRealFft;
+use crate::{check_buffers, Error, Result};
+
+/// Returns the length of the result of convolving two sequences with the provided lengths
+///
+/// This is equal to `a_length + b_length - 1`, or 0 if either length is 0.
+pub fn conv_length(a_length: usize, b_length: usize) -> usize {
+    if a_length == 0 || b_length == 0 {
+        0
+    } else {
+        a_length + b_length - 1
+    }
+}
+
+/// Convolves two sequences
+///
+/// This calculates `dst[n] = sum(a[k] * b[n - k])` for all values of k where both indices are
+/// in range, for n from 0 to `a.len() + b.len() - 2`.
+///
+/// This function returns an error if a or b is empty, or if the length of dst is not
+/// `conv_length(a.len(), b.len())`.
+pub fn conv_f32(a: &[f32], b: &[f32], dst: &mut [f32]) -> Result<()> {
+    let (a_length, b_length) = check_conv_lengths(a.len(), b.len(), dst.len())?;
+    unsafe {
+        cmsis_dsp_sys::arm_conv_f32(a.as_ptr(), a_length, b.as_ptr(), b_length, dst.as_mut_ptr());
+    }
+    Ok(())
+}
+
+/// Returns the length of the result of correlating two sequences with the provided lengths
+///
+/// This is equal to `2 * max(a_length, b_length) - 1`, or 0 if either length is 0.
+pub fn correlate_length(a_length: usize, b_length: usize) -> usize {
+    if a_length == 0 || b_length == 0 {
+        0
+    } else {
+        2 * a_length.max(b_length) - 1
+    }
+}
+
+/// Calculates the cross-correlation of two sequences
+///
+/// The shorter sequence is padded with zeros to the length of the longer sequence, N.
+/// Then, for each lag l from `-(N - 1)` to `N - 1`, this calculates
+/// `dst[l + N - 1] = sum(a[k] * b[k - l])` for all values of k where both indices are in range.
+/// If a is a copy of b delayed by d samples, the largest value is at lag d.
+///
+/// This function returns an error if a or b is empty, or if the length of dst is not
+/// `correlate_length(a.len(), b.len())`.
+pub fn correlate_f32(a: &[f32], b: &[f32], dst: &mut [f32]) -> Result<()> {
+    if a.is_empty() || b.is_empty() || dst.len() != correlate_length(a.len(), b.len()) {
+        return Err(Error::Length);
+    }
+    let _: u32 = dst.len().try_into().map_err(|_| Error::Length)?;
+    unsafe {
+        cmsis_dsp_sys::arm_correlate_f32(
+            a.as_ptr(),
+            a.len() as u32,
+            b.as_ptr(),
+            b.len() as u32,
+            dst.as_mut_ptr(),
+        );
+    }
+    Ok(())
+}
+
+/// Calculates the cross-correlation of two sequences and returns the lag with the largest
+/// correlation
+///
+/// The correlation is written into dst, as in [`correlate_f32`]. A positive lag means that a
+/// is delayed relative to b. This can be used to estimate the time delay between two signals.
+///
+/// This function returns an error if a or b is empty, or if the length of dst is not
+/// `correlate_length(a.len(), b.len())`.
+pub fn correlate_peak_lag_f32(a: &[f32], b: &[f32], dst: &mut [f32]) -> Result<isize> {
+    correlate_f32(a, b, dst)?;
+    let mut max = 0.0;
+    let mut index = 0u32;
+    unsafe {
+        cmsis_dsp_sys::arm_max_f32(dst.as_ptr(), dst.len() as u32, &mut max, &mut index);
+    }
+    let zero_lag = a.len().max(b.len()) - 1;
+    Ok(index as isize - zero_lag as isize)
+}
+
+/// Checks the lengths of two convolution inputs and the output, and returns the input lengths
+fn check_conv_lengths(a_length: usize, b_length: usize, dst_length: usize) -> Result<(u32, u32)> {
+    if a_length == 0 || b_length == 0 || dst_length != conv_length(a_length, b_length) {
+        return Err(Error::Length);
+    }
+    let _: u32 = dst_length.try_into().map_err(|_| Error::Length)?;
+    Ok((a_length as u32, b_length as u32))
+}
+
+/// Returns the length of the buffer required for an FFT convolver with the provided FFT size
+/// and kernel length
+///
+/// This is equal to `2 * fft_size + kernel_length - 1`.
+pub fn fft_convolver_buffer_length(fft_size: usize, kernel_length: usize) -> usize {
+    2 * fft_size + kernel_length - 1
+}
+
+/// A convolver that uses the overlap-add method with real FFTs to filter blocks of samples
+/// with a long impulse response
+///
+/// For long impulse responses, this is much faster than a [`FirF32`](super::FirF32) filter
+/// and produces the same results (apart from rounding).
+///
+/// The convolver uses two buffers: one that holds the spectrum of the impulse response (with a
+/// length equal to the FFT size), and one that holds the overlap between blocks and the
+/// working memory for the FFTs (with a length given by [`fft_convolver_buffer_length`]).
+pub struct FftConvolverF32<K, S> {
+    /// FFT instance
+    fft: FloatRealFft,
+    /// Spectrum of the kernel, in the packed format produced by FloatRealFft
+    spectrum: K,
+    /// Buffer containing [time-domain working memory, frequency-domain working memory,
+    /// overlap from previous blocks]
+    buffer: S,
+    /// Size of the FFTs
+    fft_size: usize,
+    /// Length of the kernel (impulse response)
+    kernel_length: usize,
+    /// The maximum number of samples that can be processed at once
+    block_size: usize,
+}
+
+impl<K, S> FftConvolverF32<K, S>
+where
+    K: AsMut<[f32]>,
+    S: AsMut<[f32]>,
+{
+    /// Creates a convolver
+    ///
+    /// kernel is the impulse response of the filter, in normal (not time-reversed) order.
+    /// The FFT size is equal to the length of spectrum, and must be a size supported by
+    /// [`FloatRealFft`]. block_size is the maximum number of samples that can be processed in
+    /// one call to `process`. `block_size + kernel.len() - 1` must not be greater than the FFT
+    /// size. The length of buffer must be equal to
+    /// `fft_convolver_buffer_length(spectrum.len(), kernel.len())`.
+    ///
+    /// The spectrum of the kernel is calculated and stored in spectrum, and the overlap is
+    /// initialized to zero.
+    ///
+    /// This function returns an error if the FFT size is not supported, if kernel is empty,
+    /// if block_size is zero or too large, or if buffer has the wrong length.
+    pub fn new(kernel: &[f32], mut spectrum: K, mut buffer: S, block_size: usize) -> Result<Self> {
+        let fft_size = spectrum.as_mut().len();
+        let fft = FloatRealFft::new(fft_size.try_into().map_err(|_| Error::Argument)?)?;
+        if kernel.is_empty() || block_size == 0 {
+            return Err(Error::Argument);
+        }
+        if block_size + kernel.len() - 1 > fft_size
+            || buffer.as_mut().len() != fft_convolver_buffer_length(fft_size, kernel.len())
+        {
+            return Err(Error::Length);
+        }
+
+        // Calculate the spectrum of the zero-padded kernel
+        let (time, _) = buffer.as_mut().split_at_mut(fft_size);
+        time[..kernel.len()].copy_from_slice(kernel);
+        for value in &mut time[kernel.len()..] {
+            *value = 0.0;
+        }
+        fft.run(time, spectrum.as_mut());
+
+        let mut convolver = FftConvolverF32 {
+            fft,
+            spectrum,
+            buffer,
+            fft_size,
+            kernel_length: kernel.len(),
+            block_size,
+        };
+        convolver.reset();
+        Ok(convolver)
+    }
+
+    /// Filters a block of samples
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length, or if the length
+    /// is greater than the block size of this convolver.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        assert_eq!(input.len(), output.len());
+        assert!(
+            input.len() <= self.block_size,
+            "Block too large for convolver"
+        );
+        let length = input.len();
+        let fft_size = self.fft_size;
+        let buffer = self.buffer.as_mut();
+        let spectrum = self.spectrum.as_mut();
+        check_buffers(
+            if spectrum.len() == fft_size
+                && buffer.len() == fft_convolver_buffer_length(fft_size, self.kernel_length)
+            {
+                Ok(())
+            } else {
+                Err(Error::Length)
+            },
+        );
+        let (time, rest) = buffer.split_at_mut(fft_size);
+        let (frequency, overlap) = rest.split_at_mut(fft_size);
+
+        // Transform the zero-padded input
+        time[..length].copy_from_slice(input);
+        for value in &mut time[length..] {
+            *value = 0.0;
+        }
+        // The real FFT uses its input as working memory, so time is overwritten here
+        self.fft.run(time, frequency);
+
+        // Multiply by the spectrum of the kernel. The first two values are the real components
+        // at 0 and at the Nyquist frequency, so they are multiplied separately.
+        let (dc, nyquist) = (frequency[0] * spectrum[0], frequency[1] * spectrum[1]);
+        unsafe {
+            cmsis_dsp_sys::arm_cmplx_mult_cmplx_f32(
+                frequency.as_ptr(),
+                spectrum.as_ptr(),
+                frequency.as_mut_ptr(),
+                (fft_size / 2) as u32,
+            );
+        }
+        frequency[0] = dc;
+        frequency[1] = nyquist;
+
+        // Transform back, producing length + kernel_length - 1 samples of output
+        self.fft.run_inverse(frequency, time);
+
+        // Add the overlap from previous blocks
+        let overlapped = length.min(overlap.len());
+        add_f32(
+            &time[..overlapped],
+            &overlap[..overlapped],
+            &mut output[..overlapped],
+        );
+        output[overlapped..].copy_from_slice(&time[overlapped..length]);
+
+        // Shift the overlap and add the new tail
+        for i in 0..overlap.len() {
+            let previous = overlap.get(i + length).copied().unwrap_or(0.0);
+            overlap[i] = time[length + i] + previous;
+        }
+    }
+
+    /// Sets the overlap from previous blocks to zero
+    pub fn reset(&mut self) {
+        let fft_size = self.fft_size;
+        for value in &mut self.buffer.as_mut()[2 * fft_size..] {
+            *value = 0.0;
+        }
+    }
+
+    /// Returns the size of the FFTs that this convolver uses
+    pub fn fft_size(&self) -> usize {
+        self.fft_size
+    }
+
+    /// Returns the length of the kernel (impulse response)
+    pub fn kernel_length(&self) -> usize {
+        self.kernel_length
+    }
+
+    /// Returns the maximum number of samples that can be processed at once
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Releases the spectrum and buffer
+    pub fn into_inner(self) -> (K, S) {
+        (self.spectrum, self.buffer)
+    }
+}
+
+impl<K, S> Filter<f32> for FftConvolverF32<K, S>
+where
+    K: AsMut<[f32]>,
+    S: AsMut<[f32]>,
+{
+    fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        Self::process(self, input, output)
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self)
+    }
+}
@@ -0,0 +1,546 @@
+//! Filter coefficient design
+
+use core::f32::consts::PI;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31};
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use super::biquad::PostShift;
+use crate::window::{fill_window, Symmetry, Window};
+use crate::{Error, Result};
+
+/// A type of biquad filter, with the parameters that are specific to that type
+///
+/// The formulas used to design these filters are from Robert Bristow-Johnson's
+/// [Audio EQ Cookbook](https://www.w3.org/TR/audio-eq-cookbook/).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BiquadType {
+    /// A low-pass filter
+    LowPass,
+    /// A high-pass filter
+    HighPass,
+    /// A band-pass filter with a peak gain of 0 dB
+    BandPass,
+    /// A band-stop filter
+    Notch,
+    /// An all-pass filter
+    AllPass,
+    /// A peaking equalizer, with the gain at the center frequency in decibels
+    Peaking {
+        /// Gain at the center frequency, in decibels
+        gain_db: f32,
+    },
+    /// A low shelf filter, with the gain below the corner frequency in decibels
+    LowShelf {
+        /// Gain below the corner frequency, in decibels
+        gain_db: f32,
+    },
+    /// A high shelf filter, with the gain above the corner frequency in decibels
+    HighShelf {
+        /// Gain above the corner frequency, in decibels
+        gain_db: f32,
+    },
+}
+
+/// The coefficients of one biquad stage
+///
+/// The coefficients are normalized so that a0 is 1, and the feedback coefficients a1 and a2
+/// use the sign convention of the CMSIS biquad filters (the opposite of many filter design
+/// tools):
+///
+/// `y[n] = b0 * x[n] + b1 * x[n-1] + b2 * x[n-2] + a1 * y[n-1] + a2 * y[n-2]`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BiquadCoefficients {
+    /// Feedforward coefficient for `x[n]`
+    pub b0: f32,
+    /// Feedforward coefficient for `x[n-1]`
+    pub b1: f32,
+    /// Feedforward coefficient for `x[n-2]`
+    pub b2: f32,
+    /// Feedback coefficient for `y[n-1]`
+    pub a1: f32,
+    /// Feedback coefficient for `y[n-2]`
+    pub a2: f32,
+}
+
+impl BiquadCoefficients {
+    /// Designs a biquad filter
+    ///
+    /// frequency is the cutoff, center, or corner frequency in the same units as sample_rate.
+    /// q is the quality factor. For low-pass and high-pass filters, a q of `1/sqrt(2)` gives
+    /// a Butterworth response.
+    ///
+    /// This function returns an error if sample_rate or q is not positive, or if frequency is
+    /// not between zero and half of sample_rate (exclusive).
+    pub fn design(kind: BiquadType, sample_rate: f32, frequency: f32, q: f32) -> Result<Self> {
+        let valid = sample_rate > 0.0
+            && sample_rate.is_finite()
+            && frequency > 0.0
+            && frequency < sample_rate / 2.0
+            && q > 0.0
+            && q.is_finite();
+        if !valid {
+            return Err(Error::Argument);
+        }
+        let w0 = 2.0 * PI * frequency / sample_rate;
+        let (sin_w0, cos_w0) = unsafe {
+            (
+                cmsis_dsp_sys::arm_sin_f32(w0),
+                cmsis_dsp_sys::arm_cos_f32(w0),
+            )
+        };
+        let alpha = sin_w0 / (2.0 * q);
+
+        // Coefficients in the order [b0, b1, b2, a0, a1, a2], not normalized
+        let [b0, b1, b2, a0, a1, a2] = match kind {
+            BiquadType::LowPass => {
+                let b1 = 1.0 - cos_w0;
+                [
+                    b1 / 2.0,
+                    b1,
+                    b1 / 2.0,
+                    1.0 + alpha,
+                    -2.0 * cos_w0,
+                    1.0 - alpha,
+                ]
+            }
+            BiquadType::HighPass => {
+                let b0 = (1.0 + cos_w0) / 2.0;
+                [b0, -2.0 * b0, b0, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha]
+            }
+            BiquadType::BandPass => [alpha, 0.0, -alpha, 1.0 + alpha, -2.0 * cos_w0, 1.0 - alpha],
+            BiquadType::Notch => [
+                1.0,
+                -2.0 * cos_w0,
+                1.0,
+                1.0 + alpha,
+                -2.0 * cos_w0,
+                1.0 - alpha,
+            ],
+            BiquadType::AllPass => [
+                1.0 - alpha,
+                -2.0 * cos_w0,
+                1.0 + alpha,
+                1.0 + alpha,
+                -2.0 * cos_w0,
+                1.0 - alpha,
+            ],
+            BiquadType::Peaking { gain_db } => {
+                let a = shelf_amplitude(gain_db)?;
+                [
+                    1.0 + alpha * a,
+                    -2.0 * cos_w0,
+                    1.0 - alpha * a,
+                    1.0 + alpha / a,
+                    -2.0 * cos_w0,
+                    1.0 - alpha / a,
+                ]
+            }
+            BiquadType::LowShelf { gain_db } => {
+                let a = shelf_amplitude(gain_db)?;
+                let k = 2.0 * unsafe { crate::sqrtf(a) } * alpha;
+                [
+                    a * ((a + 1.0) - (a - 1.0) * cos_w0 + k),
+                    2.0 * a * ((a - 1.0) - (a + 1.0) * cos_w0),
+                    a * ((a + 1.0) - (a - 1.0) * cos_w0 - k),
+                    (a + 1.0) + (a - 1.0) * cos_w0 + k,
+                    -2.0 * ((a - 1.0) + (a + 1.0) * cos_w0),
+                    (a + 1.0) + (a - 1.0) * cos_w0 - k,
+                ]
+            }
+            BiquadType::HighShelf { gain_db } => {
+                let a = shelf_amplitude(gain_db)?;
+                let k = 2.0 * unsafe { crate::sqrtf(a) } * alpha;
+                [
+                    a * ((a + 1.0) + (a - 1.0) * cos_w0 + k),
+                    -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w0),
+                    a * ((a + 1.0) + (a - 1.0) * cos_w0 - k),
+                    (a + 1.0) - (a - 1.0) * cos_w0 + k,
+                    2.0 * ((a - 1.0) - (a + 1.0) * cos_w0),
+                    (a + 1.0) - (a - 1.0) * cos_w0 - k,
+                ]
+            }
+        };
+        Ok(BiquadCoefficients {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: -a1 / a0,
+            a2: -a2 / a0,
+        })
+    }
+
+    /// Designs a low-pass filter
+    ///
+    /// This is equivalent to `design(BiquadType::LowPass, sample_rate, frequency, q)`.
+    pub fn low_pass(sample_rate: f32, frequency: f32, q: f32) -> Result<Self> {
+        Self::design(BiquadType::LowPass, sample_rate, frequency, q)
+    }
+
+    /// Designs a high-pass filter
+    ///
+    /// This is equivalent to `design(BiquadType::HighPass, sample_rate, frequency, q)`.
+    pub fn high_pass(sample_rate: f32, frequency: f32, q: f32) -> Result<Self> {
+        Self::design(BiquadType::HighPass, sample_rate, frequency, q)
+    }
+
+    /// Designs a band-pass filter
+    ///
+    /// This is equivalent to `design(BiquadType::BandPass, sample_rate, frequency, q)`.
+    pub fn band_pass(sample_rate: f32, frequency: f32, q: f32) -> Result<Self> {
+        Self::design(BiquadType::BandPass, sample_rate, frequency, q)
+    }
+
+    /// Designs a notch filter
+    ///
+    /// This is equivalent to `design(BiquadType::Notch, sample_rate, frequency, q)`.
+    pub fn notch(sample_rate: f32, frequency: f32, q: f32) -> Result<Self> {
+        Self::design(BiquadType::Notch, sample_rate, frequency, q)
+    }
+
+    /// Returns the coefficients in the order used by the floating-point biquad filters:
+    /// `[b0, b1, b2, a1, a2]`
+    pub fn to_f32(&self) -> [f32; 5] {
+        [self.b0, self.b1, self.b2, self.a1, self.a2]
+    }
+
+    /// Returns the coefficients in the order used by the double-precision biquad filters:
+    /// `[b0, b1, b2, a1, a2]`
+    pub fn to_f64(&self) -> [f64; 5] {
+        [
+            self.b0.into(),
+            self.b1.into(),
+            self.b2.into(),
+            self.a1.into(),
+            self.a2.into(),
+        ]
+    }
+
+    /// Returns the smallest post shift that allows these coefficients to be represented in
+    /// fixed-point
+    ///
+    /// This function returns an error if a coefficient is not finite or is too large to
+    /// represent with any post shift.
+    pub fn post_shift(&self) -> Result<PostShift> {
+        let max = self
+            .to_f32()
+            .iter()
+            .fold(0.0f32, |max, value| max.max(value.abs()));
+        if !max.is_finite() {
+            return Err(Error::NanInf);
+        }
+        let mut bits = 0;
+        while max >= PostShift::new(bits)?.scale() {
+            bits += 1;
+        }
+        PostShift::new(bits)
+    }
+
+    /// Converts the coefficients into Q1.31 format, in the order used by
+    /// [`BiquadCascadeDf1Q31`](super::BiquadCascadeDf1Q31): `[b0, b1, b2, a1, a2]`
+    ///
+    /// The coefficients are divided by the scale of post_shift before conversion.
+    ///
+    /// This function returns an error if a coefficient is too large to represent with
+    /// post_shift.
+    #[cfg(feature = "fixed")]
+    pub fn to_q31(&self, post_shift: PostShift) -> Result<[I1F31; 5]> {
+        self.check_post_shift(post_shift)?;
+        let scale = post_shift.scale();
+        let mut fixed = [I1F31::from_bits(0); 5];
+        for (fixed, &value) in fixed.iter_mut().zip(self.to_f32().iter()) {
+            *fixed = I1F31::saturating_from_num(value / scale);
+        }
+        Ok(fixed)
+    }
+
+    /// Converts the coefficients into Q1.15 format, in the order used by
+    /// [`BiquadCascadeDf1Q15`](super::BiquadCascadeDf1Q15): `[b0, 0, b1, b2, a1, a2]`
+    ///
+    /// The coefficients are divided by the scale of post_shift before conversion.
+    ///
+    /// This function returns an error if a coefficient is too large to represent with
+    /// post_shift.
+    #[cfg(feature = "fixed")]
+    pub fn to_q15(&self, post_shift: PostShift) -> Result<[I1F15; 6]> {
+        self.check_post_shift(post_shift)?;
+        let scale = post_shift.scale();
+        let convert = |value: f32| I1F15::saturating_from_num(value / scale);
+        Ok([
+            convert(self.b0),
+            I1F15::from_bits(0),
+            convert(self.b1),
+            convert(self.b2),
+            convert(self.a1),
+            convert(self.a2),
+        ])
+    }
+
+    /// Checks that these coefficients can be represented with the provided post shift
+    #[cfg(feature = "fixed")]
+    fn check_post_shift(&self, post_shift: PostShift) -> Result<()> {
+        if self.post_shift()?.bits() <= post_shift.bits() {
+            Ok(())
+        } else {
+            Err(Error::Argument)
+        }
+    }
+}
+
+/// Writes the coefficients of several stages into a buffer in the layout used by the
+/// floating-point biquad filters
+///
+/// This function returns an error if the length of dst is not 5 times the number of stages.
+pub fn write_cascade_f32(stages: &[BiquadCoefficients], dst: &mut [f32]) -> Result<()> {
+    if dst.len() != stages.len() * 5 {
+        return Err(Error::Length);
+    }
+    for (stage, dst) in stages.iter().zip(dst.chunks_exact_mut(5)) {
+        dst.copy_from_slice(&stage.to_f32());
+    }
+    Ok(())
+}
+
+/// Returns the coefficients of several stages in the layout used by the floating-point biquad
+/// filters
+///
+/// This is the same as `write_cascade_f32`, but it allocates the buffer. This function is only
+/// available when the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn cascade_vec_f32(stages: &[BiquadCoefficients]) -> Vec<f32> {
+    stages.iter().flat_map(|stage| stage.to_f32()).collect()
+}
+
+/// Converts the coefficients of several stages into Q1.31 format and writes them into a buffer
+/// in the layout used by [`BiquadCascadeDf1Q31`](super::BiquadCascadeDf1Q31)
+///
+/// All stages of a cascade use the same post shift. This function chooses the smallest post
+/// shift that can represent all the coefficients, and returns it.
+///
+/// This function returns an error if the length of dst is not 5 times the number of stages,
+/// or if a coefficient cannot be represented.
+#[cfg(feature = "fixed")]
+pub fn quantize_cascade_q31(stages: &[BiquadCoefficients], dst: &mut [I1F31]) -> Result<PostShift> {
+    if dst.len() != stages.len() * 5 {
+        return Err(Error::Length);
+    }
+    let post_shift = cascade_post_shift(stages)?;
+    for (stage, dst) in stages.iter().zip(dst.chunks_exact_mut(5)) {
+        dst.copy_from_slice(&stage.to_q31(post_shift)?);
+    }
+    Ok(post_shift)
+}
+
+/// Converts the coefficients of several stages into Q1.15 format and writes them into a buffer
+/// in the layout used by [`BiquadCascadeDf1Q15`](super::BiquadCascadeDf1Q15)
+///
+/// All stages of a cascade use the same post shift. This function chooses the smallest post
+/// shift that can represent all the coefficients, and returns it.
+///
+/// This function returns an error if the length of dst is not 6 times the number of stages,
+/// or if a coefficient cannot be represented.
+#[cfg(feature = "fixed")]
+pub fn quantize_cascade_q15(stages: &[BiquadCoefficients], dst: &mut [I1F15]) -> Result<PostShift> {
+    if dst.len() != stages.len() * 6 {
+        return Err(Error::Length);
+    }
+    let post_shift = cascade_post_shift(stages)?;
+    if post_shift.bits() > 15 {
+        return Err(Error::Argument);
+    }
+    for (stage, dst) in stages.iter().zip(dst.chunks_exact_mut(6)) {
+        dst.copy_from_slice(&stage.to_q15(post_shift)?);
+    }
+    Ok(post_shift)
+}
+
+/// Converts the coefficients of several stages into Q1.31 format, and returns them with the
+/// post shift that all stages use
+///
+/// This is the same as `quantize_cascade_q31`, but it allocates the buffer. This function is
+/// only available when the `alloc` and `fixed` features are enabled.
+#[cfg(all(feature = "alloc", feature = "fixed"))]
+pub fn quantize_cascade_vec_q31(stages: &[BiquadCoefficients]) -> Result<(Vec<I1F31>, PostShift)> {
+    let mut coefficients = vec![I1F31::ZERO; stages.len() * 5];
+    let post_shift = quantize_cascade_q31(stages, &mut coefficients)?;
+    Ok((coefficients, post_shift))
+}
+
+/// Converts the coefficients of several stages into Q1.15 format, and returns them with the
+/// post shift that all stages use
+///
+/// This is the same as `quantize_cascade_q15`, but it allocates the buffer. This function is
+/// only available when the `alloc` and `fixed` features are enabled.
+#[cfg(all(feature = "alloc", feature = "fixed"))]
+pub fn quantize_cascade_vec_q15(stages: &[BiquadCoefficients]) -> Result<(Vec<I1F15>, PostShift)> {
+    let mut coefficients = vec![I1F15::ZERO; stages.len() * 6];
+    let post_shift = quantize_cascade_q15(stages, &mut coefficients)?;
+    Ok((coefficients, post_shift))
+}
+
+/// Returns the smallest post shift that can represent the coefficients of all stages
+#[cfg(feature = "fixed")]
+fn cascade_post_shift(stages: &[BiquadCoefficients]) -> Result<PostShift> {
+    let mut post_shift = PostShift::NONE;
+    for stage in stages {
+        let stage_shift = stage.post_shift()?;
+        if stage_shift.bits() > post_shift.bits() {
+            post_shift = stage_shift;
+        }
+    }
+    Ok(post_shift)
+}
+
+/// Converts a gain in decibels into the amplitude `A = 10^(gain_db / 40)` used by the peaking
+/// and shelf filter formulas
+fn shelf_amplitude(gain_db: f32) -> Result<f32> {
+    if !gain_db.is_finite() {
+        return Err(Error::Argument);
+    }
+    Ok(unsafe { crate::powf(10.0, gain_db / 40.0) })
+}
+
+/// A type of finite impulse response filter, with its cutoff frequencies
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FirType {
+    /// A low-pass filter
+    LowPass {
+        /// Cutoff frequency
+        cutoff: f32,
+    },
+    /// A high-pass filter
+    ///
+    /// High-pass filters must have an odd number of taps.
+    HighPass {
+        /// Cutoff frequency
+        cutoff: f32,
+    },
+    /// A band-pass filter
+    BandPass {
+        /// Lower cutoff frequency
+        low: f32,
+        /// Upper cutoff frequency
+        high: f32,
+    },
+    /// A band-stop filter
+    ///
+    /// Band-stop filters must have an odd number of taps.
+    BandStop {
+        /// Lower cutoff frequency
+        low: f32,
+        /// Upper cutoff frequency
+        high: f32,
+    },
+}
+
+/// Designs a linear-phase FIR filter using the windowed-sinc method, and writes the taps into
+/// taps
+///
+/// The number of taps is the length of taps. More taps give a sharper transition between the
+/// passband and stopband. The taps are scaled so that the gain is 1 in the passband (at zero
+/// frequency for low-pass and band-stop filters, at half of sample_rate for high-pass filters).
+/// Band-pass filters are the difference of two low-pass filters, so their gain is close to 1
+/// when the band is wide relative to the transition width.
+///
+/// The symmetric form of window is applied to the ideal impulse response. A rectangular window
+/// gives the sharpest transition but very little stopband attenuation, and windows such as
+/// Blackman give more attenuation with a wider transition.
+///
+/// Cutoff frequencies are in the same units as sample_rate. The taps can be used directly
+/// as the coefficients of [`FirF32`](super::FirF32).
+///
+/// This function returns an error if taps is empty, if a cutoff frequency is not between zero
+/// and half of sample_rate (exclusive), if the lower cutoff of a band is not less than the
+/// upper cutoff, or if a high-pass or band-stop filter has an even number of taps.
+pub fn design_fir(kind: FirType, window: Window, sample_rate: f32, taps: &mut [f32]) -> Result<()> {
+    if taps.is_empty() || !(sample_rate.is_finite() && sample_rate > 0.0) {
+        return Err(Error::Argument);
+    }
+    let odd = taps.len() & 1 == 1;
+    let check_cutoff = |cutoff: f32| {
+        if cutoff > 0.0 && cutoff < sample_rate / 2.0 {
+            Ok(cutoff / sample_rate)
+        } else {
+            Err(Error::Argument)
+        }
+    };
+    // Normalized cutoffs of the low-pass filters to subtract, and whether to invert the result
+    let (high, low, invert) = match kind {
+        FirType::LowPass { cutoff } => (check_cutoff(cutoff)?, None, false),
+        FirType::HighPass { cutoff } if odd => (check_cutoff(cutoff)?, None, true),
+        FirType::BandPass { low, high } if low < high => {
+            (check_cutoff(high)?, Some(check_cutoff(low)?), false)
+        }
+        FirType::BandStop { low, high } if odd && low < high => {
+            (check_cutoff(high)?, Some(check_cutoff(low)?), true)
+        }
+        _ => return Err(Error::Argument),
+    };
+
+    let length = taps.len();
+    // Fill taps with the window first, and replace each value with the windowed sinc below
+    fill_window(window, Symmetry::Symmetric, taps);
+    let windowed_sinc = |n: usize, window_value: f32, cutoff: f32| {
+        window_value * low_pass_sinc(n as f32 - (length - 1) as f32 / 2.0, cutoff)
+    };
+    // Sums of the low-pass filters, used to normalize them to unity gain at zero frequency
+    let sum = |cutoff: f32| -> f32 {
+        taps.iter()
+            .enumerate()
+            .map(|(n, &window_value)| windowed_sinc(n, window_value, cutoff))
+            .sum()
+    };
+    let high_sum = sum(high);
+    let low_sum = match low {
+        Some(low) => sum(low),
+        None => 1.0,
+    };
+
+    for (n, tap) in taps.iter_mut().enumerate() {
+        let window_value = *tap;
+        let mut value = windowed_sinc(n, window_value, high) / high_sum;
+        if let Some(low) = low {
+            value -= windowed_sinc(n, window_value, low) / low_sum;
+        }
+        if invert {
+            value = -value;
+            if n == length / 2 {
+                value += 1.0;
+            }
+        }
+        *tap = value;
+    }
+    Ok(())
+}
+
+/// Designs a linear-phase FIR filter using the windowed-sinc method, and returns the taps
+///
+/// This is the same as `design_fir`, but it allocates num_taps taps. This function is only
+/// available when the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn design_fir_vec(
+    kind: FirType,
+    window: Window,
+    sample_rate: f32,
+    num_taps: usize,
+) -> Result<Vec<f32>> {
+    let mut taps = vec![0.0; num_taps];
+    design_fir(kind, window, sample_rate, &mut taps)?;
+    Ok(taps)
+}
+
+/// Returns the ideal impulse response of a low-pass filter at an offset (in samples) from its
+/// center
+///
+/// cutoff is normalized to the sample rate.
+fn low_pass_sinc(offset: f32, cutoff: f32) -> f32 {
+    if offset == 0.0 {
+        2.0 * cutoff
+    } else {
+        let x = 2.0 * PI * cutoff * offset;
+        unsafe { cmsis_dsp_sys::arm_sin_f32(x) / (PI * offset) }
+    }
+}
@@ -0,0 +1,314 @@
+//! Finite impulse response filters
+
+use core::convert::TryInto;
+use core::mem::MaybeUninit;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31, I1F7};
+
+use super::Filter;
+use crate::{assume_init, check_buffers, check_length, Error, Result};
+
+/// Returns the number of state values required for a FIR filter with the provided number of
+/// taps and maximum block size
+///
+/// This is equal to `num_taps + block_size - 1`. This function returns an error if num_taps or
+/// block_size is zero.
+pub fn fir_state_length(num_taps: usize, block_size: usize) -> Result<usize> {
+    if num_taps == 0 || block_size == 0 {
+        return Err(Error::Argument);
+    }
+    Ok(num_taps + block_size - 1)
+}
+
+/// Defines a FIR filter type for one element type
+macro_rules! fir_filter {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $instance:ident, $function:ident, $check_taps:expr
+    ) => {
+        $(#[$attr])*
+        ///
+        /// As in CMSIS-DSP, the coefficients are stored in time-reversed order:
+        /// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`.
+        pub struct $name<C, S> {
+            /// Coefficients
+            coefficients: C,
+            /// State buffer
+            state: S,
+            /// The maximum number of samples that can be processed at once
+            block_size: usize,
+        }
+
+        impl<C, S> $name<C, S>
+        where
+            C: AsRef<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            /// Creates a filter
+            ///
+            /// block_size is the maximum number of samples that can be processed in one call to
+            /// `process`. The length of state must be equal to
+            /// `fir_state_length(coefficients.len(), block_size)`. The state is initialized to
+            /// zero.
+            ///
+            /// This function returns an error if the number of coefficients is not valid, if
+            /// block_size is zero, or if state has the wrong length.
+            pub fn new(coefficients: C, mut state: S, block_size: usize) -> Result<Self> {
+                let num_taps = coefficients.as_ref().len();
+                let check_taps: fn(usize) -> Result<()> = $check_taps;
+                check_taps(num_taps)?;
+                check_fir_lengths(num_taps, state.as_mut().len(), block_size)?;
+                let mut filter = $name {
+                    coefficients,
+                    state,
+                    block_size,
+                };
+                filter.reset();
+                Ok(filter)
+            }
+
+            /// Filters a block of samples
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input and output do not have the same length, or if the
+            /// length is greater than the block size of this filter.
+            pub fn process(&mut self, input: &[$element], output: &mut [$element]) {
+                let length = self.check_block(input, output);
+                unsafe {
+                    cmsis_dsp_sys::$function(
+                        &self.instance(),
+                        input.as_ptr() as *const _,
+                        output.as_mut_ptr() as *mut _,
+                        length,
+                    );
+                }
+            }
+
+            /// Filters a block of samples, placing the results in an uninitialized output
+            /// buffer
+            ///
+            /// This is the same as `process`, but the contents of output do not need to be
+            /// initialized. The returned slice refers to output, which holds the results.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input and output do not have the same length, or if the
+            /// length is greater than the block size of this filter.
+            pub fn process_uninit<'o>(
+                &mut self,
+                input: &[$element],
+                output: &'o mut [MaybeUninit<$element>],
+            ) -> &'o mut [$element] {
+                let length = self.check_block(input, output);
+                unsafe {
+                    cmsis_dsp_sys::$function(
+                        &self.instance(),
+                        input.as_ptr() as *const _,
+                        output.as_mut_ptr() as *mut _,
+                        length,
+                    );
+                    assume_init(output)
+                }
+            }
+
+            /// Filters any number of samples, and returns the results
+            ///
+            /// The input is processed in blocks no larger than the block size of this filter.
+            /// This function is only available when the `alloc` feature is enabled.
+            #[cfg(feature = "alloc")]
+            pub fn process_owned(&mut self, input: &[$element]) -> alloc::vec::Vec<$element> {
+                let mut output = alloc::vec::Vec::with_capacity(input.len());
+                for block in input.chunks(self.block_size) {
+                    let start = output.len();
+                    self.process_uninit(block, &mut output.spare_capacity_mut()[..block.len()]);
+                    unsafe { output.set_len(start + block.len()) };
+                }
+                output
+            }
+
+            /// Filters one sample
+            ///
+            /// This is equivalent to calling `process` with one input sample and one output
+            /// sample, which is convenient when samples arrive one at a time.
+            pub fn process_sample(&mut self, input: $element) -> $element {
+                let mut output = [Default::default()];
+                self.process(&[input], &mut output);
+                output[0]
+            }
+
+            /// Sets all state values to zero
+            pub fn reset(&mut self) {
+                for value in self.state.as_mut() {
+                    *value = Default::default();
+                }
+            }
+
+            /// Returns the coefficients of this filter, in time-reversed order
+            pub fn coefficients(&self) -> &[$element] {
+                self.coefficients.as_ref()
+            }
+
+            /// Returns the maximum number of samples that can be processed at once
+            pub fn block_size(&self) -> usize {
+                self.block_size
+            }
+
+            /// Releases the coefficients and state buffer
+            pub fn into_inner(self) -> (C, S) {
+                (self.coefficients, self.state)
+            }
+
+            /// Checks the lengths of an input and output block and returns the length
+            fn check_block<O>(&self, input: &[$element], output: &[O]) -> u32 {
+                let length = check_length((input.len(), output.len()));
+                assert!(input.len() <= self.block_size, "Block too large for filter");
+                length
+            }
+
+            /// Creates a CMSIS-DSP instance that refers to the coefficients and state
+            ///
+            /// The CMSIS-DSP filter functions read the state pointer from the instance each time
+            /// they run, so a new instance can be created for each block.
+            fn instance(&mut self) -> cmsis_dsp_sys::$instance {
+                let coefficients = self.coefficients.as_ref();
+                let state = self.state.as_mut();
+                check_buffers(check_fir_lengths(coefficients.len(), state.len(), self.block_size));
+                cmsis_dsp_sys::$instance {
+                    numTaps: coefficients.len() as u16,
+                    pState: state.as_mut_ptr() as *mut _,
+                    pCoeffs: coefficients.as_ptr() as *const _,
+                }
+            }
+        }
+
+        impl<C, S> Filter<$element> for $name<C, S>
+        where
+            C: AsRef<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            fn process(&mut self, input: &[$element], output: &mut [$element]) {
+                Self::process(self, input, output)
+            }
+
+            fn reset(&mut self) {
+                Self::reset(self)
+            }
+        }
+    };
+}
+
+fir_filter! {
+    /// A finite impulse response filter that operates on floating-point values
+    FirF32, f32, arm_fir_instance_f32, arm_fir_f32, |_| Ok(())
+}
+#[cfg(feature = "fixed")]
+fir_filter! {
+    /// A finite impulse response filter that operates on Q1.31 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator. To avoid overflow, the input should be scaled down
+    /// by log2(number of taps) bits.
+    FirQ31, I1F31, arm_fir_instance_q31, arm_fir_q31, |_| Ok(())
+}
+#[cfg(feature = "fixed")]
+fir_filter! {
+    /// A finite impulse response filter that operates on Q1.15 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results. The number of taps must
+    /// be even and greater than or equal to 4. If a filter design has an odd number of taps,
+    /// add a zero coefficient.
+    FirQ15, I1F15, arm_fir_instance_q15, arm_fir_q15, check_q15_taps
+}
+#[cfg(feature = "fixed")]
+fir_filter! {
+    /// A finite impulse response filter that operates on Q1.7 fixed-point values
+    ///
+    /// This filter uses a 32-bit accumulator and saturates the results.
+    FirQ7, I1F7, arm_fir_instance_q7, arm_fir_q7, |_| Ok(())
+}
+
+#[cfg(feature = "fixed")]
+impl<C, S> FirQ31<C, S>
+where
+    C: AsRef<[I1F31]>,
+    S: AsMut<[I1F31]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator, and the results are truncated to Q2.30 before
+    /// accumulation. To avoid overflow, the input should be scaled down by log2(number of taps)
+    /// bits.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length, or if the length is
+    /// greater than the block size of this filter.
+    pub fn process_fast(&mut self, input: &[I1F31], output: &mut [I1F31]) {
+        let length = self.check_block(input, output);
+        unsafe {
+            cmsis_dsp_sys::arm_fir_fast_q31(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<C, S> FirQ15<C, S>
+where
+    C: AsRef<[I1F15]>,
+    S: AsMut<[I1F15]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator. To avoid overflow, the input should be scaled down by
+    /// log2(number of taps) bits.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length, or if the length is
+    /// greater than the block size of this filter.
+    pub fn process_fast(&mut self, input: &[I1F15], output: &mut [I1F15]) {
+        let length = self.check_block(input, output);
+        unsafe {
+            cmsis_dsp_sys::arm_fir_fast_q15(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
+
+/// Checks that a Q1.15 filter has an even number of taps, greater than or equal to 4
+fn check_q15_taps(num_taps: usize) -> Result<()> {
+    if num_taps >= 4 && num_taps & 1 == 0 {
+        Ok(())
+    } else {
+        Err(Error::Argument)
+    }
+}
+
+/// Checks the number of taps, state length, and block size of a FIR filter
+pub(crate) fn check_fir_lengths(
+    num_taps: usize,
+    state_length: usize,
+    block_size: usize,
+) -> Result<()> {
+    let _: u16 = num_taps.try_into().map_err(|_| Error::Length)?;
+    if num_taps == 0 || block_size == 0 {
+        return Err(Error::Argument);
+    }
+    let _: u32 = block_size.try_into().map_err(|_| Error::Length)?;
+    if state_length != fir_state_length(num_taps, block_size)? {
+        return Err(Error::Length);
+    }
+    Ok(())
+}
+
+pub(super) mod raw;
@@ -0,0 +1,84 @@
+//! Finite impulse response filters that operate on fixed-point values stored as raw integers
+
+use core::mem::MaybeUninit;
+
+use super::{check_fir_lengths, check_q15_taps};
+use crate::filter::Filter;
+use crate::{assume_init, check_buffers, check_length, Result};
+
+fir_filter! {
+    /// A finite impulse response filter that operates on Q1.31 values stored as raw integers
+    ///
+    /// This filter uses a 64-bit accumulator. To avoid overflow, the input should be scaled down
+    /// by log2(number of taps) bits.
+    FirQ31, i32, arm_fir_instance_q31, arm_fir_q31, |_| Ok(())
+}
+fir_filter! {
+    /// A finite impulse response filter that operates on Q1.15 values stored as raw integers
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results. The number of taps must
+    /// be even and greater than or equal to 4. If a filter design has an odd number of taps,
+    /// add a zero coefficient.
+    FirQ15, i16, arm_fir_instance_q15, arm_fir_q15, check_q15_taps
+}
+fir_filter! {
+    /// A finite impulse response filter that operates on Q1.7 values stored as raw integers
+    ///
+    /// This filter uses a 32-bit accumulator and saturates the results.
+    FirQ7, i8, arm_fir_instance_q7, arm_fir_q7, |_| Ok(())
+}
+
+impl<C, S> FirQ31<C, S>
+where
+    C: AsRef<[i32]>,
+    S: AsMut<[i32]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator, and the results are truncated to Q2.30 before
+    /// accumulation. To avoid overflow, the input should be scaled down by log2(number of taps)
+    /// bits.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length, or if the length is
+    /// greater than the block size of this filter.
+    pub fn process_fast(&mut self, input: &[i32], output: &mut [i32]) {
+        let length = self.check_block(input, output);
+        unsafe {
+            cmsis_dsp_sys::arm_fir_fast_q31(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
+
+impl<C, S> FirQ15<C, S>
+where
+    C: AsRef<[i16]>,
+    S: AsMut<[i16]>,
+{
+    /// Filters a block of samples using a faster algorithm with less precision
+    ///
+    /// This uses a 32-bit accumulator. To avoid overflow, the input should be scaled down by
+    /// log2(number of taps) bits.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length, or if the length is
+    /// greater than the block size of this filter.
+    pub fn process_fast(&mut self, input: &[i16], output: &mut [i16]) {
+        let length = self.check_block(input, output);
+        unsafe {
+            cmsis_dsp_sys::arm_fir_fast_q15(
+                &self.instance(),
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Lattice filters
+
+use core::convert::TryInto;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31};
+
+use super::Filter;
+use crate::{check_buffers, check_length, Error, Result};
+
+/// Returns the number of state values required for an IIR lattice filter with the provided
+/// number of stages and maximum block size
+///
+/// This is equal to `num_stages + block_size`.
+pub fn iir_lattice_state_length(num_stages: usize, block_size: usize) -> usize {
+    num_stages + block_size
+}
+
+/// Defines an IIR lattice filter type for one element type
+macro_rules! iir_lattice_filter {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $instance:ident, $function:ident
+    ) => {
+        $(#[$attr])*
+        ///
+        /// The filter has `num_stages` reflection coefficients and `num_stages + 1` ladder
+        /// coefficients. As in CMSIS-DSP, both are stored in reverse order:
+        /// `[k[num_stages], ..., k[2], k[1]]` and `[v[num_stages], ..., v[1], v[0]]`.
+        pub struct $name<K, V, S> {
+            /// Reflection coefficients
+            reflection: K,
+            /// Ladder coefficients
+            ladder: V,
+            /// State buffer
+            state: S,
+            /// The maximum number of samples that can be processed at once
+            block_size: usize,
+        }
+
+        impl<K, V, S> $name<K, V, S>
+        where
+            K: AsRef<[$element]>,
+            V: AsRef<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            /// Creates a filter
+            ///
+            /// block_size is the maximum number of samples that can be processed in one call to
+            /// `process`. The number of ladder coefficients must be one more than the number of
+            /// reflection coefficients, and the length of state must be equal to
+            /// `iir_lattice_state_length(reflection.len(), block_size)`. The state is
+            /// initialized to zero.
+            ///
+            /// This function returns an error if there are no reflection coefficients, if
+            /// block_size is zero, or if the ladder coefficients or state have the wrong length.
+            pub fn new(reflection: K, ladder: V, mut state: S, block_size: usize) -> Result<Self> {
+                check_iir_lattice_lengths(
+                    reflection.as_ref().len(),
+                    ladder.as_ref().len(),
+                    state.as_mut().len(),
+                    block_size,
+                )?;
+                let mut filter = $name {
+                    reflection,
+                    ladder,
+                    state,
+                    block_size,
+                };
+                filter.reset();
+                Ok(filter)
+            }
+
+            /// Filters a block of samples
+            ///
+            /// # Panics
+            ///
+            /// This function panics if input and output do not have the same length, or if the
+            /// length is greater than the block size of this filter.
+            pub fn process(&mut self, input: &[$element], output: &mut [$element]) {
+                let length = check_length((input.len(), output.len()));
+                assert!(input.len() <= self.block_size, "Block too large for filter");
+                let instance = self.instance();
+                unsafe {
+                    cmsis_dsp_sys::$function(
+                        &instance,
+                        input.as_ptr() as *const _,
+                        output.as_mut_ptr() as *mut _,
+                        length,
+                    );
+                }
+            }
+
+            /// Filters one sample
+            ///
+            /// This is equivalent to calling `process` with one input sample and one output
+            /// sample, which is convenient when samples arrive one at a time.
+            pub fn process_sample(&mut self, input: $element) -> $element {
+                let mut output = [Default::default()];
+                self.process(&[input], &mut output);
+                output[0]
+            }
+
+            /// Sets all state values to zero
+            pub fn reset(&mut self) {
+                for value in self.state.as_mut() {
+                    *value = Default::default();
+                }
+            }
+
+            /// Returns the number of stages in this filter
+            pub fn stages(&self) -> usize {
+                self.reflection.as_ref().len()
+            }
+
+            /// Returns the reflection coefficients of this filter, in reverse order
+            pub fn reflection_coefficients(&self) -> &[$element] {
+                self.reflection.as_ref()
+            }
+
+            /// Returns the ladder coefficients of this filter, in reverse order
+            pub fn ladder_coefficients(&self) -> &[$element] {
+                self.ladder.as_ref()
+            }
+
+            /// Returns the maximum number of samples that can be processed at once
+            pub fn block_size(&self) -> usize {
+                self.block_size
+            }
+
+            /// Releases the coefficients and state buffer
+            pub fn into_inner(self) -> (K, V, S) {
+                (self.reflection, self.ladder, self.state)
+            }
+
+            /// Creates a CMSIS-DSP instance that refers to the coefficients and state
+            ///
+            /// The coefficient pointers in the instance are mutable, but the CMSIS-DSP filter
+            /// functions do not write through them.
+            fn instance(&mut self) -> cmsis_dsp_sys::$instance {
+                let reflection = self.reflection.as_ref();
+                let ladder = self.ladder.as_ref();
+                let state = self.state.as_mut();
+                check_buffers(check_iir_lattice_lengths(
+                    reflection.len(),
+                    ladder.len(),
+                    state.len(),
+                    self.block_size,
+                ));
+                cmsis_dsp_sys::$instance {
+                    numStages: reflection.len() as u16,
+                    pState: state.as_mut_ptr() as *mut _,
+                    pkCoeffs: reflection.as_ptr() as *mut _,
+                    pvCoeffs: ladder.as_ptr() as *mut _,
+                }
+            }
+        }
+
+        impl<K, V, S> Filter<$element> for $name<K, V, S>
+        where
+            K: AsRef<[$element]>,
+            V: AsRef<[$element]>,
+            S: AsMut<[$element]>,
+        {
+            fn process(&mut self, input: &[$element], output: &mut [$element]) {
+                Self::process(self, input, output)
+            }
+
+            fn reset(&mut self) {
+                Self::reset(self)
+            }
+        }
+    };
+}
+
+iir_lattice_filter! {
+    /// An infinite impulse response lattice filter that operates on floating-point values
+    IirLatticeF32, f32, arm_iir_lattice_instance_f32, arm_iir_lattice_f32
+}
+#[cfg(feature = "fixed")]
+iir_lattice_filter! {
+    /// An infinite impulse response lattice filter that operates on Q1.31 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results.
+    IirLatticeQ31, I1F31, arm_iir_lattice_instance_q31, arm_iir_lattice_q31
+}
+#[cfg(feature = "fixed")]
+iir_lattice_filter! {
+    /// An infinite impulse response lattice filter that operates on Q1.15 fixed-point values
+    ///
+    /// This filter uses a 64-bit accumulator and saturates the results.
+    IirLatticeQ15, I1F15, arm_iir_lattice_instance_q15, arm_iir_lattice_q15
+}
+
+/// Checks the coefficient lengths, state length, and block size of an IIR lattice filter
+fn check_iir_lattice_lengths(
+    num_stages: usize,
+    ladder_length: usize,
+    state_length: usize,
+    block_size: usize,
+) -> Result<()> {
+    let _: u16 = num_stages.try_into().map_err(|_| Error::Length)?;
+    if num_stages == 0 || block_size == 0 {
+        return Err(Error::Argument);
+    }
+    let _: u32 = block_size.try_into().map_err(|_| Error::Length)?;
+    if ladder_length != num_stages + 1
+        || state_length != iir_lattice_state_length(num_stages, block_size)
+    {
+        return Err(Error::Length);
+    }
+    Ok(())
+}
@@ -0,0 +1,136 @@
+//! Multi-channel filters
+
+use core::marker::PhantomData;
+
+use super::{Filter, FirF32};
+use crate::check_length;
+
+/// A group of filters that process several channels of samples independently
+///
+/// Each channel has its own filter and state. To use the same coefficients for all channels,
+/// the filters can borrow one coefficient array.
+///
+/// Samples can be provided in planar format (one slice for each channel) or interleaved format
+/// (`[channel 0, channel 1, ..., channel N-1, channel 0, ...]`). T is the sample type.
+pub struct MultiChannel<F, T, const N: usize> {
+    /// The filter for each channel
+    filters: [F; N],
+    /// The sample type
+    _sample: PhantomData<fn() -> T>,
+}
+
+/// A group of FIR filters that process several channels of floating-point samples
+pub type FirMultiChannel<C, S, const N: usize> = MultiChannel<FirF32<C, S>, f32, N>;
+
+impl<F, T, const N: usize> MultiChannel<F, T, N>
+where
+    F: Filter<T>,
+{
+    /// Creates a multi-channel filter from one filter for each channel
+    pub fn new(filters: [F; N]) -> Self {
+        MultiChannel {
+            filters,
+            _sample: PhantomData,
+        }
+    }
+
+    /// Returns the number of channels
+    pub fn channels(&self) -> usize {
+        N
+    }
+
+    /// Returns a reference to the filter for a channel
+    ///
+    /// # Panics
+    ///
+    /// This function panics if channel is not less than the number of channels.
+    pub fn channel(&self, channel: usize) -> &F {
+        &self.filters[channel]
+    }
+
+    /// Returns a mutable reference to the filter for a channel
+    ///
+    /// # Panics
+    ///
+    /// This function panics if channel is not less than the number of channels.
+    pub fn channel_mut(&mut self, channel: usize) -> &mut F {
+        &mut self.filters[channel]
+    }
+
+    /// Releases the filters
+    pub fn into_inner(self) -> [F; N] {
+        self.filters
+    }
+
+    /// Filters one block of samples for each channel, in planar format
+    ///
+    /// `input[i]` and `output[i]` are the samples for channel i.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the filter for any channel panics because of the lengths of its
+    /// input and output.
+    pub fn process_planar(&mut self, input: &[&[T]; N], output: &mut [&mut [T]; N]) {
+        for ((filter, input), output) in self
+            .filters
+            .iter_mut()
+            .zip(input.iter())
+            .zip(output.iter_mut())
+        {
+            filter.process(input, output);
+        }
+    }
+
+    /// Filters a block of interleaved samples
+    ///
+    /// The length of input and output is the number of channels multiplied by the number of
+    /// frames. scratch is used to separate the channels, and its length must be at least
+    /// twice the number of frames.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length, if the length
+    /// is not a multiple of the number of channels, if scratch is too short, or if the filter
+    /// for any channel panics because of the number of frames.
+    pub fn process_interleaved(&mut self, input: &[T], output: &mut [T], scratch: &mut [T])
+    where
+        T: Copy,
+    {
+        let length: usize = check_length((input.len(), output.len()));
+        if N == 0 {
+            return;
+        }
+        assert!(
+            length % N == 0,
+            "Length not a multiple of the number of channels"
+        );
+        let frames = length / N;
+        assert!(scratch.len() >= 2 * frames, "Scratch buffer too short");
+        let (channel_input, rest) = scratch.split_at_mut(frames);
+        let channel_output = &mut rest[..frames];
+
+        for (channel, filter) in self.filters.iter_mut().enumerate() {
+            for (dst, src) in channel_input
+                .iter_mut()
+                .zip(input[channel..].iter().step_by(N))
+            {
+                *dst = *src;
+            }
+            filter.process(channel_input, channel_output);
+            for (dst, src) in output[channel..]
+                .iter_mut()
+                .step_by(N)
+                .zip(channel_output.iter())
+            {
+                *dst = *src;
+            }
+        }
+    }
+
+    /// Resets the state of the filter for each channel
+    pub fn reset(&mut self) {
+        for filter in self.filters.iter_mut() {
+            filter.reset();
+        }
+    }
+}
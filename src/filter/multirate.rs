@@ -0,0 +1,671 @@
+//! Decimation, interpolation, and rational resampling
+
+use core::convert::TryInto;
+
+use super::Filter;
+use crate::{check_buffers, check_length, Error, Result};
+
+/// Returns the number of state values required for a FIR decimator with the provided number of
+/// taps and maximum block size
+///
+/// This is equal to `num_taps + block_size - 1`. This function returns an error if num_taps or
+/// block_size is zero.
+pub fn fir_decimator_state_length(num_taps: usize, block_size: usize) -> Result<usize> {
+    if num_taps == 0 || block_size == 0 {
+        return Err(Error::Argument);
+    }
+    Ok(num_taps + block_size - 1)
+}
+
+/// Returns the number of state values required for a FIR interpolator with the provided number
+/// of taps, interpolation factor, and maximum block size
+///
+/// This is equal to `num_taps / factor + block_size - 1`. This function returns an error if
+/// num_taps, factor, or block_size is zero.
+pub fn fir_interpolator_state_length(
+    num_taps: usize,
+    factor: u8,
+    block_size: usize,
+) -> Result<usize> {
+    if num_taps == 0 || block_size == 0 {
+        return Err(Error::Argument);
+    }
+    let phase_length = num_taps
+        .checked_div(usize::from(factor))
+        .ok_or(Error::Argument)?;
+    (phase_length + block_size)
+        .checked_sub(1)
+        .ok_or(Error::Argument)
+}
+
+/// A FIR filter combined with a decimator, which operates on floating-point values
+///
+/// The filter is applied to the input, and then one of every M filtered samples is kept.
+///
+/// As in CMSIS-DSP, the coefficients are stored in time-reversed order:
+/// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`.
+pub struct FirDecimatorF32<C, S> {
+    /// Coefficients
+    coefficients: C,
+    /// State buffer
+    state: S,
+    /// Decimation factor
+    factor: u8,
+    /// The maximum number of input samples that can be processed at once
+    block_size: usize,
+}
+
+impl<C, S> FirDecimatorF32<C, S>
+where
+    C: AsRef<[f32]>,
+    S: AsMut<[f32]>,
+{
+    /// Creates a decimator
+    ///
+    /// block_size is the maximum number of input samples that can be processed in one call to
+    /// `process`, and must be a multiple of factor. The length of state must be equal to
+    /// `fir_decimator_state_length(coefficients.len(), block_size)`. The state is initialized to
+    /// zero.
+    ///
+    /// This function returns an error if the number of coefficients is zero or greater than
+    /// `u16::MAX`, if factor is zero, if block_size is not a multiple of factor, or if state has
+    /// the wrong length.
+    pub fn new(coefficients: C, mut state: S, factor: u8, block_size: usize) -> Result<Self> {
+        let num_taps = coefficients.as_ref().len();
+        check_decimator(num_taps, state.as_mut().len(), factor, block_size)?;
+        let mut decimator = FirDecimatorF32 {
+            coefficients,
+            state,
+            factor,
+            block_size,
+        };
+        decimator.reset();
+        Ok(decimator)
+    }
+
+    /// Filters and decimates a block of samples
+    ///
+    /// The length of output must be equal to the length of input divided by the decimation
+    /// factor.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the length of input is not a multiple of the decimation factor,
+    /// if output has the wrong length, or if input is longer than the block size of this
+    /// decimator.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        assert!(
+            input.len() <= self.block_size,
+            "Block too large for decimator"
+        );
+        let coefficients = self.coefficients.as_ref();
+        let state = self.state.as_mut();
+        check_buffers(check_decimator(
+            coefficients.len(),
+            state.len(),
+            self.factor,
+            self.block_size,
+        ));
+        decimate(coefficients, state, self.factor, input, output);
+    }
+
+    /// Sets all state values to zero
+    pub fn reset(&mut self) {
+        for value in self.state.as_mut() {
+            *value = 0.0;
+        }
+    }
+
+    /// Returns the decimation factor
+    pub fn factor(&self) -> u8 {
+        self.factor
+    }
+
+    /// Returns the maximum number of input samples that can be processed at once
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Releases the coefficients and state buffer
+    pub fn into_inner(self) -> (C, S) {
+        (self.coefficients, self.state)
+    }
+}
+
+impl<C, S> Filter<f32> for FirDecimatorF32<C, S>
+where
+    C: AsRef<[f32]>,
+    S: AsMut<[f32]>,
+{
+    fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        Self::process(self, input, output)
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self)
+    }
+}
+
+/// An interpolator combined with a FIR filter, which operates on floating-point values
+///
+/// Conceptually, L - 1 zeros are inserted after each input sample and the result is filtered.
+/// The implementation uses a polyphase filter that skips the multiplications by zero.
+///
+/// The number of coefficients must be a multiple of the interpolation factor. As in CMSIS-DSP,
+/// the coefficients are stored in time-reversed order:
+/// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`.
+pub struct FirInterpolatorF32<C, S> {
+    /// Coefficients
+    coefficients: C,
+    /// State buffer
+    state: S,
+    /// Interpolation factor
+    factor: u8,
+    /// The maximum number of input samples that can be processed at once
+    block_size: usize,
+}
+
+impl<C, S> FirInterpolatorF32<C, S>
+where
+    C: AsRef<[f32]>,
+    S: AsMut<[f32]>,
+{
+    /// Creates an interpolator
+    ///
+    /// block_size is the maximum number of input samples that can be processed in one call to
+    /// `process`. The length of state must be equal to
+    /// `fir_interpolator_state_length(coefficients.len(), factor, block_size)`. The state is
+    /// initialized to zero.
+    ///
+    /// This function returns an error if the number of coefficients is zero, greater than
+    /// `u16::MAX`, or not a multiple of factor, if factor or block_size is zero, or if state has
+    /// the wrong length.
+    pub fn new(coefficients: C, mut state: S, factor: u8, block_size: usize) -> Result<Self> {
+        let num_taps = coefficients.as_ref().len();
+        check_interpolator(num_taps, state.as_mut().len(), factor, block_size)?;
+        let mut interpolator = FirInterpolatorF32 {
+            coefficients,
+            state,
+            factor,
+            block_size,
+        };
+        interpolator.reset();
+        Ok(interpolator)
+    }
+
+    /// Interpolates and filters a block of samples
+    ///
+    /// The length of output must be equal to the length of input multiplied by the
+    /// interpolation factor.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if output has the wrong length, or if input is longer than the
+    /// block size of this interpolator.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        assert!(
+            input.len() <= self.block_size,
+            "Block too large for interpolator"
+        );
+        let coefficients = self.coefficients.as_ref();
+        let state = self.state.as_mut();
+        check_buffers(check_interpolator(
+            coefficients.len(),
+            state.len(),
+            self.factor,
+            self.block_size,
+        ));
+        interpolate(coefficients, state, self.factor, input, output);
+    }
+
+    /// Sets all state values to zero
+    pub fn reset(&mut self) {
+        for value in self.state.as_mut() {
+            *value = 0.0;
+        }
+    }
+
+    /// Returns the interpolation factor
+    pub fn factor(&self) -> u8 {
+        self.factor
+    }
+
+    /// Returns the maximum number of input samples that can be processed at once
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Releases the coefficients and state buffer
+    pub fn into_inner(self) -> (C, S) {
+        (self.coefficients, self.state)
+    }
+}
+
+impl<C, S> Filter<f32> for FirInterpolatorF32<C, S>
+where
+    C: AsRef<[f32]>,
+    S: AsMut<[f32]>,
+{
+    fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        Self::process(self, input, output)
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self)
+    }
+}
+
+/// Returns the length of the buffer required for a rational resampler
+///
+/// The buffer holds the most recent input samples. Its length is the same as the state length
+/// of a FIR interpolator, `fir_interpolator_state_length(num_taps, interpolation, block_size)`.
+/// This function returns an error if num_taps, interpolation, or block_size is zero.
+pub fn resampler_buffer_length(
+    num_taps: usize,
+    interpolation: u8,
+    block_size: usize,
+) -> Result<usize> {
+    fir_interpolator_state_length(num_taps, interpolation, block_size)
+}
+
+/// A rational resampler, which changes the sample rate by a factor of L / M
+///
+/// The resampler behaves like a FIR interpolator with a factor of L followed by a decimator with
+/// a factor of M, but it uses a polyphase filter: it only calculates the samples that the
+/// decimator keeps, and it skips the multiplications by the zeros that the interpolator inserts.
+/// Each output sample takes `num_taps / L` multiplications, and the buffer only needs to hold
+/// the input samples.
+///
+/// All filtering happens at the interpolated sample rate, so the coefficients must remove both
+/// interpolation images and components that would alias after decimation. The cutoff frequency
+/// should be the lower of the input and output Nyquist frequencies, and the passband gain should
+/// be L. For example, [`design_fir`](super::design::design_fir) can design the coefficients with
+/// `FirType::LowPass { cutoff: input_rate.min(output_rate) / 2.0 }` and a sample rate of
+/// `L * input_rate`, using a number of taps that is a multiple of L. Each coefficient must then
+/// be multiplied by L. More taps per phase (`num_taps / L`) give a sharper transition.
+///
+/// For example, to convert from 44.1 kHz to 48 kHz, use L = 160 and M = 147. With 16 taps per
+/// phase, the filter has 2560 coefficients. Because the length of each block multiplied by L
+/// must be a multiple of M, the input blocks must have a multiple of 147 samples.
+///
+/// As in CMSIS-DSP, the coefficients are stored in time-reversed order:
+/// `[b[num_taps - 1], b[num_taps - 2], ..., b[1], b[0]]`. The coefficients from a linear-phase
+/// design such as `design_fir` are symmetric, so their order does not matter.
+pub struct ResamplerF32<C, S> {
+    /// Coefficients
+    coefficients: C,
+    /// Buffer for previous input samples, followed by space for a block of input samples
+    buffer: S,
+    /// Interpolation factor (L)
+    interpolation: u8,
+    /// Decimation factor (M)
+    decimation: u8,
+    /// The maximum number of input samples that can be processed at once
+    block_size: usize,
+}
+
+impl<C, S> ResamplerF32<C, S>
+where
+    C: AsRef<[f32]>,
+    S: AsMut<[f32]>,
+{
+    /// Creates a resampler
+    ///
+    /// block_size is the maximum number of input samples that can be processed in one call to
+    /// `process`. The length of buffer must be equal to
+    /// `resampler_buffer_length(coefficients.len(), interpolation, block_size)`.
+    ///
+    /// This function returns an error if the number of coefficients is zero, greater than
+    /// `u16::MAX`, or not a multiple of interpolation, if interpolation, decimation, or
+    /// block_size is zero, or if buffer has the wrong length.
+    pub fn new(
+        coefficients: C,
+        mut buffer: S,
+        interpolation: u8,
+        decimation: u8,
+        block_size: usize,
+    ) -> Result<Self> {
+        if decimation == 0 {
+            return Err(Error::Argument);
+        }
+        check_resampler(
+            coefficients.as_ref().len(),
+            buffer.as_mut().len(),
+            interpolation,
+            block_size,
+        )?;
+        let mut resampler = ResamplerF32 {
+            coefficients,
+            buffer,
+            interpolation,
+            decimation,
+            block_size,
+        };
+        resampler.reset();
+        Ok(resampler)
+    }
+
+    /// Resamples a block of samples
+    ///
+    /// The length of input multiplied by L must be a multiple of M. The length of output must be
+    /// equal to the length of input multiplied by L and divided by M.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the input length is not valid, if the output has the wrong
+    /// length, or if input is longer than the block size of this resampler.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        assert!(
+            input.len() <= self.block_size,
+            "Block too large for resampler"
+        );
+        let interpolation = usize::from(self.interpolation);
+        let decimation = usize::from(self.decimation);
+        let interpolated_length = input.len() * interpolation;
+        assert_eq!(
+            interpolated_length % decimation,
+            0,
+            "Interpolated length not a multiple of the decimation factor"
+        );
+        assert_eq!(output.len(), interpolated_length / decimation);
+        let coefficients = self.coefficients.as_ref();
+        let buffer = self.buffer.as_mut();
+        check_buffers(check_resampler(
+            coefficients.len(),
+            buffer.len(),
+            self.interpolation,
+            self.block_size,
+        ));
+        let phase_length = coefficients.len() / interpolation;
+        let history = phase_length - 1;
+
+        // The buffer contains the last phase_length - 1 input samples, followed by this block
+        let samples = &mut buffer[..history + input.len()];
+        samples[history..].copy_from_slice(input);
+
+        // Output sample i is sample i * M of the interpolated signal. Interpolated sample
+        // n * L + phase depends on input samples n, n - 1, ..., n - phase_length + 1, which are
+        // multiplied by coefficients phase, phase + L, ..., phase + (phase_length - 1) * L of
+        // the filter in normal order.
+        for (i, out) in output.iter_mut().enumerate() {
+            let interpolated_index = i * decimation;
+            let (n, phase) = (
+                interpolated_index / interpolation,
+                interpolated_index % interpolation,
+            );
+            // The coefficients are time-reversed, so coefficient phase + j * L in normal order
+            // is at index num_taps - 1 - phase - j * L. The oldest sample (j = phase_length - 1)
+            // uses the lowest index.
+            let first_coefficient = interpolation - 1 - phase;
+            let taps = coefficients[first_coefficient..]
+                .iter()
+                .step_by(interpolation);
+            *out = taps
+                .zip(samples[n..=n + history].iter())
+                .map(|(coefficient, sample)| coefficient * sample)
+                .sum();
+        }
+
+        // Keep the last phase_length - 1 samples for the next block
+        samples.copy_within(input.len().., 0);
+    }
+
+    /// Sets all state values to zero
+    pub fn reset(&mut self) {
+        for value in self.buffer.as_mut() {
+            *value = 0.0;
+        }
+    }
+
+    /// Returns the interpolation factor (L)
+    pub fn interpolation(&self) -> u8 {
+        self.interpolation
+    }
+
+    /// Returns the decimation factor (M)
+    pub fn decimation(&self) -> u8 {
+        self.decimation
+    }
+
+    /// Returns the maximum number of input samples that can be processed at once
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Releases the coefficients and buffer
+    pub fn into_inner(self) -> (C, S) {
+        (self.coefficients, self.buffer)
+    }
+}
+
+impl<C, S> Filter<f32> for ResamplerF32<C, S>
+where
+    C: AsRef<[f32]>,
+    S: AsMut<[f32]>,
+{
+    fn process(&mut self, input: &[f32], output: &mut [f32]) {
+        Self::process(self, input, output)
+    }
+
+    fn reset(&mut self) {
+        Self::reset(self)
+    }
+}
+
+/// Runs a FIR decimator
+///
+/// The coefficients and state must already have been checked.
+fn decimate(
+    coefficients: &[f32],
+    state: &mut [f32],
+    factor: u8,
+    input: &[f32],
+    output: &mut [f32],
+) {
+    let length: u32 = check_length(input.len());
+    assert_eq!(
+        input.len() % usize::from(factor),
+        0,
+        "Input length not a multiple of the decimation factor"
+    );
+    assert_eq!(output.len(), input.len() / usize::from(factor));
+    let instance = cmsis_dsp_sys::arm_fir_decimate_instance_f32 {
+        M: factor,
+        numTaps: coefficients.len() as u16,
+        pCoeffs: coefficients.as_ptr(),
+        pState: state.as_mut_ptr(),
+    };
+    unsafe {
+        cmsis_dsp_sys::arm_fir_decimate_f32(&instance, input.as_ptr(), output.as_mut_ptr(), length);
+    }
+}
+
+/// Runs a FIR interpolator
+///
+/// The coefficients and state must already have been checked.
+fn interpolate(
+    coefficients: &[f32],
+    state: &mut [f32],
+    factor: u8,
+    input: &[f32],
+    output: &mut [f32],
+) {
+    let length: u32 = check_length(input.len());
+    assert_eq!(output.len(), input.len() * usize::from(factor));
+    let instance = cmsis_dsp_sys::arm_fir_interpolate_instance_f32 {
+        L: factor,
+        phaseLength: (coefficients.len() / usize::from(factor)) as u16,
+        pCoeffs: coefficients.as_ptr(),
+        pState: state.as_mut_ptr(),
+    };
+    unsafe {
+        cmsis_dsp_sys::arm_fir_interpolate_f32(
+            &instance,
+            input.as_ptr(),
+            output.as_mut_ptr(),
+            length,
+        );
+    }
+}
+
+/// Checks the parameters of a decimator
+fn check_decimator(
+    num_taps: usize,
+    state_length: usize,
+    factor: u8,
+    block_size: usize,
+) -> Result<()> {
+    check_multirate_lengths(num_taps, factor, block_size)?;
+    // Like arm_fir_decimate_init_f32, require the block size to be a multiple of the factor
+    if block_size % usize::from(factor) != 0 {
+        return Err(Error::Length);
+    }
+    if state_length != fir_decimator_state_length(num_taps, block_size)? {
+        return Err(Error::Length);
+    }
+    Ok(())
+}
+
+/// Checks the parameters of an interpolator
+fn check_interpolator(
+    num_taps: usize,
+    state_length: usize,
+    factor: u8,
+    block_size: usize,
+) -> Result<()> {
+    check_multirate_lengths(num_taps, factor, block_size)?;
+    // Like arm_fir_interpolate_init_f32, require the number of taps to be a multiple of the
+    // factor
+    if num_taps % usize::from(factor) != 0 {
+        return Err(Error::Length);
+    }
+    if state_length != fir_interpolator_state_length(num_taps, factor, block_size)? {
+        return Err(Error::Length);
+    }
+    Ok(())
+}
+
+/// Checks the parameters of a resampler, except for the decimation factor
+fn check_resampler(
+    num_taps: usize,
+    buffer_length: usize,
+    interpolation: u8,
+    block_size: usize,
+) -> Result<()> {
+    if buffer_length != resampler_buffer_length(num_taps, interpolation, block_size)? {
+        return Err(Error::Length);
+    }
+    check_interpolator(num_taps, buffer_length, interpolation, block_size)
+}
+
+/// Checks that the number of taps, factor, and block size are in range for a decimator or
+/// interpolator
+fn check_multirate_lengths(num_taps: usize, factor: u8, block_size: usize) -> Result<()> {
+    let _: u16 = num_taps.try_into().map_err(|_| Error::Length)?;
+    let _: u32 = block_size.try_into().map_err(|_| Error::Length)?;
+    if num_taps == 0 || factor == 0 || block_size == 0 {
+        return Err(Error::Argument);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Resamples input by inserting L - 1 zeros after each sample, filtering, and keeping every
+    /// Mth sample
+    fn reference_resample(
+        coefficients: &[f32],
+        interpolation: usize,
+        decimation: usize,
+        input: &[f32],
+        output: &mut [f32],
+    ) {
+        let num_taps = coefficients.len();
+        for (i, out) in output.iter_mut().enumerate() {
+            let t = i * decimation;
+            *out = (0..num_taps)
+                .filter(|k| *k <= t && (t - k) % interpolation == 0)
+                // Coefficients are time-reversed
+                .map(|k| coefficients[num_taps - 1 - k] * input[(t - k) / interpolation])
+                .sum();
+        }
+    }
+
+    #[test]
+    fn resampler_matches_reference() {
+        let coefficients = [0.5, -1.0, 2.0, 0.25, 1.5, -0.75];
+        let input = [
+            1.0, 2.0, -3.0, 0.5, 4.0, -1.0, 0.0, 2.5, -2.0, 1.0, 3.0, -0.5,
+        ];
+        let mut expected = [0.0; 18];
+        reference_resample(&coefficients, 3, 2, &input, &mut expected);
+
+        let mut buffer = [0.0; 5];
+        assert_eq!(resampler_buffer_length(6, 3, 4).unwrap(), buffer.len());
+        let mut resampler = ResamplerF32::new(&coefficients, &mut buffer, 3, 2, 4).unwrap();
+        let mut output = [0.0; 18];
+        for (input, output) in input.chunks(4).zip(output.chunks_mut(6)) {
+            resampler.process(input, output);
+        }
+        assert_eq!(output, expected);
+
+        // Smaller blocks give the same results
+        resampler.reset();
+        let mut output = [0.0; 18];
+        for (input, output) in input.chunks(2).zip(output.chunks_mut(3)) {
+            resampler.process(input, output);
+        }
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn resampler_decimation_greater_than_interpolation() {
+        // L = 2 and M = 3, with 3 taps per phase
+        let coefficients = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+        let input = [1.0, -1.0, 2.0, 0.5, -0.5, 3.0, 1.5, -2.0, 0.0];
+        let mut expected = [0.0; 6];
+        reference_resample(&coefficients, 2, 3, &input, &mut expected);
+
+        let mut buffer = [0.0; 5];
+        let mut resampler = ResamplerF32::new(&coefficients, &mut buffer, 2, 3, 3).unwrap();
+        let mut output = [0.0; 6];
+        for (input, output) in input.chunks(3).zip(output.chunks_mut(2)) {
+            resampler.process(input, output);
+        }
+        for (actual, expected) in output.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-6, "{:?}", output);
+        }
+    }
+
+    #[test]
+    fn resampler_parameters() {
+        // 44.1 kHz to 48 kHz with 16 taps per phase
+        assert_eq!(resampler_buffer_length(2560, 160, 147).unwrap(), 162);
+        let coefficients = [0.0; 4];
+        let mut buffer = [0.0; 5];
+        assert!(matches!(
+            ResamplerF32::new(&coefficients, &mut buffer, 2, 0, 4),
+            Err(Error::Argument)
+        ));
+        // The number of coefficients must be a multiple of L
+        assert!(matches!(
+            ResamplerF32::new(&coefficients, &mut buffer, 3, 2, 4),
+            Err(Error::Length)
+        ));
+        assert!(matches!(
+            ResamplerF32::new(&coefficients, &mut buffer[..4], 2, 3, 4),
+            Err(Error::Length)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Interpolated length not a multiple of the decimation factor")]
+    fn resampler_invalid_block_length() {
+        let coefficients = [1.0; 4];
+        let mut buffer = [0.0; 5];
+        let mut resampler = ResamplerF32::new(&coefficients, &mut buffer, 2, 3, 4).unwrap();
+        resampler.process(&[0.0; 2], &mut [0.0; 1]);
+    }
+}
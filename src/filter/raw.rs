@@ -0,0 +1,8 @@
+//! Filters that operate on fixed-point values stored as raw integers
+//!
+//! These types have the same names and behavior as the fixed-point filters in the parent module,
+//! but they operate on `i32`, `i16`, and `i8` values that hold the bits of Q1.31, Q1.15, and Q1.7
+//! values. They are available whether or not the `fixed` feature is enabled.
+
+pub use super::biquad::raw::{BiquadCascadeDf1Q15, BiquadCascadeDf1Q31, BiquadCascadeDf1Q31x64};
+pub use super::fir::raw::{FirQ15, FirQ31, FirQ7};
@@ -0,0 +1,338 @@
+//! Sparse finite impulse response filters
+
+use core::convert::TryInto;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31, I1F7};
+
+use crate::{check_buffers, check_length, Error, Result};
+
+/// Returns the number of state values required for a sparse FIR filter with the provided
+/// maximum tap delay and block size
+///
+/// This is equal to `max_delay + block_size`.
+pub fn fir_sparse_state_length(max_delay: usize, block_size: usize) -> usize {
+    max_delay + block_size
+}
+
+/// Defines a sparse FIR filter type for one element type
+macro_rules! sparse_fir_filter {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $instance:ident
+    ) => {
+        $(#[$attr])*
+        ///
+        /// A sparse filter has a small number of nonzero taps at arbitrary delays.
+        /// `coefficients[i]` is applied to the input sample from `tap_delays[i]` samples ago.
+        ///
+        /// The state is a circular buffer, so every call to `process` must use exactly the
+        /// block size of the filter.
+        pub struct $name<C, D, S> {
+            /// Coefficients
+            coefficients: C,
+            /// Delay of each tap, in samples
+            tap_delays: D,
+            /// State buffer
+            state: S,
+            /// Largest value in tap_delays
+            max_delay: u16,
+            /// Current position in the state buffer
+            state_index: u16,
+            /// The number of samples processed at once
+            block_size: usize,
+        }
+
+        impl<C, D, S> $name<C, D, S>
+        where
+            C: AsRef<[$element]>,
+            D: AsRef<[i32]>,
+            S: AsMut<[$element]>,
+        {
+            /// Creates a filter
+            ///
+            /// coefficients and tap_delays must have the same length. The length of state must
+            /// be equal to `fir_sparse_state_length(max_delay, block_size)`, where max_delay is
+            /// the largest value in tap_delays. The state is initialized to zero.
+            ///
+            /// This function returns an error if there are no coefficients, if block_size is
+            /// zero, if a tap delay is negative or greater than 65535, or if tap_delays or state
+            /// has the wrong length.
+            pub fn new(coefficients: C, tap_delays: D, mut state: S, block_size: usize) -> Result<Self> {
+                let max_delay = check_sparse_lengths(
+                    coefficients.as_ref().len(),
+                    tap_delays.as_ref(),
+                    state.as_mut().len(),
+                    block_size,
+                )?;
+                let mut filter = $name {
+                    coefficients,
+                    tap_delays,
+                    state,
+                    max_delay,
+                    state_index: 0,
+                    block_size,
+                };
+                filter.reset();
+                Ok(filter)
+            }
+
+            /// Sets all state values to zero
+            pub fn reset(&mut self) {
+                for value in self.state.as_mut() {
+                    *value = Default::default();
+                }
+                self.state_index = 0;
+            }
+
+            /// Returns the coefficients of this filter
+            pub fn coefficients(&self) -> &[$element] {
+                self.coefficients.as_ref()
+            }
+
+            /// Returns the delay of each tap, in samples
+            pub fn tap_delays(&self) -> &[i32] {
+                self.tap_delays.as_ref()
+            }
+
+            /// Returns the number of samples that must be processed at once
+            pub fn block_size(&self) -> usize {
+                self.block_size
+            }
+
+            /// Releases the coefficients, tap delays, and state buffer
+            pub fn into_inner(self) -> (C, D, S) {
+                (self.coefficients, self.tap_delays, self.state)
+            }
+
+            /// Checks the lengths of an input block, output block, and scratch buffer, and
+            /// returns the length
+            fn check_block(&self, input: &[$element], output: &[$element], scratch: &[$element]) -> u32 {
+                let length = check_length((input.len(), output.len(), scratch.len()));
+                assert_eq!(input.len(), self.block_size, "Block size not equal to filter block size");
+                length
+            }
+
+            /// Creates a CMSIS-DSP instance that refers to the coefficients and state
+            ///
+            /// The filter functions update the state index in the instance, so it must be
+            /// copied back into this filter after each block.
+            fn instance(&mut self) -> cmsis_dsp_sys::$instance {
+                let coefficients = self.coefficients.as_ref();
+                let tap_delays = self.tap_delays.as_ref();
+                let state = self.state.as_mut();
+                // The state index depends on the maximum delay, so the delays must not change
+                let max_delay = self.max_delay;
+                check_buffers(
+                    check_sparse_lengths(coefficients.len(), tap_delays, state.len(), self.block_size)
+                        .and_then(|delay| if delay == max_delay { Ok(()) } else { Err(Error::Argument) }),
+                );
+                cmsis_dsp_sys::$instance {
+                    numTaps: coefficients.len() as u16,
+                    stateIndex: self.state_index,
+                    pState: state.as_mut_ptr() as *mut _,
+                    pCoeffs: coefficients.as_ptr() as *const _,
+                    maxDelay: max_delay,
+                    // The CMSIS-DSP filter functions do not write through this pointer
+                    pTapDelay: tap_delays.as_ptr() as *mut _,
+                }
+            }
+        }
+    };
+}
+
+sparse_fir_filter! {
+    /// A sparse finite impulse response filter that operates on floating-point values
+    FirSparseF32, f32, arm_fir_sparse_instance_f32
+}
+#[cfg(feature = "fixed")]
+sparse_fir_filter! {
+    /// A sparse finite impulse response filter that operates on Q1.31 fixed-point values
+    ///
+    /// This filter uses a 32-bit accumulator, so the results may wrap around on overflow. To
+    /// avoid overflow, the input should be scaled down by log2(number of taps) bits.
+    FirSparseQ31, I1F31, arm_fir_sparse_instance_q31
+}
+#[cfg(feature = "fixed")]
+sparse_fir_filter! {
+    /// A sparse finite impulse response filter that operates on Q1.15 fixed-point values
+    ///
+    /// This filter uses a 32-bit accumulator and saturates the results.
+    FirSparseQ15, I1F15, arm_fir_sparse_instance_q15
+}
+#[cfg(feature = "fixed")]
+sparse_fir_filter! {
+    /// A sparse finite impulse response filter that operates on Q1.7 fixed-point values
+    ///
+    /// This filter uses a 32-bit accumulator and saturates the results.
+    FirSparseQ7, I1F7, arm_fir_sparse_instance_q7
+}
+
+impl<C, D, S> FirSparseF32<C, D, S>
+where
+    C: AsRef<[f32]>,
+    D: AsRef<[i32]>,
+    S: AsMut<[f32]>,
+{
+    /// Filters a block of samples
+    ///
+    /// scratch is used as temporary storage.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the lengths of input, output, and scratch are not all equal to
+    /// the block size of this filter.
+    pub fn process(&mut self, input: &[f32], output: &mut [f32], scratch: &mut [f32]) {
+        let length = self.check_block(input, output, scratch);
+        let mut instance = self.instance();
+        unsafe {
+            cmsis_dsp_sys::arm_fir_sparse_f32(
+                &mut instance,
+                input.as_ptr(),
+                output.as_mut_ptr(),
+                scratch.as_mut_ptr(),
+                length,
+            );
+        }
+        self.state_index = instance.stateIndex;
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<C, D, S> FirSparseQ31<C, D, S>
+where
+    C: AsRef<[I1F31]>,
+    D: AsRef<[i32]>,
+    S: AsMut<[I1F31]>,
+{
+    /// Filters a block of samples
+    ///
+    /// scratch is used as temporary storage.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the lengths of input, output, and scratch are not all equal to
+    /// the block size of this filter.
+    pub fn process(&mut self, input: &[I1F31], output: &mut [I1F31], scratch: &mut [I1F31]) {
+        let length = self.check_block(input, output, scratch);
+        let mut instance = self.instance();
+        unsafe {
+            cmsis_dsp_sys::arm_fir_sparse_q31(
+                &mut instance,
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                scratch.as_mut_ptr() as *mut _,
+                length,
+            );
+        }
+        self.state_index = instance.stateIndex;
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<C, D, S> FirSparseQ15<C, D, S>
+where
+    C: AsRef<[I1F15]>,
+    D: AsRef<[i32]>,
+    S: AsMut<[I1F15]>,
+{
+    /// Filters a block of samples
+    ///
+    /// scratch_in and scratch_out are used as temporary storage.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the lengths of input, output, scratch_in, and scratch_out are
+    /// not all equal to the block size of this filter.
+    pub fn process(
+        &mut self,
+        input: &[I1F15],
+        output: &mut [I1F15],
+        scratch_in: &mut [I1F15],
+        scratch_out: &mut [i32],
+    ) {
+        let length = self.check_block(input, output, scratch_in);
+        assert_eq!(scratch_out.len(), input.len());
+        let mut instance = self.instance();
+        unsafe {
+            cmsis_dsp_sys::arm_fir_sparse_q15(
+                &mut instance,
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                scratch_in.as_mut_ptr() as *mut _,
+                scratch_out.as_mut_ptr(),
+                length,
+            );
+        }
+        self.state_index = instance.stateIndex;
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl<C, D, S> FirSparseQ7<C, D, S>
+where
+    C: AsRef<[I1F7]>,
+    D: AsRef<[i32]>,
+    S: AsMut<[I1F7]>,
+{
+    /// Filters a block of samples
+    ///
+    /// scratch_in and scratch_out are used as temporary storage.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the lengths of input, output, scratch_in, and scratch_out are
+    /// not all equal to the block size of this filter.
+    pub fn process(
+        &mut self,
+        input: &[I1F7],
+        output: &mut [I1F7],
+        scratch_in: &mut [I1F7],
+        scratch_out: &mut [i32],
+    ) {
+        let length = self.check_block(input, output, scratch_in);
+        assert_eq!(scratch_out.len(), input.len());
+        let mut instance = self.instance();
+        unsafe {
+            cmsis_dsp_sys::arm_fir_sparse_q7(
+                &mut instance,
+                input.as_ptr() as *const _,
+                output.as_mut_ptr() as *mut _,
+                scratch_in.as_mut_ptr() as *mut _,
+                scratch_out.as_mut_ptr(),
+                length,
+            );
+        }
+        self.state_index = instance.stateIndex;
+    }
+}
+
+/// Checks the number of taps, tap delays, state length, and block size of a sparse FIR
+/// filter, and returns the maximum delay
+fn check_sparse_lengths(
+    num_taps: usize,
+    tap_delays: &[i32],
+    state_length: usize,
+    block_size: usize,
+) -> Result<u16> {
+    let _: u16 = num_taps.try_into().map_err(|_| Error::Length)?;
+    if num_taps == 0 || block_size == 0 {
+        return Err(Error::Argument);
+    }
+    if tap_delays.len() != num_taps {
+        return Err(Error::Length);
+    }
+    let mut max_delay = 0u16;
+    for &delay in tap_delays {
+        let delay: u16 = delay.try_into().map_err(|_| Error::Argument)?;
+        max_delay = max_delay.max(delay);
+    }
+    // The state index is a u16, so the whole circular buffer must be addressable with one
+    let _: u16 = fir_sparse_state_length(max_delay.into(), block_size)
+        .try_into()
+        .map_err(|_| Error::Length)?;
+    if state_length != fir_sparse_state_length(max_delay.into(), block_size) {
+        return Err(Error::Length);
+    }
+    Ok(max_delay)
+}
@@ -0,0 +1,628 @@
+//! Interpolation functions
+//!
+//! CMSIS-DSP implements linear and bilinear interpolation as inline functions, so those
+//! interpolators are implemented in Rust. Cubic spline interpolation uses the CMSIS-DSP
+//! functions.
+//!
+//! The linear interpolators and the floating-point bilinear interpolator use the same arithmetic
+//! as CMSIS-DSP. The fixed-point bilinear interpolators calculate the weighted sum of the four
+//! corner values exactly and truncate only the final result. CMSIS-DSP truncates the
+//! intermediate products, so its results can differ in the least significant bits.
+//!
+//! The fixed-point interpolators take x values in Q12.20 format. The integer part of x is the
+//! index into the table, and the fractional part is the position between two table entries.
+
+use core::convert::TryInto;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I12F20, I1F15, I1F31, I1F7};
+
+use crate::{check_buffers, check_length, Error, Result};
+
+/// A linear interpolator that operates on a table of floating-point values
+///
+/// The table contains y values at evenly spaced x values: `table[i]` is the y value at
+/// `x1 + i * x_spacing`. For x values outside the table, the interpolator returns the first or
+/// last table value.
+pub struct LinearInterpolatorF32<Y> {
+    /// The y values
+    table: Y,
+    /// The x value that corresponds to the first y value
+    x1: f32,
+    /// The difference between the x values of consecutive y values
+    x_spacing: f32,
+}
+
+impl<Y> LinearInterpolatorF32<Y>
+where
+    Y: AsRef<[f32]>,
+{
+    /// Creates an interpolator
+    ///
+    /// This function returns an error if the table is empty or if x_spacing is not positive
+    /// and finite.
+    pub fn new(x1: f32, x_spacing: f32, table: Y) -> Result<Self> {
+        if table.as_ref().is_empty() {
+            return Err(Error::Length);
+        }
+        if !(x_spacing > 0.0 && x_spacing.is_finite() && x1.is_finite()) {
+            return Err(Error::Argument);
+        }
+        Ok(LinearInterpolatorF32 {
+            table,
+            x1,
+            x_spacing,
+        })
+    }
+
+    /// Calculates the interpolated y value at an x value
+    pub fn interpolate(&self, x: f32) -> f32 {
+        let table = self.table.as_ref();
+        let last = table.len() - 1;
+        if x < self.x1 {
+            return table[0];
+        }
+        let index = (x - self.x1) / self.x_spacing;
+        if index.is_nan() || index >= last as f32 {
+            return table[last];
+        }
+        let index = index as usize;
+        let x0 = self.x1 + index as f32 * self.x_spacing;
+        let x1 = self.x1 + (index + 1) as f32 * self.x_spacing;
+        let (y0, y1) = (table[index], table[index + 1]);
+        y0 + (x - x0) * ((y1 - y0) / (x1 - x0))
+    }
+
+    /// Returns the table of y values
+    pub fn table(&self) -> &[f32] {
+        self.table.as_ref()
+    }
+
+    /// Returns the x value that corresponds to the first y value
+    pub fn x1(&self) -> f32 {
+        self.x1
+    }
+
+    /// Returns the difference between the x values of consecutive y values
+    pub fn x_spacing(&self) -> f32 {
+        self.x_spacing
+    }
+
+    /// Releases the table
+    pub fn into_inner(self) -> Y {
+        self.table
+    }
+}
+
+/// The maximum number of values in a fixed-point interpolation table, which is limited by the
+/// integer part of a Q12.20 x value
+#[cfg(feature = "fixed")]
+const MAX_FIXED_TABLE_LENGTH: usize = 2048;
+
+/// Defines a fixed-point linear interpolator type for one element type
+#[cfg(feature = "fixed")]
+macro_rules! fixed_linear_interpolator {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $interpolate:expr
+    ) => {
+        $(#[$attr])*
+        ///
+        /// `table[i]` is the y value at x = i. For x values outside the table, the interpolator
+        /// returns the first or last table value.
+        pub struct $name<Y> {
+            /// The y values
+            table: Y,
+        }
+
+        impl<Y> $name<Y>
+        where
+            Y: AsRef<[$element]>,
+        {
+            /// Creates an interpolator
+            ///
+            /// This function returns an error if the table is empty or has more than 2048
+            /// values.
+            pub fn new(table: Y) -> Result<Self> {
+                check_fixed_table_length(table.as_ref().len())?;
+                Ok($name { table })
+            }
+
+            /// Calculates the interpolated y value at an x value
+            pub fn interpolate(&self, x: I12F20) -> $element {
+                let table = self.table.as_ref();
+                let last = table.len() - 1;
+                let index = x.to_bits() >> 20;
+                if index < 0 {
+                    return table[0];
+                }
+                let index = index as usize;
+                if index >= last {
+                    return table[last];
+                }
+                let fraction = x.to_bits() & 0x000f_ffff;
+                let interpolate: fn($element, $element, i32) -> $element = $interpolate;
+                interpolate(table[index], table[index + 1], fraction)
+            }
+
+            /// Returns the table of y values
+            pub fn table(&self) -> &[$element] {
+                self.table.as_ref()
+            }
+
+            /// Releases the table
+            pub fn into_inner(self) -> Y {
+                self.table
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+fixed_linear_interpolator! {
+    /// A linear interpolator that operates on a table of Q1.31 fixed-point values
+    LinearInterpolatorQ31, I1F31, |y0, y1, fraction| {
+        let fraction = i64::from(fraction << 11);
+        let y = ((i64::from(y0.to_bits()) * (0x7fff_ffff - fraction)) >> 32) as i32
+            + ((i64::from(y1.to_bits()) * fraction) >> 32) as i32;
+        I1F31::from_bits(y << 1)
+    }
+}
+#[cfg(feature = "fixed")]
+fixed_linear_interpolator! {
+    /// A linear interpolator that operates on a table of Q1.15 fixed-point values
+    LinearInterpolatorQ15, I1F15, |y0, y1, fraction| {
+        let fraction = i64::from(fraction);
+        let y = i64::from(y0.to_bits()) * (0x000f_ffff - fraction)
+            + i64::from(y1.to_bits()) * fraction;
+        I1F15::from_bits((y >> 20) as i16)
+    }
+}
+#[cfg(feature = "fixed")]
+fixed_linear_interpolator! {
+    /// A linear interpolator that operates on a table of Q1.7 fixed-point values
+    LinearInterpolatorQ7, I1F7, |y0, y1, fraction| {
+        let y = i32::from(y0.to_bits()) * (0x000f_ffff - fraction)
+            + i32::from(y1.to_bits()) * fraction;
+        I1F7::from_bits((y >> 20) as i8)
+    }
+}
+
+/// A bilinear interpolator that operates on a two-dimensional table of floating-point values
+///
+/// The table is stored in row-major order: the value at column x and row y is
+/// `table[y * columns + x]`. The interpolator returns None for points outside the table.
+pub struct BilinearInterpolatorF32<T> {
+    /// The values, in row-major order
+    table: T,
+    /// The number of rows
+    rows: usize,
+    /// The number of columns
+    columns: usize,
+}
+
+impl<T> BilinearInterpolatorF32<T>
+where
+    T: AsRef<[f32]>,
+{
+    /// Creates an interpolator
+    ///
+    /// This function returns an error if the table has fewer than two rows or two columns,
+    /// or if the length of the table is not equal to `rows * columns`.
+    pub fn new(table: T, rows: usize, columns: usize) -> Result<Self> {
+        check_bilinear_dimensions(table.as_ref().len(), rows, columns)?;
+        Ok(BilinearInterpolatorF32 {
+            table,
+            rows,
+            columns,
+        })
+    }
+
+    /// Calculates the interpolated value at column x and row y
+    ///
+    /// This function returns None if x is not in the range [0, columns - 1) or y is not in
+    /// the range [0, rows - 1).
+    pub fn interpolate(&self, x: f32, y: f32) -> Option<f32> {
+        if !(x >= 0.0 && y >= 0.0) {
+            return None;
+        }
+        let (column, row) = (x as usize, y as usize);
+        if column > self.columns - 2 || row > self.rows - 2 {
+            return None;
+        }
+        let table = self.table.as_ref();
+        let index = row * self.columns + column;
+        let (f00, f01) = (table[index], table[index + 1]);
+        let index = index + self.columns;
+        let (f10, f11) = (table[index], table[index + 1]);
+        let x_diff = x - column as f32;
+        let y_diff = y - row as f32;
+        Some(
+            f00 + (f01 - f00) * x_diff
+                + (f10 - f00) * y_diff
+                + (f00 - f01 - f10 + f11) * x_diff * y_diff,
+        )
+    }
+
+    /// Returns the number of rows in the table
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the table
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the table, in row-major order
+    pub fn table(&self) -> &[f32] {
+        self.table.as_ref()
+    }
+
+    /// Releases the table
+    pub fn into_inner(self) -> T {
+        self.table
+    }
+}
+
+/// Defines a fixed-point bilinear interpolator type for one element type
+#[cfg(feature = "fixed")]
+macro_rules! fixed_bilinear_interpolator {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $bits:ty
+    ) => {
+        $(#[$attr])*
+        ///
+        /// The table is stored in row-major order: the value at column x and row y is
+        /// `table[y * columns + x]`. The interpolator returns None for points outside the
+        /// table.
+        pub struct $name<T> {
+            /// The values, in row-major order
+            table: T,
+            /// The number of rows
+            rows: usize,
+            /// The number of columns
+            columns: usize,
+        }
+
+        impl<T> $name<T>
+        where
+            T: AsRef<[$element]>,
+        {
+            /// Creates an interpolator
+            ///
+            /// This function returns an error if the table has fewer than two rows or two
+            /// columns or more than 2048 rows or columns, or if the length of the table is not
+            /// equal to `rows * columns`.
+            pub fn new(table: T, rows: usize, columns: usize) -> Result<Self> {
+                check_bilinear_dimensions(table.as_ref().len(), rows, columns)?;
+                if rows > MAX_FIXED_TABLE_LENGTH || columns > MAX_FIXED_TABLE_LENGTH {
+                    return Err(Error::Length);
+                }
+                Ok($name {
+                    table,
+                    rows,
+                    columns,
+                })
+            }
+
+            /// Calculates the interpolated value at column x and row y
+            ///
+            /// This function returns None if x is not in the range [0, columns - 1) or y is
+            /// not in the range [0, rows - 1).
+            pub fn interpolate(&self, x: I12F20, y: I12F20) -> Option<$element> {
+                let (column, row) = (x.to_bits() >> 20, y.to_bits() >> 20);
+                if column < 0 || row < 0 {
+                    return None;
+                }
+                let (column, row) = (column as usize, row as usize);
+                if column > self.columns - 2 || row > self.rows - 2 {
+                    return None;
+                }
+                let table = self.table.as_ref();
+                let index = row * self.columns + column;
+                let corners = [
+                    table[index].to_bits().into(),
+                    table[index + 1].to_bits().into(),
+                    table[index + self.columns].to_bits().into(),
+                    table[index + self.columns + 1].to_bits().into(),
+                ];
+                let value = bilinear_bits(corners, x.frac().to_bits(), y.frac().to_bits());
+                Some(<$element>::from_bits(value as $bits))
+            }
+
+            /// Returns the number of rows in the table
+            pub fn rows(&self) -> usize {
+                self.rows
+            }
+
+            /// Returns the number of columns in the table
+            pub fn columns(&self) -> usize {
+                self.columns
+            }
+
+            /// Returns the table, in row-major order
+            pub fn table(&self) -> &[$element] {
+                self.table.as_ref()
+            }
+
+            /// Releases the table
+            pub fn into_inner(self) -> T {
+                self.table
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+fixed_bilinear_interpolator! {
+    /// A bilinear interpolator that operates on a two-dimensional table of Q1.31 fixed-point
+    /// values
+    BilinearInterpolatorQ31, I1F31, i32
+}
+#[cfg(feature = "fixed")]
+fixed_bilinear_interpolator! {
+    /// A bilinear interpolator that operates on a two-dimensional table of Q1.15 fixed-point
+    /// values
+    BilinearInterpolatorQ15, I1F15, i16
+}
+#[cfg(feature = "fixed")]
+fixed_bilinear_interpolator! {
+    /// A bilinear interpolator that operates on a two-dimensional table of Q1.7 fixed-point
+    /// values
+    BilinearInterpolatorQ7, I1F7, i8
+}
+
+/// Boundary conditions for cubic spline interpolation
+#[derive(Debug, Copy, Clone)]
+pub enum SplineType {
+    /// Natural spline, which has a second derivative of zero at both ends
+    Natural = 0,
+    /// Parabolic runout spline, which has the same second derivative at the first two points
+    /// and at the last two points
+    ParabolicRunout = 1,
+}
+
+/// Returns the number of coefficients required for a cubic spline with the provided number of
+/// known points
+///
+/// This is equal to `3 * (points - 1)`.
+pub fn spline_coefficients_length(points: usize) -> usize {
+    3 * points.saturating_sub(1)
+}
+
+/// Returns the length of the temporary buffer required to create a cubic spline with the
+/// provided number of known points
+///
+/// This is equal to `2 * points - 1`.
+pub fn spline_temp_length(points: usize) -> usize {
+    (2 * points).saturating_sub(1)
+}
+
+/// A cubic spline interpolator that operates on floating-point values
+///
+/// The spline passes through a set of known points, which do not need to be evenly spaced.
+/// Points outside the range of the known x values are extrapolated.
+pub struct SplineF32<X, Y, C> {
+    /// x values of the known points
+    x: X,
+    /// y values of the known points
+    y: Y,
+    /// Coefficients calculated from the known points
+    coefficients: C,
+    /// Boundary conditions
+    spline_type: SplineType,
+}
+
+impl<X, Y, C> SplineF32<X, Y, C>
+where
+    X: AsRef<[f32]>,
+    Y: AsRef<[f32]>,
+    C: AsMut<[f32]>,
+{
+    /// Creates a spline through the known points with x values x and y values y
+    ///
+    /// The x values must be in strictly increasing order. The length of coefficients must be
+    /// equal to `spline_coefficients_length(x.len())`, and the length of temp must be equal to
+    /// `spline_temp_length(x.len())`. temp is only used while creating the spline.
+    ///
+    /// This function returns an error if there are fewer than two known points, if the x values
+    /// are not strictly increasing, or if y, coefficients, or temp has the wrong length.
+    pub fn new(
+        spline_type: SplineType,
+        x: X,
+        y: Y,
+        mut coefficients: C,
+        temp: &mut [f32],
+    ) -> Result<Self> {
+        let points = x.as_ref().len();
+        let _: u32 = points.try_into().map_err(|_| Error::Length)?;
+        if points < 2
+            || y.as_ref().len() != points
+            || coefficients.as_mut().len() != spline_coefficients_length(points)
+            || temp.len() != spline_temp_length(points)
+        {
+            return Err(Error::Length);
+        }
+        if !x.as_ref().windows(2).all(|pair| pair[0] < pair[1]) {
+            return Err(Error::Argument);
+        }
+        let mut instance = cmsis_dsp_sys::arm_spline_instance_f32 {
+            type_: 0,
+            x: core::ptr::null(),
+            y: core::ptr::null(),
+            n_x: 0,
+            coeffs: core::ptr::null_mut(),
+        };
+        unsafe {
+            cmsis_dsp_sys::arm_spline_init_f32(
+                &mut instance,
+                spline_type as _,
+                x.as_ref().as_ptr(),
+                y.as_ref().as_ptr(),
+                points as u32,
+                coefficients.as_mut().as_mut_ptr(),
+                temp.as_mut_ptr(),
+            );
+        }
+        Ok(SplineF32 {
+            x,
+            y,
+            coefficients,
+            spline_type,
+        })
+    }
+
+    /// Calculates the interpolated y values at the x values in xq
+    ///
+    /// # Panics
+    ///
+    /// This function panics if xq and dst do not have the same length.
+    pub fn interpolate(&mut self, xq: &[f32], dst: &mut [f32]) {
+        let length = check_length((xq.len(), dst.len()));
+        let x = self.x.as_ref();
+        let y = self.y.as_ref();
+        let coefficients = self.coefficients.as_mut();
+        check_buffers(
+            if x.len() >= 2
+                && y.len() == x.len()
+                && coefficients.len() == spline_coefficients_length(x.len())
+            {
+                Ok(())
+            } else {
+                Err(Error::Length)
+            },
+        );
+        let mut instance = cmsis_dsp_sys::arm_spline_instance_f32 {
+            type_: self.spline_type as _,
+            x: x.as_ptr(),
+            y: y.as_ptr(),
+            n_x: x.len() as u32,
+            coeffs: coefficients.as_mut_ptr(),
+        };
+        unsafe {
+            cmsis_dsp_sys::arm_spline_f32(&mut instance, xq.as_ptr(), dst.as_mut_ptr(), length);
+        }
+    }
+
+    /// Returns the boundary conditions of this spline
+    pub fn spline_type(&self) -> SplineType {
+        self.spline_type
+    }
+
+    /// Returns the x values of the known points
+    pub fn x(&self) -> &[f32] {
+        self.x.as_ref()
+    }
+
+    /// Returns the y values of the known points
+    pub fn y(&self) -> &[f32] {
+        self.y.as_ref()
+    }
+
+    /// Releases the x values, y values, and coefficients
+    pub fn into_inner(self) -> (X, Y, C) {
+        (self.x, self.y, self.coefficients)
+    }
+}
+
+/// Interpolates between four corner values
+///
+/// The corners are `[f00, f01, f10, f11]`, where the first digit is the row and the second
+/// digit is the column. x_fraction and y_fraction are the position between the columns and
+/// rows, with 20 fractional bits.
+#[cfg(feature = "fixed")]
+fn bilinear_bits(corners: [i32; 4], x_fraction: i32, y_fraction: i32) -> i64 {
+    let (x1, y1) = (i128::from(x_fraction), i128::from(y_fraction));
+    let (x0, y0) = ((1 << 20) - x1, (1 << 20) - y1);
+    let [f00, f01, f10, f11] = corners;
+    let sum = i128::from(f00) * x0 * y0
+        + i128::from(f01) * x1 * y0
+        + i128::from(f10) * x0 * y1
+        + i128::from(f11) * x1 * y1;
+    (sum >> 40) as i64
+}
+
+/// Checks the dimensions of a bilinear interpolation table
+fn check_bilinear_dimensions(length: usize, rows: usize, columns: usize) -> Result<()> {
+    if rows < 2 || columns < 2 || rows.checked_mul(columns) != Some(length) {
+        return Err(Error::Length);
+    }
+    Ok(())
+}
+
+/// Checks the length of a fixed-point interpolation table
+#[cfg(feature = "fixed")]
+fn check_fixed_table_length(length: usize) -> Result<()> {
+    let _: u32 = length.try_into().map_err(|_| Error::Length)?;
+    if length == 0 || length > MAX_FIXED_TABLE_LENGTH {
+        return Err(Error::Length);
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "fixed"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bilinear_q15_reference() {
+        let table = [0.0, 0.5, -0.5, 0.25].map(I1F15::from_num);
+        let interpolator = BilinearInterpolatorQ15::new(table, 2, 2).unwrap();
+        let at =
+            |x: f32, y: f32| interpolator.interpolate(I12F20::from_num(x), I12F20::from_num(y));
+        assert_eq!(at(0.0, 0.0), Some(I1F15::from_num(0.0)));
+        assert_eq!(at(0.5, 0.0), Some(I1F15::from_num(0.25)));
+        assert_eq!(at(0.0, 0.5), Some(I1F15::from_num(-0.25)));
+        assert_eq!(at(0.5, 0.5), Some(I1F15::from_num(0.0625)));
+        assert_eq!(at(0.25, 0.75), Some(I1F15::from_num(-0.203125)));
+        assert_eq!(at(1.0, 0.0), None);
+        assert_eq!(at(0.0, -0.25), None);
+    }
+
+    #[test]
+    fn bilinear_q31_is_exact() {
+        // Interpolating between equal values returns that value exactly
+        let interpolator = BilinearInterpolatorQ31::new([I1F31::MAX; 4], 2, 2).unwrap();
+        let value = interpolator.interpolate(I12F20::from_num(0.5), I12F20::from_num(0.5));
+        assert_eq!(value, Some(I1F31::MAX));
+
+        // Only the final result is truncated, toward negative infinity
+        let table = [0, 1, 0, 0].map(I1F31::from_bits);
+        let interpolator = BilinearInterpolatorQ31::new(table, 2, 2).unwrap();
+        let value = interpolator.interpolate(I12F20::from_num(0.5), I12F20::from_num(0.0));
+        assert_eq!(value, Some(I1F31::from_bits(0)));
+        let table = [0, -1, 0, 0].map(I1F31::from_bits);
+        let interpolator = BilinearInterpolatorQ31::new(table, 2, 2).unwrap();
+        let value = interpolator.interpolate(I12F20::from_num(0.5), I12F20::from_num(0.0));
+        assert_eq!(value, Some(I1F31::from_bits(-1)));
+    }
+
+    #[test]
+    fn bilinear_q7_indexing() {
+        // table[y * columns + x] = 8 * (3 * y + x)
+        let table = [0, 8, 16, 24, 32, 40, 48, 56, 64].map(I1F7::from_bits);
+        let interpolator = BilinearInterpolatorQ7::new(table, 3, 3).unwrap();
+        let at =
+            |x: f32, y: f32| interpolator.interpolate(I12F20::from_num(x), I12F20::from_num(y));
+        assert_eq!(at(1.5, 0.5), Some(I1F7::from_bits(24)));
+        assert_eq!(at(1.0, 1.0), Some(I1F7::from_bits(32)));
+        assert_eq!(at(0.5, 1.75), Some(I1F7::from_bits(46)));
+        assert_eq!(at(2.0, 0.0), None);
+        assert_eq!(at(0.0, 2.0), None);
+    }
+
+    #[test]
+    fn bilinear_dimensions() {
+        let table = [I1F15::from_num(0.0); 4];
+        assert!(matches!(
+            BilinearInterpolatorQ15::new(table, 1, 4),
+            Err(Error::Length)
+        ));
+        assert!(matches!(
+            BilinearInterpolatorQ15::new(table, 2, 3),
+            Err(Error::Length)
+        ));
+    }
+}
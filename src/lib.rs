@@ -20,20 +20,79 @@
 //! All other targets will be configured automatically based on the target passed to cargo. If you forget to enable a
 //! feature, everything should still work but it may be slower.
 //!
+//! ## Fixed-point functions
+//!
+//! The functions and types that operate on fixed-point values use types from the `fixed` crate. They are available when
+//! the `fixed` feature is enabled, which it is by default. Applications that only use floating-point values can disable
+//! default features to avoid depending on `fixed`.
+//!
+//! The `raw` submodules of [`basic`], [`statistics`], [`support`], [`fast_math`], and [`filter`] provide the same
+//! functions and filter types for Q1.31, Q1.15, and Q1.7 values stored as `i32`, `i16`, and `i8`. They are available
+//! whether or not the `fixed` feature is enabled. The fixed-point functions in those modules forward to them.
+//! [`complex::interleaved::raw`] provides the complex functions for interleaved `i32` and `i16` values. Other
+//! fixed-point functions, such as the matrix, interpolation, transform, and controller functions, are only
+//! available with the `fixed` feature.
+//!
+//! ## Allocation
+//!
+//! This library does not allocate memory. When the `alloc` feature is enabled, some modules also provide convenience
+//! functions that allocate and return a `Vec` of results, for applications that have a global allocator.
+//!
+//! ## Uninitialized output buffers
+//!
+//! A few functions have `_uninit` variants that write their results into a slice of `MaybeUninit` values and
+//! return the initialized slice, which avoids the cost of initializing large output buffers first. These are the
+//! single-precision absolute value, add, subtract, multiply, negate, offset, and scale functions in the `basic`
+//! module, `FloatRealFft::run_uninit` and `run_inverse_uninit`, and the `process_uninit` methods of the FIR and biquad
+//! filters. Other functions require initialized output buffers.
+//!
+//! ## Functions implemented in Rust
+//!
+//! This library links version 5.7.0 of CMSIS-DSP. Some functions that are missing from that version, such as the
+//! clip, quaternion, MFCC, and dynamic time warping functions, are implemented in Rust. Where a later version of
+//! CMSIS-DSP has the same function, the Rust implementation follows its behavior.
+//!
+//! Version 5.7.0 also has no half-precision functions. When the `f16` feature is enabled, `transform::FloatFftF16Io`
+//! and `transform::FloatRealFftF16Io` accept half-precision input and output, but they convert the values to single
+//! precision and use the single-precision FFTs.
+//!
+//! ## Complex numbers
+//!
+//! The functions and types that operate on complex values accept any type that implements `complex::ComplexLayout`.
+//! This includes two-element arrays such as `[f32; 2]` and, when the `num-complex` feature is enabled (which it is by
+//! default), the `Complex` type from the `num-complex` crate.
+//!
 //!
 
 extern crate cmsis_dsp_sys_pregenerated as cmsis_dsp_sys;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub mod basic;
 pub mod transform;
 pub mod complex;
+pub mod support;
+pub mod matrix;
+pub mod filter;
+pub mod statistics;
+pub mod fast_math;
+pub mod controller;
+pub mod interpolation;
+pub mod svm;
+pub mod bayes;
+pub mod distance;
+pub mod quaternion;
+pub mod window;
 #[cfg(feature = "libm")]
 mod libm_c;
 #[cfg(all(feature = "micromath", not(feature = "libm")))]
 mod micromath_c;
+#[cfg(test)]
+mod test_cmsis;
 
 use core::convert::TryInto;
-use core::fmt::Debug;
+use core::fmt::{self, Debug};
+use core::mem::MaybeUninit;
 
 /// DSP library errors
 #[derive(Debug)]
@@ -43,10 +102,29 @@ pub enum Error {
     SizeMismatch,
     NanInf,
     Singular,
+    Decomposition,
     TestFailure,
     Unknown,
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Error::Argument => "invalid argument",
+            Error::Length => "invalid length",
+            Error::SizeMismatch => "size mismatch",
+            Error::NanInf => "not-a-number or infinite value",
+            Error::Singular => "singular matrix",
+            Error::Decomposition => "matrix decomposition failed",
+            Error::TestFailure => "test failed",
+            Error::Unknown => "unknown error",
+        };
+        f.write_str(message)
+    }
+}
+
+impl core::error::Error for Error {}
+
 trait StatusCode {
     /// Converts this status code into Ok(()) if this represents success, or an error value
     /// if this represents an error
@@ -72,6 +150,17 @@ impl StatusCode for cmsis_dsp_sys::arm_status::Type {
 /// Result type alias
 pub type Result<T> = ::core::result::Result<T, Error>;
 
+extern "C" {
+    /// The C standard library square root, power, and logarithm functions
+    ///
+    /// Like the CMSIS-DSP functions that use them, these must be provided by the `libm` or
+    /// `micromath` feature or by the application.
+    fn sqrtf(value: f32) -> f32;
+    fn sqrt(value: f64) -> f64;
+    fn powf(base: f32, exponent: f32) -> f32;
+    fn log10f(value: f32) -> f32;
+}
+
 
 
 /// Checks that all elements of the provided lengths value/tuple are equal, and that the length
@@ -89,6 +178,26 @@ fn check_length<L, N>(lengths: L) -> N
         .expect("Length too large for size type")
 }
 
+/// Panics if the lengths of the buffers held by a filter or similar type are no longer valid
+///
+/// Types that hold buffers through `AsRef` or `AsMut` check the lengths when they are created,
+/// but those traits can return slices with different lengths on later calls. The lengths are
+/// checked again with this function before each use of the buffers, so that CMSIS-DSP never
+/// accesses memory outside them.
+fn check_buffers(lengths: Result<()>) {
+    assert!(lengths.is_ok(), "Buffer length changed after creation");
+}
+
+/// Returns an output buffer as a slice of initialized values, after all of its elements have
+/// been written
+///
+/// # Safety
+///
+/// Every element of values must be initialized.
+unsafe fn assume_init<T>(values: &mut [MaybeUninit<T>]) -> &mut [T] {
+    core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut T, values.len())
+}
+
 trait Lengths {
     fn assert_lengths_equal(&self);
     fn length(&self) -> usize;
@@ -0,0 +1,1684 @@
+//! Matrix functions
+//!
+//! Matrices are stored in row-major order. A matrix used as an input is represented by a
+//! `MatrixRef`, which borrows its data. A matrix used as an output is represented by a
+//! `MatrixMut`, which mutably borrows its data.
+//!
+//! The functions in this module check that the dimensions of their arguments are compatible,
+//! and return `Err(Error::SizeMismatch)` if they are not.
+//!
+//! For matrices with dimensions known at compile time, the `Matrix` type owns its data and
+//! checks dimension compatibility at compile time.
+
+use core::ops::{Index, IndexMut};
+use core::slice;
+
+use crate::complex::ComplexLayout;
+use crate::{Error, Result, StatusCode};
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31};
+
+#[cfg(feature = "kalman")]
+pub mod kalman;
+
+/// A reference to a matrix that can be used as an input
+#[derive(Debug)]
+pub struct MatrixRef<'a, T> {
+    rows: u16,
+    columns: u16,
+    data: &'a [T],
+}
+
+impl<'a, T> MatrixRef<'a, T> {
+    /// Creates a matrix with the provided dimensions, backed by the provided data in
+    /// row-major order
+    ///
+    /// This function returns an error if data.len() is not equal to rows * columns.
+    pub fn new(rows: u16, columns: u16, data: &'a [T]) -> Result<Self> {
+        check_data_length(rows, columns, data.len())?;
+        Ok(MatrixRef {
+            rows,
+            columns,
+            data,
+        })
+    }
+
+    /// Returns the number of rows in this matrix
+    pub fn rows(&self) -> u16 {
+        self.rows
+    }
+
+    /// Returns the number of columns in this matrix
+    pub fn columns(&self) -> u16 {
+        self.columns
+    }
+
+    /// Returns the data in this matrix, in row-major order
+    pub fn data(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// Returns true if this matrix is square
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    /// Returns true if this matrix has the same dimensions as another matrix
+    fn same_size<U>(&self, other: &MatrixRef<'_, U>) -> bool {
+        self.rows == other.rows && self.columns == other.columns
+    }
+
+    /// Returns an iterator over the rows of this matrix
+    fn row_slices(&self) -> impl Iterator<Item = &'a [T]> {
+        // chunks() panics if the chunk size is zero. A matrix with no columns has no elements,
+        // so max(1) produces an iterator with no rows.
+        self.data.chunks(usize::from(self.columns).max(1))
+    }
+
+    /// Returns a pointer to the data, for use in a CMSIS-DSP matrix instance
+    ///
+    /// CMSIS-DSP does not modify source matrices, so the returned pointer must not be used to
+    /// write to the data.
+    fn data_ptr<U>(&self) -> *mut U {
+        self.data.as_ptr() as *mut U
+    }
+}
+
+impl<'a, T> Clone for MatrixRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatrixRef<'a, T> {}
+
+/// A reference to a matrix that can be used as an output
+#[derive(Debug)]
+pub struct MatrixMut<'a, T> {
+    rows: u16,
+    columns: u16,
+    data: &'a mut [T],
+}
+
+impl<'a, T> MatrixMut<'a, T> {
+    /// Creates a matrix with the provided dimensions, backed by the provided data in
+    /// row-major order
+    ///
+    /// This function returns an error if data.len() is not equal to rows * columns.
+    pub fn new(rows: u16, columns: u16, data: &'a mut [T]) -> Result<Self> {
+        check_data_length(rows, columns, data.len())?;
+        Ok(MatrixMut {
+            rows,
+            columns,
+            data,
+        })
+    }
+
+    /// Returns the number of rows in this matrix
+    pub fn rows(&self) -> u16 {
+        self.rows
+    }
+
+    /// Returns the number of columns in this matrix
+    pub fn columns(&self) -> u16 {
+        self.columns
+    }
+
+    /// Returns the data in this matrix, in row-major order
+    pub fn data(&self) -> &[T] {
+        self.data
+    }
+
+    /// Returns the data in this matrix, in row-major order
+    pub fn data_mut(&mut self) -> &mut [T] {
+        self.data
+    }
+
+    /// Returns a reference to this matrix that can be used as an input
+    pub fn as_ref(&self) -> MatrixRef<'_, T> {
+        MatrixRef {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data,
+        }
+    }
+
+    /// Returns true if this matrix has the same dimensions as another matrix
+    fn same_size<U>(&self, other: &MatrixRef<'_, U>) -> bool {
+        self.rows == other.rows && self.columns == other.columns
+    }
+
+    /// Returns a pointer to the data, for use in a CMSIS-DSP matrix instance
+    fn data_ptr<U>(&mut self) -> *mut U {
+        self.data.as_mut_ptr() as *mut U
+    }
+}
+
+/// Implements functions that create CMSIS-DSP matrix instances
+macro_rules! matrix_instance {
+    ($element:ty, $instance:ident, $fn_name:ident) => {
+        impl<'a> MatrixRef<'a, $element> {
+            // Some element types are only used for destination matrices
+            #[allow(dead_code)]
+            fn $fn_name(&self) -> cmsis_dsp_sys::$instance {
+                cmsis_dsp_sys::$instance {
+                    numRows: self.rows,
+                    numCols: self.columns,
+                    pData: self.data_ptr(),
+                }
+            }
+        }
+
+        impl<'a> MatrixMut<'a, $element> {
+            fn $fn_name(&mut self) -> cmsis_dsp_sys::$instance {
+                cmsis_dsp_sys::$instance {
+                    numRows: self.rows,
+                    numCols: self.columns,
+                    pData: self.data_ptr(),
+                }
+            }
+        }
+    };
+}
+
+matrix_instance!(f32, arm_matrix_instance_f32, instance_f32);
+matrix_instance!(f64, arm_matrix_instance_f64, instance_f64);
+#[cfg(feature = "fixed")]
+matrix_instance!(I1F15, arm_matrix_instance_q15, instance_q15);
+#[cfg(feature = "fixed")]
+matrix_instance!(I1F31, arm_matrix_instance_q31, instance_q31);
+
+/// Implements functions that create CMSIS-DSP matrix instances for complex matrices
+///
+/// Every ComplexLayout type has the same layout as two scalars, which is what the CMSIS-DSP
+/// complex matrix functions expect.
+macro_rules! complex_matrix_instance {
+    ($scalar:ty, $instance:ident, $fn_name:ident) => {
+        impl<'a, C: ComplexLayout<Scalar = $scalar>> MatrixRef<'a, C> {
+            fn $fn_name(&self) -> cmsis_dsp_sys::$instance {
+                cmsis_dsp_sys::$instance {
+                    numRows: self.rows,
+                    numCols: self.columns,
+                    pData: self.data_ptr(),
+                }
+            }
+        }
+
+        impl<'a, C: ComplexLayout<Scalar = $scalar>> MatrixMut<'a, C> {
+            fn $fn_name(&mut self) -> cmsis_dsp_sys::$instance {
+                cmsis_dsp_sys::$instance {
+                    numRows: self.rows,
+                    numCols: self.columns,
+                    pData: self.data_ptr(),
+                }
+            }
+        }
+    };
+}
+
+complex_matrix_instance!(f32, arm_matrix_instance_f32, complex_instance_f32);
+#[cfg(feature = "fixed")]
+complex_matrix_instance!(I1F15, arm_matrix_instance_q15, complex_instance_q15);
+#[cfg(feature = "fixed")]
+complex_matrix_instance!(I1F31, arm_matrix_instance_q31, complex_instance_q31);
+
+/// Adds two matrices
+///
+/// This is functionally equivalent to performing `dst[i][j] = src1[i][j] + src2[i][j]` for all
+/// values of i and j in range.
+///
+/// This function returns an error if src1, src2, and dst do not all have the same dimensions.
+pub fn add_f32(
+    src1: &MatrixRef<'_, f32>,
+    src2: &MatrixRef<'_, f32>,
+    dst: &mut MatrixMut<'_, f32>,
+) -> Result<()> {
+    check_dimensions(src1.same_size(src2) && dst.same_size(src1))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_add_f32(
+            &src1.instance_f32(),
+            &src2.instance_f32(),
+            &mut dst.instance_f32(),
+        )
+        .check_status()
+    }
+}
+
+/// Subtracts one matrix from another
+///
+/// This is functionally equivalent to performing `dst[i][j] = src1[i][j] - src2[i][j]` for all
+/// values of i and j in range.
+///
+/// This function returns an error if src1, src2, and dst do not all have the same dimensions.
+pub fn subtract_f32(
+    src1: &MatrixRef<'_, f32>,
+    src2: &MatrixRef<'_, f32>,
+    dst: &mut MatrixMut<'_, f32>,
+) -> Result<()> {
+    check_dimensions(src1.same_size(src2) && dst.same_size(src1))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_sub_f32(
+            &src1.instance_f32(),
+            &src2.instance_f32(),
+            &mut dst.instance_f32(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies two matrices
+///
+/// This calculates the matrix product `dst = src1 * src2`.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, or if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2.
+pub fn multiply_f32(
+    src1: &MatrixRef<'_, f32>,
+    src2: &MatrixRef<'_, f32>,
+    dst: &mut MatrixMut<'_, f32>,
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_mult_f32(
+            &src1.instance_f32(),
+            &src2.instance_f32(),
+            &mut dst.instance_f32(),
+        )
+        .check_status()
+    }
+}
+
+/// Transposes a matrix
+///
+/// This is functionally equivalent to performing `dst[j][i] = src[i][j]` for all values of i and
+/// j in range.
+///
+/// This function returns an error if the number of rows in dst is not equal to the number of
+/// columns in src, or the number of columns in dst is not equal to the number of rows in src.
+pub fn transpose_f32(src: &MatrixRef<'_, f32>, dst: &mut MatrixMut<'_, f32>) -> Result<()> {
+    check_transpose_dimensions(src, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_trans_f32(&src.instance_f32(), &mut dst.instance_f32())
+            .check_status()
+    }
+}
+
+/// Multiplies each element of a matrix by a scale factor
+///
+/// This is functionally equivalent to performing `dst[i][j] = src[i][j] * scale` for all values
+/// of i and j in range.
+///
+/// This function returns an error if src and dst do not have the same dimensions.
+pub fn scale_f32(src: &MatrixRef<'_, f32>, scale: f32, dst: &mut MatrixMut<'_, f32>) -> Result<()> {
+    check_dimensions(dst.same_size(src))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_scale_f32(&src.instance_f32(), scale, &mut dst.instance_f32())
+            .check_status()
+    }
+}
+
+/// Adds two matrices
+///
+/// This is similar to performing `dst[i][j] = src1[i][j] + src2[i][j]` for all values of i
+/// and j in range. This function saturates on overflow.
+///
+/// This function returns an error if src1, src2, and dst do not all have the same dimensions.
+#[cfg(feature = "fixed")]
+pub fn add_q31(
+    src1: &MatrixRef<'_, I1F31>,
+    src2: &MatrixRef<'_, I1F31>,
+    dst: &mut MatrixMut<'_, I1F31>,
+) -> Result<()> {
+    check_dimensions(src1.same_size(src2) && dst.same_size(src1))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_add_q31(
+            &src1.instance_q31(),
+            &src2.instance_q31(),
+            &mut dst.instance_q31(),
+        )
+        .check_status()
+    }
+}
+
+/// Subtracts one matrix from another
+///
+/// This is similar to performing `dst[i][j] = src1[i][j] - src2[i][j]` for all values of i
+/// and j in range. This function saturates on overflow.
+///
+/// This function returns an error if src1, src2, and dst do not all have the same dimensions.
+#[cfg(feature = "fixed")]
+pub fn subtract_q31(
+    src1: &MatrixRef<'_, I1F31>,
+    src2: &MatrixRef<'_, I1F31>,
+    dst: &mut MatrixMut<'_, I1F31>,
+) -> Result<()> {
+    check_dimensions(src1.same_size(src2) && dst.same_size(src1))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_sub_q31(
+            &src1.instance_q31(),
+            &src2.instance_q31(),
+            &mut dst.instance_q31(),
+        )
+        .check_status()
+    }
+}
+
+/// Adds two matrices
+///
+/// This is similar to performing `dst[i][j] = src1[i][j] + src2[i][j]` for all values of i
+/// and j in range. This function saturates on overflow.
+///
+/// This function returns an error if src1, src2, and dst do not all have the same dimensions.
+#[cfg(feature = "fixed")]
+pub fn add_q15(
+    src1: &MatrixRef<'_, I1F15>,
+    src2: &MatrixRef<'_, I1F15>,
+    dst: &mut MatrixMut<'_, I1F15>,
+) -> Result<()> {
+    check_dimensions(src1.same_size(src2) && dst.same_size(src1))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_add_q15(
+            &src1.instance_q15(),
+            &src2.instance_q15(),
+            &mut dst.instance_q15(),
+        )
+        .check_status()
+    }
+}
+
+/// Subtracts one matrix from another
+///
+/// This is similar to performing `dst[i][j] = src1[i][j] - src2[i][j]` for all values of i
+/// and j in range. This function saturates on overflow.
+///
+/// This function returns an error if src1, src2, and dst do not all have the same dimensions.
+#[cfg(feature = "fixed")]
+pub fn subtract_q15(
+    src1: &MatrixRef<'_, I1F15>,
+    src2: &MatrixRef<'_, I1F15>,
+    dst: &mut MatrixMut<'_, I1F15>,
+) -> Result<()> {
+    check_dimensions(src1.same_size(src2) && dst.same_size(src1))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_sub_q15(
+            &src1.instance_q15(),
+            &src2.instance_q15(),
+            &mut dst.instance_q15(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies two matrices
+///
+/// This calculates the matrix product `dst = src1 * src2`. This function uses a 64-bit internal
+/// accumulator in Q2.62 format. Each result is the accumulator shifted right by 31 bits and
+/// truncated to 32 bits, so the results wrap around instead of saturating if they are outside the
+/// Q1.31 range. To avoid overflow, the inputs should be scaled down by log2(number of columns in
+/// src1) bits.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, or if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2.
+#[cfg(feature = "fixed")]
+pub fn multiply_q31(
+    src1: &MatrixRef<'_, I1F31>,
+    src2: &MatrixRef<'_, I1F31>,
+    dst: &mut MatrixMut<'_, I1F31>,
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_mult_q31(
+            &src1.instance_q31(),
+            &src2.instance_q31(),
+            &mut dst.instance_q31(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies two matrices using a faster algorithm with less precision
+///
+/// This calculates the matrix product `dst = src1 * src2`. This function uses a 32-bit internal
+/// accumulator, so the inputs should be scaled down by log2(number of columns in src1) bits to
+/// avoid overflow.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, or if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2.
+#[cfg(feature = "fixed")]
+pub fn multiply_fast_q31(
+    src1: &MatrixRef<'_, I1F31>,
+    src2: &MatrixRef<'_, I1F31>,
+    dst: &mut MatrixMut<'_, I1F31>,
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_mult_fast_q31(
+            &src1.instance_q31(),
+            &src2.instance_q31(),
+            &mut dst.instance_q31(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies two matrices
+///
+/// This calculates the matrix product `dst = src1 * src2`. This function uses a 64-bit internal
+/// accumulator and saturates the results.
+///
+/// state is used as temporary storage. It must have at least as many elements as src2.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2, or if state is too small.
+#[cfg(feature = "fixed")]
+pub fn multiply_q15(
+    src1: &MatrixRef<'_, I1F15>,
+    src2: &MatrixRef<'_, I1F15>,
+    dst: &mut MatrixMut<'_, I1F15>,
+    state: &mut [I1F15],
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    check_state_length(state.len(), src2.data.len())?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_mult_q15(
+            &src1.instance_q15(),
+            &src2.instance_q15(),
+            &mut dst.instance_q15(),
+            state.as_mut_ptr() as *mut _,
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies two matrices using a faster algorithm with less precision
+///
+/// This calculates the matrix product `dst = src1 * src2`. This function uses a 32-bit internal
+/// accumulator, so the inputs should be scaled down by log2(number of columns in src1) bits to
+/// avoid overflow.
+///
+/// state is used as temporary storage. It must have at least as many elements as src2.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2, or if state is too small.
+#[cfg(feature = "fixed")]
+pub fn multiply_fast_q15(
+    src1: &MatrixRef<'_, I1F15>,
+    src2: &MatrixRef<'_, I1F15>,
+    dst: &mut MatrixMut<'_, I1F15>,
+    state: &mut [I1F15],
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    check_state_length(state.len(), src2.data.len())?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_mult_fast_q15(
+            &src1.instance_q15(),
+            &src2.instance_q15(),
+            &mut dst.instance_q15(),
+            state.as_mut_ptr() as *mut _,
+        )
+        .check_status()
+    }
+}
+
+/// Transposes a matrix
+///
+/// This is functionally equivalent to performing `dst[j][i] = src[i][j]` for all values of i and
+/// j in range.
+///
+/// This function returns an error if the number of rows in dst is not equal to the number of
+/// columns in src, or the number of columns in dst is not equal to the number of rows in src.
+#[cfg(feature = "fixed")]
+pub fn transpose_q31(src: &MatrixRef<'_, I1F31>, dst: &mut MatrixMut<'_, I1F31>) -> Result<()> {
+    check_transpose_dimensions(src, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_trans_q31(&src.instance_q31(), &mut dst.instance_q31())
+            .check_status()
+    }
+}
+
+/// Transposes a matrix
+///
+/// This is functionally equivalent to performing `dst[j][i] = src[i][j]` for all values of i and
+/// j in range.
+///
+/// This function returns an error if the number of rows in dst is not equal to the number of
+/// columns in src, or the number of columns in dst is not equal to the number of rows in src.
+#[cfg(feature = "fixed")]
+pub fn transpose_q15(src: &MatrixRef<'_, I1F15>, dst: &mut MatrixMut<'_, I1F15>) -> Result<()> {
+    check_transpose_dimensions(src, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_trans_q15(&src.instance_q15(), &mut dst.instance_q15())
+            .check_status()
+    }
+}
+
+/// Multiplies each element of a matrix by a scale factor
+///
+/// The scale factor is `scale_fract * 2^shift`. This is similar to performing
+/// `dst[i][j] = src[i][j] * scale_fract * 2^shift` for all values of i and j in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if src and dst do not have the same dimensions.
+#[cfg(feature = "fixed")]
+pub fn scale_q31(
+    src: &MatrixRef<'_, I1F31>,
+    scale_fract: I1F31,
+    shift: i8,
+    dst: &mut MatrixMut<'_, I1F31>,
+) -> Result<()> {
+    check_dimensions(dst.same_size(src))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_scale_q31(
+            &src.instance_q31(),
+            scale_fract.to_bits(),
+            shift.into(),
+            &mut dst.instance_q31(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies each element of a matrix by a scale factor
+///
+/// The scale factor is `scale_fract * 2^shift`. This is similar to performing
+/// `dst[i][j] = src[i][j] * scale_fract * 2^shift` for all values of i and j in range.
+/// This function saturates on overflow.
+///
+/// This function returns an error if src and dst do not have the same dimensions.
+#[cfg(feature = "fixed")]
+pub fn scale_q15(
+    src: &MatrixRef<'_, I1F15>,
+    scale_fract: I1F15,
+    shift: i8,
+    dst: &mut MatrixMut<'_, I1F15>,
+) -> Result<()> {
+    check_dimensions(dst.same_size(src))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_scale_q15(
+            &src.instance_q15(),
+            scale_fract.to_bits(),
+            shift.into(),
+            &mut dst.instance_q15(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies a matrix by a vector
+///
+/// This calculates the matrix-vector product `dst = matrix * vector`.
+///
+/// This function calculates one dot product for each row of the matrix.
+///
+/// This function returns an error if the length of vector is not equal to the number of columns
+/// in matrix, or the length of dst is not equal to the number of rows in matrix.
+pub fn vector_multiply_f32(
+    matrix: &MatrixRef<'_, f32>,
+    vector: &[f32],
+    dst: &mut [f32],
+) -> Result<()> {
+    check_vector_multiply_dimensions(matrix, vector.len(), dst.len())?;
+    for (row, out) in matrix.row_slices().zip(dst.iter_mut()) {
+        *out = crate::basic::dot_product_f32(row, vector);
+    }
+    Ok(())
+}
+
+/// Multiplies a matrix by a vector
+///
+/// This calculates the matrix-vector product `dst = matrix * vector`. Each element of the result
+/// is calculated with a 64-bit accumulator and then saturated.
+///
+/// This function calculates one dot product for each row of the matrix.
+///
+/// This function returns an error if the length of vector is not equal to the number of columns
+/// in matrix, or the length of dst is not equal to the number of rows in matrix.
+#[cfg(feature = "fixed")]
+pub fn vector_multiply_q31(
+    matrix: &MatrixRef<'_, I1F31>,
+    vector: &[I1F31],
+    dst: &mut [I1F31],
+) -> Result<()> {
+    check_vector_multiply_dimensions(matrix, vector.len(), dst.len())?;
+    for (row, out) in matrix.row_slices().zip(dst.iter_mut()) {
+        *out = I1F31::saturating_from_num(crate::basic::dot_product_q31(row, vector));
+    }
+    Ok(())
+}
+
+/// Multiplies a matrix by a vector
+///
+/// This calculates the matrix-vector product `dst = matrix * vector`. Each element of the result
+/// is calculated with a 64-bit accumulator and then saturated.
+///
+/// This function calculates one dot product for each row of the matrix.
+///
+/// This function returns an error if the length of vector is not equal to the number of columns
+/// in matrix, or the length of dst is not equal to the number of rows in matrix.
+#[cfg(feature = "fixed")]
+pub fn vector_multiply_q15(
+    matrix: &MatrixRef<'_, I1F15>,
+    vector: &[I1F15],
+    dst: &mut [I1F15],
+) -> Result<()> {
+    check_vector_multiply_dimensions(matrix, vector.len(), dst.len())?;
+    for (row, out) in matrix.row_slices().zip(dst.iter_mut()) {
+        *out = I1F15::saturating_from_num(crate::basic::dot_product_q15(row, vector));
+    }
+    Ok(())
+}
+
+/// Multiplies two complex matrices
+///
+/// This calculates the matrix product `dst = src1 * src2`.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, or if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2.
+pub fn complex_multiply_f32<C: ComplexLayout<Scalar = f32>>(
+    src1: &MatrixRef<'_, C>,
+    src2: &MatrixRef<'_, C>,
+    dst: &mut MatrixMut<'_, C>,
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_cmplx_mult_f32(
+            &src1.complex_instance_f32(),
+            &src2.complex_instance_f32(),
+            &mut dst.complex_instance_f32(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies two complex matrices
+///
+/// This calculates the matrix product `dst = src1 * src2`. This function uses a 64-bit internal
+/// accumulator and saturates the results.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, or if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_q31<C: ComplexLayout<Scalar = I1F31>>(
+    src1: &MatrixRef<'_, C>,
+    src2: &MatrixRef<'_, C>,
+    dst: &mut MatrixMut<'_, C>,
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_cmplx_mult_q31(
+            &src1.complex_instance_q31(),
+            &src2.complex_instance_q31(),
+            &mut dst.complex_instance_q31(),
+        )
+        .check_status()
+    }
+}
+
+/// Multiplies two complex matrices
+///
+/// This calculates the matrix product `dst = src1 * src2`. This function uses a 64-bit internal
+/// accumulator and saturates the results.
+///
+/// scratch is used as temporary storage. It must have at least as many elements as src2.
+///
+/// This function returns an error if the number of columns in src1 is not equal to the number of
+/// rows in src2, if dst does not have the same number of rows as src1 and the same number of
+/// columns as src2, or if scratch is too small.
+#[cfg(feature = "fixed")]
+pub fn complex_multiply_q15<C: ComplexLayout<Scalar = I1F15>>(
+    src1: &MatrixRef<'_, C>,
+    src2: &MatrixRef<'_, C>,
+    dst: &mut MatrixMut<'_, C>,
+    scratch: &mut [C],
+) -> Result<()> {
+    check_multiply_dimensions(src1, src2, dst)?;
+    check_state_length(scratch.len(), src2.data.len())?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_cmplx_mult_q15(
+            &src1.complex_instance_q15(),
+            &src2.complex_instance_q15(),
+            &mut dst.complex_instance_q15(),
+            scratch.as_mut_ptr() as *mut _,
+        )
+        .check_status()
+    }
+}
+
+/// Transposes a complex matrix
+///
+/// This is functionally equivalent to performing `dst[j][i] = src[i][j]` for all values of i and
+/// j in range. The elements are not conjugated.
+///
+/// This function returns an error if the number of rows in dst is not equal to the number of
+/// columns in src, or the number of columns in dst is not equal to the number of rows in src.
+pub fn complex_transpose_f32<C: ComplexLayout<Scalar = f32>>(
+    src: &MatrixRef<'_, C>,
+    dst: &mut MatrixMut<'_, C>,
+) -> Result<()> {
+    check_transpose_dimensions(src, dst)?;
+    transpose_with(src, dst, |value| value);
+    Ok(())
+}
+
+/// Calculates the conjugate transpose (Hermitian transpose) of a complex matrix
+///
+/// This is functionally equivalent to performing `dst[j][i] = conj(src[i][j])` for all values of
+/// i and j in range.
+///
+/// This function returns an error if the number of rows in dst is not equal to the number of
+/// columns in src, or the number of columns in dst is not equal to the number of rows in src.
+pub fn complex_conjugate_transpose_f32<C: ComplexLayout<Scalar = f32>>(
+    src: &MatrixRef<'_, C>,
+    dst: &mut MatrixMut<'_, C>,
+) -> Result<()> {
+    check_transpose_dimensions(src, dst)?;
+    transpose_with(src, dst, |value| C::from_parts(value.re(), -value.im()));
+    Ok(())
+}
+
+/// Calculates the inverse of a matrix
+///
+/// The inverse is calculated using Gauss-Jordan elimination, which uses src as working memory.
+/// The contents of src are undefined after this function returns.
+///
+/// This function returns an error if src is not square or dst does not have the same dimensions
+/// as src. If src is singular, this function returns `Err(Error::Singular)`.
+pub fn inverse_f32(src: &mut MatrixMut<'_, f32>, dst: &mut MatrixMut<'_, f32>) -> Result<()> {
+    check_dimensions(src.rows == src.columns && dst.same_size(&src.as_ref()))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_inverse_f32(&src.instance_f32(), &mut dst.instance_f32())
+            .check_status()
+    }
+}
+
+/// Calculates the inverse of a matrix
+///
+/// The inverse is calculated using Gauss-Jordan elimination, which uses src as working memory.
+/// The contents of src are undefined after this function returns.
+///
+/// This function returns an error if src is not square or dst does not have the same dimensions
+/// as src. If src is singular, this function returns `Err(Error::Singular)`.
+pub fn inverse_f64(src: &mut MatrixMut<'_, f64>, dst: &mut MatrixMut<'_, f64>) -> Result<()> {
+    check_dimensions(src.rows == src.columns && dst.same_size(&src.as_ref()))?;
+    unsafe {
+        cmsis_dsp_sys::arm_mat_inverse_f64(&src.instance_f64(), &mut dst.instance_f64())
+            .check_status()
+    }
+}
+
+/// Calculates the Cholesky decomposition of a symmetric positive definite matrix
+///
+/// This calculates a lower triangular matrix dst such that `src = dst * transpose(dst)`.
+/// Only the lower triangle of src is read. The upper triangle of dst is set to zero.
+///
+/// The decomposition uses the CMSIS-DSP dot product.
+///
+/// This function returns an error if src is not square or dst does not have the same dimensions
+/// as src. If src is not positive definite, this function returns `Err(Error::Decomposition)`.
+pub fn cholesky_f32(src: &MatrixRef<'_, f32>, dst: &mut MatrixMut<'_, f32>) -> Result<()> {
+    check_dimensions(src.is_square() && dst.same_size(src))?;
+    let n = usize::from(src.rows);
+    let l = &mut *dst.data;
+    for i in 0..n {
+        for j in 0..=i {
+            // Row i and row j of the lower triangle, up to (not including) column j
+            let row_i = &l[i * n..i * n + j];
+            let row_j = &l[j * n..j * n + j];
+            let sum = src.data[i * n + j] - crate::basic::dot_product_f32(row_i, row_j);
+            if i == j {
+                if sum <= 0.0 || sum.is_nan() {
+                    return Err(Error::Decomposition);
+                }
+                l[i * n + i] = unsafe { crate::sqrtf(sum) };
+            } else {
+                l[i * n + j] = sum / l[j * n + j];
+            }
+        }
+        for value in &mut l[i * n + i + 1..(i + 1) * n] {
+            *value = 0.0;
+        }
+    }
+    Ok(())
+}
+
+/// Calculates the LDLᵀ decomposition of a symmetric matrix
+///
+/// This calculates a lower triangular matrix lower with ones on its diagonal and a diagonal
+/// matrix D, with diagonal elements diagonal, such that `src = lower * D * transpose(lower)`.
+/// Only the lower triangle of src is read. The upper triangle of lower is set to zero.
+///
+/// Unlike the Cholesky decomposition, this does not require src to be positive definite and
+/// does not calculate square roots. Rows are not exchanged (there is no pivoting), so the
+/// decomposition can be inaccurate if src is not positive definite and an element of D is small.
+///
+/// This function returns an error if src is not square, lower does not have the same dimensions
+/// as src, or the length of diagonal is not equal to the number of rows in src. If an element
+/// of D is zero, this function returns `Err(Error::Decomposition)`.
+pub fn ldlt_f32(
+    src: &MatrixRef<'_, f32>,
+    lower: &mut MatrixMut<'_, f32>,
+    diagonal: &mut [f32],
+) -> Result<()> {
+    let n = usize::from(src.rows);
+    check_dimensions(src.is_square() && lower.same_size(src) && diagonal.len() == n)?;
+    let l = &mut *lower.data;
+    for j in 0..n {
+        let mut d = src.data[j * n + j];
+        for k in 0..j {
+            d -= l[j * n + k] * l[j * n + k] * diagonal[k];
+        }
+        if d == 0.0 || d.is_nan() {
+            return Err(Error::Decomposition);
+        }
+        diagonal[j] = d;
+        for i in j + 1..n {
+            let mut sum = src.data[i * n + j];
+            for k in 0..j {
+                sum -= l[i * n + k] * l[j * n + k] * diagonal[k];
+            }
+            l[i * n + j] = sum / d;
+        }
+        l[j * n + j] = 1.0;
+        for value in &mut l[j * n + j + 1..(j + 1) * n] {
+            *value = 0.0;
+        }
+    }
+    Ok(())
+}
+
+/// Solves a lower triangular system of equations
+///
+/// This calculates the matrix dst such that `lower * dst = src`, using forward substitution.
+/// Only the lower triangle of lower is read.
+///
+/// This function returns an error if lower is not square, src does not have the same number of
+/// rows as lower, or dst does not have the same dimensions as src. If any diagonal element
+/// of lower is zero, this function returns `Err(Error::Singular)`.
+pub fn solve_lower_triangular_f32(
+    lower: &MatrixRef<'_, f32>,
+    src: &MatrixRef<'_, f32>,
+    dst: &mut MatrixMut<'_, f32>,
+) -> Result<()> {
+    check_dimensions(lower.is_square() && src.rows == lower.rows && dst.same_size(src))?;
+    let n = usize::from(src.rows);
+    let columns = usize::from(src.columns);
+    for column in 0..columns {
+        for i in 0..n {
+            let diagonal = lower.data[i * n + i];
+            if diagonal == 0.0 {
+                return Err(Error::Singular);
+            }
+            let mut sum = src.data[i * columns + column];
+            for k in 0..i {
+                sum -= lower.data[i * n + k] * dst.data[k * columns + column];
+            }
+            dst.data[i * columns + column] = sum / diagonal;
+        }
+    }
+    Ok(())
+}
+
+/// Solves an upper triangular system of equations
+///
+/// This calculates the matrix dst such that `upper * dst = src`, using back substitution.
+/// Only the upper triangle of upper is read.
+///
+/// This function returns an error if upper is not square, src does not have the same number of
+/// rows as upper, or dst does not have the same dimensions as src. If any diagonal element
+/// of upper is zero, this function returns `Err(Error::Singular)`.
+pub fn solve_upper_triangular_f32(
+    upper: &MatrixRef<'_, f32>,
+    src: &MatrixRef<'_, f32>,
+    dst: &mut MatrixMut<'_, f32>,
+) -> Result<()> {
+    check_dimensions(upper.is_square() && src.rows == upper.rows && dst.same_size(src))?;
+    let n = usize::from(src.rows);
+    let columns = usize::from(src.columns);
+    for column in 0..columns {
+        for i in (0..n).rev() {
+            let diagonal = upper.data[i * n + i];
+            if diagonal == 0.0 {
+                return Err(Error::Singular);
+            }
+            let mut sum = src.data[i * columns + column];
+            for k in i + 1..n {
+                sum -= upper.data[i * n + k] * dst.data[k * columns + column];
+            }
+            dst.data[i * columns + column] = sum / diagonal;
+        }
+    }
+    Ok(())
+}
+
+/// Implements Householder reflection and QR decomposition functions for a floating-point type
+macro_rules! householder_qr {
+    ($element:ty, $sqrt:path, $householder_name:ident, $qr_name:ident) => {
+        /// Calculates a Householder reflection that maps a vector onto the first basis vector
+        ///
+        /// This calculates a vector v (stored in dst, with `v[0] == 1`) and returns a scalar beta
+        /// such that `(I - beta * v * transpose(v)) * src` is zero everywhere except in the first
+        /// element.
+        ///
+        /// If the squared norm of all elements of src except the first is less than or equal to
+        /// threshold, the reflection is the identity: dst is set to the first basis vector and the
+        /// returned beta is zero.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src and dst do not have the same length, or if they are empty.
+        pub fn $householder_name(
+            src: &[$element],
+            threshold: $element,
+            dst: &mut [$element],
+        ) -> $element {
+            assert_eq!(src.len(), dst.len());
+            assert!(!src.is_empty());
+            let alpha = src[0];
+            let tail_norm2: $element = src[1..].iter().map(|value| value * value).sum();
+            dst[0] = 1.0;
+            if tail_norm2 <= threshold {
+                for value in &mut dst[1..] {
+                    *value = 0.0;
+                }
+                return 0.0;
+            }
+            let mut beta = unsafe { $sqrt(alpha * alpha + tail_norm2) };
+            if alpha > 0.0 {
+                beta = -beta;
+            }
+            let scale = 1.0 / (alpha - beta);
+            for (out, value) in dst[1..].iter_mut().zip(&src[1..]) {
+                *out = value * scale;
+            }
+            (beta - alpha) / beta
+        }
+
+        /// Calculates the QR decomposition of a matrix using Householder reflections
+        ///
+        /// src must have at least as many rows as columns. This calculates an orthogonal matrix q
+        /// and an upper triangular matrix r such that `src = q * r`.
+        ///
+        /// The Householder scaling factors (one for each column of src) are stored in tau. Columns
+        /// with a squared norm below the diagonal less than or equal to threshold are not
+        /// reflected.
+        ///
+        /// No temporary buffers are required.
+        ///
+        /// This function returns an error if src has more columns than rows, r does not have the
+        /// same dimensions as src, q is not square with the same number of rows as src, or the
+        /// length of tau is not equal to the number of columns in src.
+        pub fn $qr_name(
+            src: &MatrixRef<'_, $element>,
+            threshold: $element,
+            r: &mut MatrixMut<'_, $element>,
+            q: &mut MatrixMut<'_, $element>,
+            tau: &mut [$element],
+        ) -> Result<()> {
+            check_dimensions(
+                src.rows >= src.columns
+                    && r.same_size(src)
+                    && q.rows == src.rows
+                    && q.columns == src.rows
+                    && tau.len() == usize::from(src.columns),
+            )?;
+            let m = usize::from(src.rows);
+            let n = usize::from(src.columns);
+            let r = &mut *r.data;
+            let q = &mut *q.data;
+            r.copy_from_slice(src.data);
+
+            for k in 0..n {
+                // Calculate the reflection for column k, below and including the diagonal
+                let alpha = r[k * n + k];
+                let tail_norm2: $element = (k + 1..m).map(|i| r[i * n + k] * r[i * n + k]).sum();
+                if tail_norm2 <= threshold {
+                    tau[k] = 0.0;
+                    for i in k + 1..m {
+                        r[i * n + k] = 0.0;
+                    }
+                    continue;
+                }
+                let mut beta = unsafe { $sqrt(alpha * alpha + tail_norm2) };
+                if alpha > 0.0 {
+                    beta = -beta;
+                }
+                let scale = 1.0 / (alpha - beta);
+                // Store the reflection vector (except its first element, which is 1)
+                // below the diagonal
+                for i in k + 1..m {
+                    r[i * n + k] *= scale;
+                }
+                let beta_k = (beta - alpha) / beta;
+                tau[k] = beta_k;
+                // The reflection maps column k onto the first basis vector
+                r[k * n + k] = beta;
+                // Apply the reflection to the remaining columns
+                for j in k + 1..n {
+                    let mut w = r[k * n + j];
+                    for i in k + 1..m {
+                        w += r[i * n + k] * r[i * n + j];
+                    }
+                    w *= beta_k;
+                    r[k * n + j] -= w;
+                    for i in k + 1..m {
+                        r[i * n + j] -= w * r[i * n + k];
+                    }
+                }
+            }
+
+            // Accumulate the reflections into q, starting from the identity
+            for (index, value) in q.iter_mut().enumerate() {
+                *value = if index / m == index % m { 1.0 } else { 0.0 };
+            }
+            for k in (0..n).rev() {
+                let beta_k = tau[k];
+                if beta_k == 0.0 {
+                    continue;
+                }
+                for j in k..m {
+                    let mut w = q[k * m + j];
+                    for i in k + 1..m {
+                        w += r[i * n + k] * q[i * m + j];
+                    }
+                    w *= beta_k;
+                    q[k * m + j] -= w;
+                    for i in k + 1..m {
+                        q[i * m + j] -= w * r[i * n + k];
+                    }
+                }
+            }
+
+            // Clear the reflection vectors from below the diagonal of r
+            for k in 0..n {
+                for i in k + 1..m {
+                    r[i * n + k] = 0.0;
+                }
+            }
+            Ok(())
+        }
+    };
+}
+
+householder_qr!(f32, crate::sqrtf, householder_f32, qr_f32);
+householder_qr!(f64, crate::sqrt, householder_f64, qr_f64);
+
+/// A matrix with dimensions known at compile time, which owns its data
+///
+/// The operations on this type check dimension compatibility at compile time, so they do not
+/// return errors or panic. Both dimensions must be less than or equal to `u16::MAX`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    /// The elements, in row-major order
+    data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Causes a compile-time error if the dimensions do not fit into the CMSIS-DSP matrix
+    /// dimension type
+    const DIMENSIONS_VALID: () = assert!(
+        R <= u16::MAX as usize && C <= u16::MAX as usize,
+        "Matrix dimensions too large"
+    );
+
+    /// Creates a matrix from an array of rows
+    pub const fn from_rows(rows: [[T; C]; R]) -> Self {
+        Matrix { data: rows }
+    }
+
+    /// Returns the rows of this matrix
+    pub fn row_slices(&self) -> &[[T; C]; R] {
+        &self.data
+    }
+
+    /// Returns the rows of this matrix
+    pub fn row_slices_mut(&mut self) -> &mut [[T; C]; R] {
+        &mut self.data
+    }
+
+    /// Returns the elements of this matrix in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        // [[T; C]; R] has the same layout as [T; R * C]
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, R * C) }
+    }
+
+    /// Returns the elements of this matrix in row-major order
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, R * C) }
+    }
+
+    /// Returns a reference to this matrix that can be used with the functions in this module
+    pub fn as_matrix_ref(&self) -> MatrixRef<'_, T> {
+        let () = Self::DIMENSIONS_VALID;
+        MatrixRef {
+            rows: R as u16,
+            columns: C as u16,
+            data: self.as_slice(),
+        }
+    }
+
+    /// Returns a mutable reference to this matrix that can be used with the functions in this
+    /// module
+    pub fn as_matrix_mut(&mut self) -> MatrixMut<'_, T> {
+        let () = Self::DIMENSIONS_VALID;
+        MatrixMut {
+            rows: R as u16,
+            columns: C as u16,
+            data: self.as_mut_slice(),
+        }
+    }
+}
+
+impl<const R: usize, const C: usize> Matrix<f32, R, C> {
+    /// Returns a matrix with all elements set to zero
+    pub const fn zeros() -> Self {
+        Matrix {
+            data: [[0.0; C]; R],
+        }
+    }
+
+    /// Adds this matrix and another matrix, and returns the sum
+    pub fn add(&self, other: &Self) -> Self {
+        let mut result = Self::zeros();
+        unsafe {
+            cmsis_dsp_sys::arm_mat_add_f32(
+                &self.as_matrix_ref().instance_f32(),
+                &other.as_matrix_ref().instance_f32(),
+                &mut result.as_matrix_mut().instance_f32(),
+            );
+        }
+        result
+    }
+
+    /// Subtracts another matrix from this matrix, and returns the difference
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut result = Self::zeros();
+        unsafe {
+            cmsis_dsp_sys::arm_mat_sub_f32(
+                &self.as_matrix_ref().instance_f32(),
+                &other.as_matrix_ref().instance_f32(),
+                &mut result.as_matrix_mut().instance_f32(),
+            );
+        }
+        result
+    }
+
+    /// Multiplies this matrix by another matrix, and returns the product `self * other`
+    pub fn multiply<const K: usize>(&self, other: &Matrix<f32, C, K>) -> Matrix<f32, R, K> {
+        let mut result = Matrix::<f32, R, K>::zeros();
+        unsafe {
+            cmsis_dsp_sys::arm_mat_mult_f32(
+                &self.as_matrix_ref().instance_f32(),
+                &other.as_matrix_ref().instance_f32(),
+                &mut result.as_matrix_mut().instance_f32(),
+            );
+        }
+        result
+    }
+
+    /// Multiplies this matrix by a vector, and returns the product `self * vector`
+    pub fn multiply_vector(&self, vector: &[f32; C]) -> [f32; R] {
+        let mut result = [0.0; R];
+        for (row, out) in self.data.iter().zip(result.iter_mut()) {
+            *out = crate::basic::dot_product_f32(row, vector);
+        }
+        result
+    }
+
+    /// Returns the transpose of this matrix
+    pub fn transpose(&self) -> Matrix<f32, C, R> {
+        let mut result = Matrix::<f32, C, R>::zeros();
+        unsafe {
+            cmsis_dsp_sys::arm_mat_trans_f32(
+                &self.as_matrix_ref().instance_f32(),
+                &mut result.as_matrix_mut().instance_f32(),
+            );
+        }
+        result
+    }
+
+    /// Multiplies each element of this matrix by a scale factor, and returns the result
+    pub fn scale(&self, scale: f32) -> Self {
+        let mut result = Self::zeros();
+        unsafe {
+            cmsis_dsp_sys::arm_mat_scale_f32(
+                &self.as_matrix_ref().instance_f32(),
+                scale,
+                &mut result.as_matrix_mut().instance_f32(),
+            );
+        }
+        result
+    }
+}
+
+impl<const N: usize> Matrix<f32, N, N> {
+    /// Returns an identity matrix
+    pub fn identity() -> Self {
+        let mut result = Self::zeros();
+        for (i, row) in result.data.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        result
+    }
+
+    /// Calculates and returns the inverse of this matrix
+    ///
+    /// If this matrix is singular, this function returns `Err(Error::Singular)`.
+    pub fn inverse(&self) -> Result<Self> {
+        // The inverse function uses its source as working memory
+        let mut working = *self;
+        let mut result = Self::zeros();
+        inverse_f32(&mut working.as_matrix_mut(), &mut result.as_matrix_mut())?;
+        Ok(result)
+    }
+}
+
+impl<const R: usize, const C: usize> Default for Matrix<f32, R, C> {
+    /// Returns a matrix with all elements set to zero
+    fn default() -> Self {
+        Self::zeros()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    /// Returns the element at (row, column)
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        &self.data[row][column]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    /// Returns the element at (row, column)
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        &mut self.data[row][column]
+    }
+}
+
+/// Solves a linear least-squares problem
+///
+/// This calculates the vector x that minimizes the Euclidean norm of `a * x - b`. a must have at
+/// least as many rows as columns, and must have full column rank.
+///
+/// The solution is found by calculating the QR decomposition of a and then solving the upper
+/// triangular system `R * x = transpose(Q) * b`. All temporary storage is allocated on the stack.
+///
+/// This function returns `Err(Error::SizeMismatch)` if a has more columns than rows. a is
+/// considered rank-deficient, and this function returns `Err(Error::Singular)`, if the magnitude
+/// of any diagonal element of R is not greater than `N * f32::EPSILON` times the largest
+/// diagonal magnitude.
+pub fn solve_least_squares<const M: usize, const N: usize>(
+    a: &Matrix<f32, M, N>,
+    b: &[f32; M],
+) -> Result<[f32; N]> {
+    let mut r = Matrix::<f32, M, N>::zeros();
+    let mut q = Matrix::<f32, M, M>::zeros();
+    let mut tau = [0.0; N];
+    qr_f32(
+        &a.as_matrix_ref(),
+        0.0,
+        &mut r.as_matrix_mut(),
+        &mut q.as_matrix_mut(),
+        &mut tau,
+    )?;
+    let q_transpose_b = q.transpose().multiply_vector(b);
+
+    // Diagonal elements of R that are small relative to the largest one indicate that a column
+    // of a is (nearly) a linear combination of the other columns
+    let largest = (0..N).map(|i| r[(i, i)].abs()).fold(0.0, f32::max);
+    let tolerance = largest * N as f32 * f32::EPSILON;
+    if (0..N).any(|i| r[(i, i)].abs() <= tolerance || r[(i, i)].is_nan()) {
+        return Err(Error::Singular);
+    }
+
+    // The top N rows of r contain the upper triangular part
+    let n = N as u16;
+    let mut x = [0.0; N];
+    solve_upper_triangular_f32(
+        &MatrixRef::new(n, n, &r.as_slice()[..N * N])?,
+        &MatrixRef::new(n, 1, &q_transpose_b[..N])?,
+        &mut MatrixMut::new(n, 1, &mut x)?,
+    )?;
+    Ok(x)
+}
+
+/// Transposes a matrix, applying a function to each element
+///
+/// The dimensions of src and dst must already have been checked.
+fn transpose_with<T, F>(src: &MatrixRef<'_, T>, dst: &mut MatrixMut<'_, T>, mut f: F)
+where
+    T: Copy,
+    F: FnMut(T) -> T,
+{
+    let rows = usize::from(src.rows);
+    for (i, row) in src.row_slices().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            dst.data[j * rows + i] = f(*value);
+        }
+    }
+}
+
+/// Checks that a data length matches the provided matrix dimensions
+fn check_data_length(rows: u16, columns: u16, length: usize) -> Result<()> {
+    if usize::from(rows) * usize::from(columns) == length {
+        Ok(())
+    } else {
+        Err(Error::Length)
+    }
+}
+
+/// Checks that a temporary buffer has at least the required length
+#[cfg(feature = "fixed")]
+fn check_state_length(length: usize, required: usize) -> Result<()> {
+    if length >= required {
+        Ok(())
+    } else {
+        Err(Error::Length)
+    }
+}
+
+/// Converts the result of a dimension check into Ok(()) or Err(Error::SizeMismatch)
+fn check_dimensions(compatible: bool) -> Result<()> {
+    if compatible {
+        Ok(())
+    } else {
+        Err(Error::SizeMismatch)
+    }
+}
+
+/// Checks that the dimensions of two source matrices and a destination matrix are compatible
+/// for multiplication
+fn check_multiply_dimensions<T, U, V>(
+    src1: &MatrixRef<'_, T>,
+    src2: &MatrixRef<'_, U>,
+    dst: &MatrixMut<'_, V>,
+) -> Result<()> {
+    check_dimensions(
+        src1.columns == src2.rows && dst.rows == src1.rows && dst.columns == src2.columns,
+    )
+}
+
+/// Checks that the dimensions of a matrix, source vector, and destination vector are compatible
+/// for matrix-vector multiplication
+fn check_vector_multiply_dimensions<T>(
+    matrix: &MatrixRef<'_, T>,
+    vector_length: usize,
+    dst_length: usize,
+) -> Result<()> {
+    check_dimensions(
+        vector_length == usize::from(matrix.columns) && dst_length == usize::from(matrix.rows),
+    )
+}
+
+/// Checks that the dimensions of a source and destination matrix are compatible for
+/// transposition
+fn check_transpose_dimensions<T, U>(src: &MatrixRef<'_, T>, dst: &MatrixMut<'_, U>) -> Result<()> {
+    check_dimensions(dst.rows == src.columns && dst.columns == src.rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A symmetric positive definite matrix
+    const SPD: [f32; 9] = [4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0];
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "micromath", not(feature = "libm")),
+        ignore = "micromath sqrtf is not precise enough"
+    )]
+    fn cholesky_spd() {
+        let mut lower = [f32::NAN; 9];
+        cholesky_f32(
+            &MatrixRef::new(3, 3, &SPD).unwrap(),
+            &mut MatrixMut::new(3, 3, &mut lower).unwrap(),
+        )
+        .unwrap();
+        let expected = [2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0];
+        for (actual, expected) in lower.iter().zip(&expected) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn cholesky_not_positive_definite() {
+        // Eigenvalues 3 and -1
+        let src = [1.0, 2.0, 2.0, 1.0];
+        let mut lower = [0.0; 4];
+        let result = cholesky_f32(
+            &MatrixRef::new(2, 2, &src).unwrap(),
+            &mut MatrixMut::new(2, 2, &mut lower).unwrap(),
+        );
+        assert!(matches!(result, Err(Error::Decomposition)));
+    }
+
+    #[test]
+    fn ldlt_spd() {
+        let mut lower = [f32::NAN; 9];
+        let mut diagonal = [0.0; 3];
+        ldlt_f32(
+            &MatrixRef::new(3, 3, &SPD).unwrap(),
+            &mut MatrixMut::new(3, 3, &mut lower).unwrap(),
+            &mut diagonal,
+        )
+        .unwrap();
+        let expected = [1.0, 0.0, 0.0, 3.0, 1.0, 0.0, -4.0, 5.0, 1.0];
+        for (actual, expected) in lower.iter().zip(&expected) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+        for (actual, expected) in diagonal.iter().zip(&[4.0, 1.0, 9.0]) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn ldlt_indefinite() {
+        let src = [1.0, 2.0, 2.0, 1.0];
+        let mut lower = [0.0; 4];
+        let mut diagonal = [0.0; 2];
+        ldlt_f32(
+            &MatrixRef::new(2, 2, &src).unwrap(),
+            &mut MatrixMut::new(2, 2, &mut lower).unwrap(),
+            &mut diagonal,
+        )
+        .unwrap();
+        assert_eq!(lower, [1.0, 0.0, 2.0, 1.0]);
+        assert_eq!(diagonal, [1.0, -3.0]);
+    }
+
+    #[test]
+    fn ldlt_zero_pivot() {
+        let src = [0.0, 1.0, 1.0, 0.0];
+        let mut lower = [0.0; 4];
+        let mut diagonal = [0.0; 2];
+        let result = ldlt_f32(
+            &MatrixRef::new(2, 2, &src).unwrap(),
+            &mut MatrixMut::new(2, 2, &mut lower).unwrap(),
+            &mut diagonal,
+        );
+        assert!(matches!(result, Err(Error::Decomposition)));
+    }
+
+    #[test]
+    fn triangular_solve_round_trip() {
+        let lower = [2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0];
+        let upper = [2.0, 6.0, -8.0, 0.0, 1.0, 5.0, 0.0, 0.0, 3.0];
+        let x = [1.0, -2.0, 0.5, 3.0, -1.5, 4.0];
+        // b = lower * x and c = upper * x
+        let mut b = [0.0; 6];
+        let mut c = [0.0; 6];
+        for i in 0..3 {
+            for j in 0..2 {
+                b[i * 2 + j] = (0..3).map(|k| lower[i * 3 + k] * x[k * 2 + j]).sum();
+                c[i * 2 + j] = (0..3).map(|k| upper[i * 3 + k] * x[k * 2 + j]).sum();
+            }
+        }
+
+        let mut solution = [0.0; 6];
+        solve_lower_triangular_f32(
+            &MatrixRef::new(3, 3, &lower).unwrap(),
+            &MatrixRef::new(3, 2, &b).unwrap(),
+            &mut MatrixMut::new(3, 2, &mut solution).unwrap(),
+        )
+        .unwrap();
+        for (actual, expected) in solution.iter().zip(&x) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+
+        let mut solution = [0.0; 6];
+        solve_upper_triangular_f32(
+            &MatrixRef::new(3, 3, &upper).unwrap(),
+            &MatrixRef::new(3, 2, &c).unwrap(),
+            &mut MatrixMut::new(3, 2, &mut solution).unwrap(),
+        )
+        .unwrap();
+        for (actual, expected) in solution.iter().zip(&x) {
+            assert!((actual - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "micromath", not(feature = "libm")),
+        ignore = "micromath sqrtf is not precise enough"
+    )]
+    fn least_squares_line_fit() {
+        // y = 2x + 1 at x = 0, 1, 2, 3, with errors orthogonal to both columns
+        let a = Matrix::from_rows([[0.0, 1.0], [1.0, 1.0], [2.0, 1.0], [3.0, 1.0]]);
+        let b = [1.5, 2.5, 4.5, 7.5];
+        let x = solve_least_squares(&a, &b).unwrap();
+        assert!((x[0] - 2.0).abs() < 1e-5);
+        assert!((x[1] - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "micromath", not(feature = "libm")),
+        ignore = "micromath sqrtf is not precise enough"
+    )]
+    fn least_squares_rank_deficient() {
+        // The second column is twice the first, so rounding leaves a tiny nonzero R diagonal
+        let a = Matrix::from_rows([[0.1, 0.2], [0.3, 0.6], [0.7, 1.4]]);
+        let result = solve_least_squares(&a, &[1.0, 2.0, 3.0]);
+        assert!(matches!(result, Err(Error::Singular)));
+    }
+
+    #[test]
+    fn triangular_solve_singular() {
+        let lower = [1.0, 0.0, 2.0, 0.0];
+        let src = [1.0, 1.0];
+        let mut dst = [0.0; 2];
+        let result = solve_lower_triangular_f32(
+            &MatrixRef::new(2, 2, &lower).unwrap(),
+            &MatrixRef::new(2, 1, &src).unwrap(),
+            &mut MatrixMut::new(2, 1, &mut dst).unwrap(),
+        );
+        assert!(matches!(result, Err(Error::Singular)));
+    }
+
+    /// The classic example matrix, whose R factor has the diagonal (14, 175, 35) up to sign
+    const SRC: [f64; 9] = [12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0];
+
+    #[test]
+    fn qr_f64_reconstructs_source() {
+        let mut r = [0.0; 9];
+        let mut q = [0.0; 9];
+        let mut tau = [0.0; 3];
+        qr_f64(
+            &MatrixRef::new(3, 3, &SRC).unwrap(),
+            0.0,
+            &mut MatrixMut::new(3, 3, &mut r).unwrap(),
+            &mut MatrixMut::new(3, 3, &mut q).unwrap(),
+            &mut tau,
+        )
+        .unwrap();
+
+        for (i, expected) in [14.0, 175.0, 35.0].iter().enumerate() {
+            assert!((r[i * 3 + i].abs() - expected).abs() < 1e-9);
+        }
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(r[i * 3 + j], 0.0);
+            }
+        }
+        for i in 0..3 {
+            for j in 0..3 {
+                // q * r == src
+                let product: f64 = (0..3).map(|k| q[i * 3 + k] * r[k * 3 + j]).sum();
+                assert!((product - SRC[i * 3 + j]).abs() < 1e-9);
+                // transpose(q) * q == I
+                let gram: f64 = (0..3).map(|k| q[k * 3 + i] * q[k * 3 + j]).sum();
+                let identity = if i == j { 1.0 } else { 0.0 };
+                assert!((gram - identity).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "micromath", not(feature = "libm")),
+        ignore = "micromath sqrtf is not precise enough"
+    )]
+    fn qr_f32_tall_matrix() {
+        let src = [3.0, 1.0, 4.0, 1.0, 0.0, 5.0];
+        let mut r = [0.0; 6];
+        let mut q = [0.0; 9];
+        let mut tau = [0.0; 2];
+        qr_f32(
+            &MatrixRef::new(3, 2, &src).unwrap(),
+            0.0,
+            &mut MatrixMut::new(3, 2, &mut r).unwrap(),
+            &mut MatrixMut::new(3, 3, &mut q).unwrap(),
+            &mut tau,
+        )
+        .unwrap();
+
+        // The first column has norm 5
+        assert!((r[0].abs() - 5.0).abs() < 1e-5);
+        assert_eq!(r[2], 0.0);
+        assert_eq!(r[4], 0.0);
+        for i in 0..3 {
+            for j in 0..2 {
+                let product: f32 = (0..2).map(|k| q[i * 3 + k] * r[k * 2 + j]).sum();
+                assert!((product - src[i * 2 + j]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn qr_rejects_wide_matrix() {
+        let src = [0.0f32; 6];
+        let mut r = [0.0; 6];
+        let mut q = [0.0; 4];
+        let mut tau = [0.0; 3];
+        let result = qr_f32(
+            &MatrixRef::new(2, 3, &src).unwrap(),
+            0.0,
+            &mut MatrixMut::new(2, 3, &mut r).unwrap(),
+            &mut MatrixMut::new(2, 2, &mut q).unwrap(),
+            &mut tau,
+        );
+        assert!(matches!(result, Err(Error::SizeMismatch)));
+    }
+
+    #[test]
+    fn householder_f64_zeroes_tail() {
+        let src = [3.0, 4.0];
+        let mut v = [0.0; 2];
+        let beta = householder_f64(&src, 0.0, &mut v);
+        assert_eq!(v[0], 1.0);
+        // (I - beta * v * transpose(v)) * src
+        let dot = v[0] * src[0] + v[1] * src[1];
+        let reflected = [src[0] - beta * dot * v[0], src[1] - beta * dot * v[1]];
+        assert!((reflected[0].abs() - 5.0).abs() < 1e-12);
+        assert!(reflected[1].abs() < 1e-12);
+    }
+}
@@ -0,0 +1,189 @@
+//! Kalman filter
+//!
+//! This module is available when the `kalman` feature is enabled.
+
+use super::Matrix;
+use crate::Result;
+
+/// A linear Kalman filter with N state variables and M measured variables
+///
+/// All matrices are stored in the filter, so no heap allocation is required.
+#[derive(Debug, Clone)]
+pub struct KalmanFilter<const N: usize, const M: usize> {
+    /// State estimate
+    state: Matrix<f32, N, 1>,
+    /// State estimate covariance
+    covariance: Matrix<f32, N, N>,
+    /// State transition model
+    transition: Matrix<f32, N, N>,
+    /// Observation model
+    observation: Matrix<f32, M, N>,
+    /// Process noise covariance
+    process_noise: Matrix<f32, N, N>,
+    /// Measurement noise covariance
+    measurement_noise: Matrix<f32, M, M>,
+}
+
+impl<const N: usize, const M: usize> KalmanFilter<N, M> {
+    /// Creates a Kalman filter
+    ///
+    /// Arguments:
+    /// * `transition`: The state transition model, which maps the previous state to the next state
+    /// * `observation`: The observation model, which maps the state to the measured values
+    /// * `process_noise`: The covariance of the process noise
+    /// * `measurement_noise`: The covariance of the measurement noise
+    /// * `initial_state`: The initial state estimate
+    /// * `initial_covariance`: The covariance of the initial state estimate
+    pub fn new(
+        transition: Matrix<f32, N, N>,
+        observation: Matrix<f32, M, N>,
+        process_noise: Matrix<f32, N, N>,
+        measurement_noise: Matrix<f32, M, M>,
+        initial_state: [f32; N],
+        initial_covariance: Matrix<f32, N, N>,
+    ) -> Self {
+        let mut state = Matrix::zeros();
+        state.as_mut_slice().copy_from_slice(&initial_state);
+        KalmanFilter {
+            state,
+            covariance: initial_covariance,
+            transition,
+            observation,
+            process_noise,
+            measurement_noise,
+        }
+    }
+
+    /// Returns the current state estimate
+    pub fn state(&self) -> &[f32] {
+        self.state.as_slice()
+    }
+
+    /// Returns the current state estimate
+    pub fn state_mut(&mut self) -> &mut [f32] {
+        self.state.as_mut_slice()
+    }
+
+    /// Returns the covariance of the current state estimate
+    pub fn covariance(&self) -> &Matrix<f32, N, N> {
+        &self.covariance
+    }
+
+    /// Returns the covariance of the current state estimate
+    pub fn covariance_mut(&mut self) -> &mut Matrix<f32, N, N> {
+        &mut self.covariance
+    }
+
+    /// Predicts the next state with no control input
+    pub fn predict(&mut self) {
+        self.state = self.transition.multiply(&self.state);
+        self.predict_covariance();
+    }
+
+    /// Predicts the next state, taking a control input into account
+    ///
+    /// `control_model` maps the control input to its effect on the state.
+    pub fn predict_with_control<const U: usize>(
+        &mut self,
+        control_model: &Matrix<f32, N, U>,
+        control: &[f32; U],
+    ) {
+        let mut control_vector = Matrix::<f32, U, 1>::zeros();
+        control_vector.as_mut_slice().copy_from_slice(control);
+        self.state = self
+            .transition
+            .multiply(&self.state)
+            .add(&control_model.multiply(&control_vector));
+        self.predict_covariance();
+    }
+
+    /// Updates the state estimate based on a measurement
+    ///
+    /// This function returns `Err(Error::Singular)` if the innovation covariance is singular.
+    /// In that case, the state estimate is not changed.
+    pub fn update(&mut self, measurement: &[f32; M]) -> Result<()> {
+        let mut measurement_vector = Matrix::<f32, M, 1>::zeros();
+        measurement_vector
+            .as_mut_slice()
+            .copy_from_slice(measurement);
+        // Innovation
+        let innovation = measurement_vector.subtract(&self.observation.multiply(&self.state));
+        // Innovation covariance
+        let observation_transpose = self.observation.transpose();
+        let innovation_covariance = self
+            .observation
+            .multiply(&self.covariance)
+            .multiply(&observation_transpose)
+            .add(&self.measurement_noise);
+        // Kalman gain
+        let gain = self
+            .covariance
+            .multiply(&observation_transpose)
+            .multiply(&innovation_covariance.inverse()?);
+
+        self.state = self.state.add(&gain.multiply(&innovation));
+        let identity = Matrix::<f32, N, N>::identity();
+        self.covariance = identity
+            .subtract(&gain.multiply(&self.observation))
+            .multiply(&self.covariance);
+        Ok(())
+    }
+
+    /// Calculates the predicted covariance
+    fn predict_covariance(&mut self) {
+        self.covariance = self
+            .transition
+            .multiply(&self.covariance)
+            .multiply(&self.transition.transpose())
+            .add(&self.process_noise);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Estimates a scalar constant, where each step can be checked by hand
+    #[test]
+    fn scalar_constant() {
+        let mut filter = KalmanFilter::<1, 1>::new(
+            Matrix::from_rows([[1.0]]),
+            Matrix::from_rows([[1.0]]),
+            Matrix::zeros(),
+            Matrix::from_rows([[1.0]]),
+            [0.0],
+            Matrix::from_rows([[1.0]]),
+        );
+
+        filter.predict();
+        assert_eq!(filter.state(), &[0.0]);
+        assert_eq!(filter.covariance()[(0, 0)], 1.0);
+
+        // Gain P / (P + R) = 1 / (1 + 1) = 0.5
+        filter.update(&[2.0]).unwrap();
+        assert_eq!(filter.state(), &[1.0]);
+        // Covariance (1 - K) * P = 0.5
+        assert_eq!(filter.covariance()[(0, 0)], 0.5);
+
+        // Gain 0.5 / (0.5 + 1) = 1/3
+        filter.predict();
+        filter.update(&[4.0]).unwrap();
+        assert!((filter.state()[0] - 2.0).abs() < 1e-6);
+        // Covariance (1 - 1/3) * 0.5 = 1/3
+        assert!((filter.covariance()[(0, 0)] - 1.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn singular_innovation_covariance() {
+        let mut filter = KalmanFilter::<1, 1>::new(
+            Matrix::from_rows([[1.0]]),
+            Matrix::from_rows([[1.0]]),
+            Matrix::zeros(),
+            Matrix::zeros(),
+            [3.0],
+            Matrix::zeros(),
+        );
+        assert!(matches!(filter.update(&[2.0]), Err(crate::Error::Singular)));
+        assert_eq!(filter.state(), &[3.0]);
+    }
+}
@@ -1,6 +1,7 @@
 //! Definitions of C math functions implemented in micromath
 //!
-//! Micromath implements fewer functions than libm, and has no double-precision functions.
+//! Micromath implements fewer functions than libm, and has no double-precision functions. The
+//! double-precision square root, which `matrix::qr_f64` uses, is implemented here.
 
 
 macro_rules! forward {
@@ -48,3 +49,68 @@ forward! {
     hypotf(f32, f32) -> hypot,
     powf(f32, f32) -> powf,
 }
+
+/// Calculates the double-precision square root of a value
+///
+/// This starts with an estimate from the exponent of the value and refines it with Newton's
+/// method. Like the C function, it returns NaN for negative values.
+#[no_mangle]
+pub extern "C" fn sqrt(value: f64) -> f64 {
+    if value.is_nan() || value < 0.0 {
+        return f64::NAN;
+    }
+    if value == 0.0 || value == f64::INFINITY {
+        return value;
+    }
+    // Scale subnormal values up by 2^104 so that the estimate below works, and scale the result
+    // down by 2^52
+    let (value, scale) = if value < f64::MIN_POSITIVE {
+        (
+            value * f64::from_bits(0x4670_0000_0000_0000),
+            1.0 / 4_503_599_627_370_496.0,
+        )
+    } else {
+        (value, 1.0)
+    };
+    // Halving the exponent gives an estimate with a relative error of less than 6%
+    let mut root = f64::from_bits((value.to_bits() >> 1) + 0x1ff8_0000_0000_0000);
+    // Each iteration approximately doubles the number of correct bits
+    for _ in 0..6 {
+        root = 0.5 * (root + value / root);
+    }
+    root * scale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sqrt;
+
+    #[test]
+    fn sqrt_f64_reference() {
+        assert_eq!(sqrt(4.0), 2.0);
+        assert_eq!(sqrt(1.0), 1.0);
+        assert_eq!(sqrt(0.25), 0.5);
+        assert_eq!(sqrt(0.0), 0.0);
+        assert_eq!(sqrt(f64::INFINITY), f64::INFINITY);
+        assert!(sqrt(-1.0).is_nan());
+        assert!(sqrt(f64::NAN).is_nan());
+        for &(value, expected) in [
+            (2.0, core::f64::consts::SQRT_2),
+            (1e300, 1e150),
+            (1e-300, 1e-150),
+            (f64::MAX, 1.340_780_792_994_259_6e154),
+            // The smallest subnormal value, 2^-1074
+            (f64::from_bits(1), f64::from_bits(0x1e60_0000_0000_0000)),
+        ]
+        .iter()
+        {
+            let root = sqrt(value);
+            assert!(
+                ((root - expected) / expected).abs() < 1e-15,
+                "sqrt({}) = {}",
+                value,
+                root
+            );
+        }
+    }
+}
@@ -0,0 +1,394 @@
+//! Quaternion functions
+//!
+//! These functions behave in the same way as the quaternion functions in later versions of
+//! CMSIS-DSP. The functions that operate on blocks of quaternions process each quaternion independently.
+
+use core::ops::Mul;
+
+use crate::check_length;
+
+/// A quaternion
+///
+/// The values are `[w, x, y, z]`, where w is the real part and x, y, and z are the imaginary
+/// parts. This is the same layout that CMSIS-DSP uses.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[repr(transparent)]
+pub struct Quaternion(pub [f32; 4]);
+
+impl Quaternion {
+    /// The identity quaternion, which represents no rotation
+    pub const IDENTITY: Quaternion = Quaternion([1.0, 0.0, 0.0, 0.0]);
+
+    /// Returns the squared norm of this quaternion
+    fn norm_squared(&self) -> f32 {
+        self.0.iter().map(|value| value * value).sum()
+    }
+
+    /// Returns the norm (magnitude) of this quaternion
+    pub fn norm(&self) -> f32 {
+        unsafe { crate::sqrtf(self.norm_squared()) }
+    }
+
+    /// Returns this quaternion scaled to have a norm of 1
+    ///
+    /// If this quaternion is zero, the result contains NaN values.
+    pub fn normalize(&self) -> Quaternion {
+        let norm = self.norm();
+        let [w, x, y, z] = self.0;
+        Quaternion([w / norm, x / norm, y / norm, z / norm])
+    }
+
+    /// Returns the conjugate of this quaternion
+    pub fn conjugate(&self) -> Quaternion {
+        let [w, x, y, z] = self.0;
+        Quaternion([w, -x, -y, -z])
+    }
+
+    /// Returns the inverse of this quaternion
+    ///
+    /// If this quaternion is zero, the result contains NaN values.
+    pub fn inverse(&self) -> Quaternion {
+        let norm_squared = self.norm_squared();
+        let [w, x, y, z] = self.0;
+        Quaternion([
+            w / norm_squared,
+            -x / norm_squared,
+            -y / norm_squared,
+            -z / norm_squared,
+        ])
+    }
+
+    /// Returns the rotation matrix that this quaternion represents
+    ///
+    /// The matrix has 3 rows and 3 columns and is stored in row-major order. This quaternion
+    /// must be normalized.
+    pub fn to_rotation(&self) -> [f32; 9] {
+        let [w, x, y, z] = self.0;
+        let (ww, xx, yy, zz) = (w * w, x * x, y * y, z * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        [
+            ww + xx - yy - zz,
+            2.0 * (xy - wz),
+            2.0 * (xz + wy),
+            2.0 * (xy + wz),
+            ww - xx + yy - zz,
+            2.0 * (yz - wx),
+            2.0 * (xz - wy),
+            2.0 * (yz + wx),
+            ww - xx - yy + zz,
+        ]
+    }
+
+    /// Returns a quaternion that represents a rotation matrix
+    ///
+    /// The matrix has 3 rows and 3 columns and is stored in row-major order. It must be a
+    /// rotation matrix (orthogonal, with a determinant of 1). The result is normalized. Because q
+    /// and -q represent the same rotation, the sign of the result is not specified.
+    pub fn from_rotation(matrix: &[f32; 9]) -> Quaternion {
+        let m = matrix;
+        let trace = m[0] + m[4] + m[8];
+        // Use the largest of w, x, y, and z as the divisor, to avoid dividing by a small value
+        if trace > 0.0 {
+            let doubler = unsafe { crate::sqrtf(trace + 1.0) } * 2.0;
+            let s = 1.0 / doubler;
+            Quaternion([
+                0.25 * doubler,
+                (m[7] - m[5]) * s,
+                (m[2] - m[6]) * s,
+                (m[3] - m[1]) * s,
+            ])
+        } else if m[0] > m[4] && m[0] > m[8] {
+            let doubler = unsafe { crate::sqrtf(1.0 + m[0] - m[4] - m[8]) } * 2.0;
+            let s = 1.0 / doubler;
+            Quaternion([
+                (m[7] - m[5]) * s,
+                0.25 * doubler,
+                (m[1] + m[3]) * s,
+                (m[2] + m[6]) * s,
+            ])
+        } else if m[4] > m[8] {
+            let doubler = unsafe { crate::sqrtf(1.0 + m[4] - m[0] - m[8]) } * 2.0;
+            let s = 1.0 / doubler;
+            Quaternion([
+                (m[2] - m[6]) * s,
+                (m[1] + m[3]) * s,
+                0.25 * doubler,
+                (m[5] + m[7]) * s,
+            ])
+        } else {
+            let doubler = unsafe { crate::sqrtf(1.0 + m[8] - m[0] - m[4]) } * 2.0;
+            let s = 1.0 / doubler;
+            Quaternion([
+                (m[3] - m[1]) * s,
+                (m[2] + m[6]) * s,
+                (m[5] + m[7]) * s,
+                0.25 * doubler,
+            ])
+        }
+    }
+
+    /// Returns the Hamilton product of this quaternion and another quaternion
+    pub fn product(&self, other: &Quaternion) -> Quaternion {
+        let [a0, a1, a2, a3] = self.0;
+        let [b0, b1, b2, b3] = other.0;
+        Quaternion([
+            a0 * b0 - a1 * b1 - a2 * b2 - a3 * b3,
+            a0 * b1 + a1 * b0 + a2 * b3 - a3 * b2,
+            a0 * b2 + a2 * b0 + a3 * b1 - a1 * b3,
+            a0 * b3 + a3 * b0 + a1 * b2 - a2 * b1,
+        ])
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        self.product(&rhs)
+    }
+}
+
+impl From<[f32; 4]> for Quaternion {
+    fn from(values: [f32; 4]) -> Self {
+        Quaternion(values)
+    }
+}
+
+impl From<Quaternion> for [f32; 4] {
+    fn from(quaternion: Quaternion) -> Self {
+        quaternion.0
+    }
+}
+
+/// Calculates the norm of each quaternion in a block
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn quaternion_norm_f32(src: &[Quaternion], dst: &mut [f32]) {
+    let _: u32 = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src.norm();
+    }
+}
+
+/// Normalizes each quaternion in a block
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn quaternion_normalize_f32(src: &[Quaternion], dst: &mut [Quaternion]) {
+    let _: u32 = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src.normalize();
+    }
+}
+
+/// Calculates the conjugate of each quaternion in a block
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn quaternion_conjugate_f32(src: &[Quaternion], dst: &mut [Quaternion]) {
+    let _: u32 = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src.conjugate();
+    }
+}
+
+/// Calculates the inverse of each quaternion in a block
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn quaternion_inverse_f32(src: &[Quaternion], dst: &mut [Quaternion]) {
+    let _: u32 = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src.inverse();
+    }
+}
+
+/// Calculates the products of two blocks of quaternions
+///
+/// This is functionally equivalent to performing `dst[i] = src1[i] * src2[i]` for all values
+/// of i in range.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and dst do not have the same length.
+pub fn quaternion_product_f32(src1: &[Quaternion], src2: &[Quaternion], dst: &mut [Quaternion]) {
+    let _: u32 = check_length((src1.len(), src2.len(), dst.len()));
+    for ((dst, src1), src2) in dst.iter_mut().zip(src1.iter()).zip(src2.iter()) {
+        *dst = src1.product(src2);
+    }
+}
+
+/// Converts each quaternion in a block into a rotation matrix
+///
+/// Each matrix has 3 rows and 3 columns and is stored in row-major order. The quaternions must
+/// be normalized.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn quaternion_to_rotation_f32(src: &[Quaternion], dst: &mut [[f32; 9]]) {
+    let _: u32 = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src.to_rotation();
+    }
+}
+
+/// Converts each rotation matrix in a block into a quaternion
+///
+/// Each matrix has 3 rows and 3 columns and is stored in row-major order.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn rotation_to_quaternion_f32(src: &[[f32; 9]], dst: &mut [Quaternion]) {
+    let _: u32 = check_length((src.len(), dst.len()));
+    for (dst, src) in dst.iter_mut().zip(src.iter()) {
+        *dst = Quaternion::from_rotation(src);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_1_SQRT_2;
+
+    use super::*;
+
+    const I: Quaternion = Quaternion([0.0, 1.0, 0.0, 0.0]);
+    const J: Quaternion = Quaternion([0.0, 0.0, 1.0, 0.0]);
+    const K: Quaternion = Quaternion([0.0, 0.0, 0.0, 1.0]);
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-6, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    /// Checks that two quaternions represent the same rotation
+    fn assert_same_rotation(actual: Quaternion, expected: Quaternion) {
+        let dot: f32 = actual
+            .0
+            .iter()
+            .zip(expected.0.iter())
+            .map(|(a, e)| a * e)
+            .sum();
+        if dot < 0.0 {
+            assert_close(&negate(actual).0, &expected.0);
+        } else {
+            assert_close(&actual.0, &expected.0);
+        }
+    }
+
+    fn negate(q: Quaternion) -> Quaternion {
+        let [w, x, y, z] = q.0;
+        Quaternion([-w, -x, -y, -z])
+    }
+
+    #[test]
+    fn product_of_units() {
+        assert_eq!(I * J, K);
+        assert_eq!(J * K, I);
+        assert_eq!(K * I, J);
+        assert_eq!(J * I, negate(K));
+        assert_eq!(I * I, negate(Quaternion::IDENTITY));
+        assert_eq!(I * J * K, negate(Quaternion::IDENTITY));
+
+        let mut dst = [Quaternion::default(); 2];
+        quaternion_product_f32(&[I, K], &[J, J], &mut dst);
+        assert_eq!(dst, [K, negate(I)]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "micromath", not(feature = "libm")),
+        ignore = "micromath sqrtf is not precise enough"
+    )]
+    fn conjugate_norm_and_normalize() {
+        let q = Quaternion([1.0, -2.0, 4.0, -2.0]);
+        assert_eq!(q.conjugate(), Quaternion([1.0, 2.0, -4.0, 2.0]));
+        assert_eq!(q.norm(), 5.0);
+        assert_close(&q.normalize().0, &[0.2, -0.4, 0.8, -0.4]);
+        assert!((q.normalize().norm() - 1.0).abs() < 1e-6);
+        // The product of a quaternion and its conjugate is the squared norm
+        assert_eq!(q * q.conjugate(), Quaternion([25.0, 0.0, 0.0, 0.0]));
+
+        let mut norms = [0.0; 2];
+        quaternion_norm_f32(&[q, Quaternion::IDENTITY], &mut norms);
+        assert_eq!(norms, [5.0, 1.0]);
+        let mut dst = [Quaternion::default(); 1];
+        quaternion_conjugate_f32(&[q], &mut dst);
+        assert_eq!(dst[0], q.conjugate());
+        quaternion_normalize_f32(&[q], &mut dst);
+        assert_eq!(dst[0], q.normalize());
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "micromath", not(feature = "libm")),
+        ignore = "micromath sqrtf is not precise enough"
+    )]
+    fn inverse() {
+        let q = Quaternion([1.0, -2.0, 4.0, -2.0]);
+        assert_close(&(q * q.inverse()).0, &Quaternion::IDENTITY.0);
+        assert_close(&(q.inverse() * q).0, &Quaternion::IDENTITY.0);
+        // The inverse of a unit quaternion is its conjugate
+        let unit = q.normalize();
+        assert_close(&unit.inverse().0, &unit.conjugate().0);
+        let mut dst = [Quaternion::default(); 1];
+        quaternion_inverse_f32(&[q], &mut dst);
+        assert_eq!(dst[0], q.inverse());
+        // Zero has no inverse
+        assert!(Quaternion::default().inverse().0[0].is_nan());
+    }
+
+    #[test]
+    fn rotation_reference() {
+        assert_eq!(
+            Quaternion::IDENTITY.to_rotation(),
+            [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+        );
+        // A rotation of 90 degrees around the z axis
+        let q = Quaternion([FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2]);
+        assert_close(
+            &q.to_rotation(),
+            &[0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+        );
+        // A rotation of 180 degrees around the x axis
+        assert_close(
+            &I.to_rotation(),
+            &[1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, -1.0],
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "micromath", not(feature = "libm")),
+        ignore = "micromath sqrtf is not precise enough"
+    )]
+    fn rotation_round_trip() {
+        // These cover each case in from_rotation: a positive trace, and each of x, y, and z
+        // being the largest component
+        let quaternions = [
+            Quaternion([0.5, 0.5, -0.5, 0.5]),
+            Quaternion([0.1, 0.9, 0.3, -0.2]).normalize(),
+            Quaternion([-0.1, 0.3, 0.9, 0.2]).normalize(),
+            Quaternion([0.2, -0.3, 0.1, -0.9]).normalize(),
+            I,
+            J,
+            K,
+        ];
+        let mut matrices = [[0.0; 9]; 7];
+        quaternion_to_rotation_f32(&quaternions, &mut matrices);
+        let mut round_trip = [Quaternion::default(); 7];
+        rotation_to_quaternion_f32(&matrices, &mut round_trip);
+        for (&actual, &expected) in round_trip.iter().zip(quaternions.iter()) {
+            assert_same_rotation(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,827 @@
+//! Statistics functions
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I16F48, I18F14, I1F15, I1F31, I1F7, I34F30};
+
+#[cfg(feature = "fixed")]
+use crate::support::{q15_as_bits, q31_as_bits, q7_as_bits};
+
+use crate::check_length;
+
+/// Calculates the mean of a block of values
+///
+/// # Panics
+///
+/// This function panics if src is empty.
+pub fn mean_f32(src: &[f32]) -> f32 {
+    let length = check_nonempty(src.len());
+    let mut result = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_mean_f32(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the variance of a block of values
+///
+/// This is the sample variance, which divides the sum of squared differences from the mean by
+/// `src.len() - 1`. If src has only one value, the variance is zero.
+///
+/// # Panics
+///
+/// This function panics if src is empty.
+pub fn variance_f32(src: &[f32]) -> f32 {
+    let length = check_nonempty(src.len());
+    let mut result = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_var_f32(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the standard deviation of a block of values
+///
+/// This is the square root of the sample variance (see [`variance_f32`]). If src has only one
+/// value, the standard deviation is zero.
+///
+/// # Panics
+///
+/// This function panics if src is empty.
+pub fn standard_deviation_f32(src: &[f32]) -> f32 {
+    let length = check_nonempty(src.len());
+    let mut result = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_std_f32(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the root mean square of a block of values
+///
+/// This is equal to `sqrt(sum(src[i] * src[i]) / src.len())`.
+///
+/// # Panics
+///
+/// This function panics if src is empty.
+pub fn rms_f32(src: &[f32]) -> f32 {
+    let length = check_nonempty(src.len());
+    let mut result = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_rms_f32(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Defines a function that calculates one statistic of a block of fixed-point values by calling
+/// the function with the same name in [`raw`]
+#[cfg(feature = "fixed")]
+macro_rules! fixed_statistic {
+    ($(#[$attr:meta])* $name:ident, $element:ty, $as_bits:ident) => {
+        $(#[$attr])*
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src is empty.
+        pub fn $name(src: &[$element]) -> $element {
+            <$element>::from_bits(raw::$name($as_bits(src)))
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the mean of a block of Q1.31 values
+    ///
+    /// The values are accumulated in 64 bits, so the sum does not overflow.
+    mean_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the mean of a block of Q1.15 values
+    ///
+    /// The values are accumulated in 32 bits, so the sum does not overflow for blocks of up to
+    /// 65536 values.
+    mean_q15, I1F15, q15_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the mean of a block of Q1.7 values
+    ///
+    /// The values are accumulated in 32 bits, so the sum does not overflow for blocks of up to
+    /// 2^24 values.
+    mean_q7, I1F7, q7_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the variance of a block of Q1.31 values
+    ///
+    /// This is the sample variance, which divides by `src.len() - 1`. The input values are
+    /// shifted right by 8 bits to Q1.23 format, so their 8 least significant bits are ignored.
+    /// The squares are accumulated in Q2.46 format in 64 bits, and the result is shifted back to
+    /// Q1.31 format. If src has only one value, the variance is zero.
+    variance_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the variance of a block of Q1.15 values
+    ///
+    /// This is the sample variance, which divides by `src.len() - 1`. The squares are
+    /// accumulated in 64 bits. If src has only one value, the variance is zero.
+    variance_q15, I1F15, q15_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the standard deviation of a block of Q1.31 values
+    ///
+    /// This is the square root of the sample variance (see [`variance_q31`]).
+    standard_deviation_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the standard deviation of a block of Q1.15 values
+    ///
+    /// This is the square root of the sample variance (see [`variance_q15`]).
+    standard_deviation_q15, I1F15, q15_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the root mean square of a block of Q1.31 values
+    ///
+    /// The squares are accumulated in 64 bits with only one guard bit, and the accumulator
+    /// wraps around on overflow. To avoid overflow, the input should be scaled down by
+    /// log2(src.len()) bits.
+    rms_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_statistic! {
+    /// Calculates the root mean square of a block of Q1.15 values
+    ///
+    /// The squares are accumulated in 64 bits, and the result saturates.
+    rms_q15, I1F15, q15_as_bits
+}
+
+/// Calculates the sum of the squares of a block of values
+///
+/// The returned value is the sum of `src[i] * src[i]` over all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if the length of src does not fit into a u32.
+pub fn power_f32(src: &[f32]) -> f32 {
+    let length = check_length(src.len());
+    let mut result = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_power_f32(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the sum of the squares of a block of values
+///
+/// The returned value is the sum of `src[i] * src[i]` over all values of i in range. The
+/// products are truncated to 48 fractional bits before accumulation.
+///
+/// # Panics
+///
+/// This function panics if the length of src does not fit into a u32.
+#[cfg(feature = "fixed")]
+pub fn power_q31(src: &[I1F31]) -> I16F48 {
+    I16F48::from_bits(raw::power_q31(q31_as_bits(src)))
+}
+
+/// Calculates the sum of the squares of a block of values
+///
+/// The returned value is the sum of `src[i] * src[i]` over all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if the length of src does not fit into a u32.
+#[cfg(feature = "fixed")]
+pub fn power_q15(src: &[I1F15]) -> I34F30 {
+    I34F30::from_bits(raw::power_q15(q15_as_bits(src)))
+}
+
+/// Calculates the sum of the squares of a block of values
+///
+/// The returned value is the sum of `src[i] * src[i]` over all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if the length of src does not fit into a u32.
+#[cfg(feature = "fixed")]
+pub fn power_q7(src: &[I1F7]) -> I18F14 {
+    I18F14::from_bits(raw::power_q7(q7_as_bits(src)))
+}
+
+/// Calculates the sum of a block of values
+pub fn accumulate_f32(src: &[f32]) -> f32 {
+    src.iter().sum()
+}
+
+/// Calculates the entropy of a probability distribution
+///
+/// The returned value is `-sum(p[i] * ln(p[i]))` over all values of i in range. The values in
+/// p should be positive and sum to 1.
+///
+/// # Panics
+///
+/// This function panics if the length of p does not fit into a u32.
+pub fn entropy_f32(p: &[f32]) -> f32 {
+    let length = check_length(p.len());
+    unsafe { cmsis_dsp_sys::arm_entropy_f32(p.as_ptr(), length) }
+}
+
+/// Calculates the entropy of a probability distribution
+///
+/// The returned value is `-sum(p[i] * ln(p[i]))` over all values of i in range. The values in
+/// p should be positive and sum to 1.
+///
+/// # Panics
+///
+/// This function panics if the length of p does not fit into a u32.
+pub fn entropy_f64(p: &[f64]) -> f64 {
+    let length = check_length(p.len());
+    unsafe { cmsis_dsp_sys::arm_entropy_f64(p.as_ptr(), length) }
+}
+
+/// Calculates the Kullback-Leibler divergence `D(p || q)` between two probability distributions
+///
+/// The returned value is `sum(p[i] * ln(p[i] / q[i]))` over all values of i in range. The values
+/// in p and q should be positive, and each distribution should sum to 1.
+///
+/// # Panics
+///
+/// This function panics if p and q do not have the same length.
+pub fn kullback_leibler_f32(p: &[f32], q: &[f32]) -> f32 {
+    let length = check_length((p.len(), q.len()));
+    unsafe { cmsis_dsp_sys::arm_kullback_leibler_f32(p.as_ptr(), q.as_ptr(), length) }
+}
+
+/// Calculates the Kullback-Leibler divergence `D(p || q)` between two probability distributions
+///
+/// The returned value is `sum(p[i] * ln(p[i] / q[i]))` over all values of i in range. The values
+/// in p and q should be positive, and each distribution should sum to 1.
+///
+/// # Panics
+///
+/// This function panics if p and q do not have the same length.
+pub fn kullback_leibler_f64(p: &[f64], q: &[f64]) -> f64 {
+    let length = check_length((p.len(), q.len()));
+    unsafe { cmsis_dsp_sys::arm_kullback_leibler_f64(p.as_ptr(), q.as_ptr(), length) }
+}
+
+/// Calculates the logarithm of the sum of the exponentials of a block of values
+///
+/// The returned value is `ln(sum(exp(src[i])))` over all values of i in range. It is
+/// calculated in a way that avoids overflow when the values are large, so it can be used to
+/// add probabilities that are represented by their logarithms.
+///
+/// # Panics
+///
+/// This function panics if src is empty.
+pub fn logsumexp_f32(src: &[f32]) -> f32 {
+    let length = check_nonempty(src.len());
+    unsafe { cmsis_dsp_sys::arm_logsumexp_f32(src.as_ptr(), length) }
+}
+
+/// Calculates the logarithm of the dot product of two vectors that contain logarithms
+///
+/// The returned value is `ln(sum(exp(src1[i] + src2[i])))` over all values of i in range. This
+/// is the logarithm of the dot product of the vectors whose logarithms are src1 and src2.
+///
+/// scratch is used as temporary storage.
+///
+/// # Panics
+///
+/// This function panics if src1, src2, and scratch do not have the same length, or if they are
+/// empty.
+pub fn logsumexp_dot_product_f32(src1: &[f32], src2: &[f32], scratch: &mut [f32]) -> f32 {
+    let length = check_length((src1.len(), src2.len(), scratch.len()));
+    check_nonempty(src1.len());
+    unsafe {
+        cmsis_dsp_sys::arm_logsumexp_dot_prod_f32(
+            src1.as_ptr(),
+            src2.as_ptr(),
+            length,
+            scratch.as_mut_ptr(),
+        )
+    }
+}
+
+/// Calculates the mean squared error between two blocks of values
+///
+/// The returned value is the mean of `(src1[i] - src2[i])^2` over all values of i in range.
+///
+/// # Panics
+///
+/// This function panics if src1 and src2 do not have the same length, or if they are empty.
+pub fn mse_f32(src1: &[f32], src2: &[f32]) -> f32 {
+    let length: u32 = check_length((src1.len(), src2.len()));
+    check_nonempty(src1.len());
+    let sum: f32 = src1
+        .iter()
+        .zip(src2.iter())
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum();
+    sum / length as f32
+}
+
+/// Defines a function that calculates the mean squared error between two blocks of
+/// fixed-point values by calling the function with the same name in [`raw`]
+#[cfg(feature = "fixed")]
+macro_rules! fixed_mse {
+    ($element:ty, $description:expr, $name:ident, $as_bits:ident) => {
+        #[doc = concat!("Calculates the mean squared error between two blocks of ", $description, " values")]
+        ///
+        /// The returned value is the mean of `(src1[i] - src2[i])^2` over all values of i in
+        /// range. The result saturates at the largest representable value.
+        ///
+        /// The differences are calculated and accumulated without loss of precision.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src1 and src2 do not have the same length, or if they are
+        /// empty.
+        pub fn $name(src1: &[$element], src2: &[$element]) -> $element {
+            <$element>::from_bits(raw::$name($as_bits(src1), $as_bits(src2)))
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+fixed_mse!(I1F31, "Q1.31", mse_q31, q31_as_bits);
+#[cfg(feature = "fixed")]
+fixed_mse!(I1F15, "Q1.15", mse_q15, q15_as_bits);
+#[cfg(feature = "fixed")]
+fixed_mse!(I1F7, "Q1.7", mse_q7, q7_as_bits);
+
+/// Defines a function that finds the minimum or maximum value in a block and its index
+macro_rules! extremum_with_index {
+    ($(#[$attr:meta])* $name:ident, $element:ty, $function:ident) => {
+        $(#[$attr])*
+        ///
+        /// This function returns the value and its index. If several values are equal to the
+        /// result, the index of the first one is returned.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src is empty.
+        pub fn $name(src: &[$element]) -> ($element, usize) {
+            let length = check_nonempty(src.len());
+            let mut result = <$element>::default();
+            let mut index = 0u32;
+            unsafe {
+                cmsis_dsp_sys::$function(
+                    src.as_ptr() as *const _,
+                    length,
+                    &mut result as *mut $element as *mut _,
+                    &mut index,
+                );
+            }
+            (result, index as usize)
+        }
+    };
+}
+
+/// Defines a function that finds a value in a block of fixed-point values and its index by
+/// calling the function with the same name in [`raw`]
+#[cfg(feature = "fixed")]
+macro_rules! fixed_with_index {
+    ($(#[$attr:meta])* $name:ident, $element:ty, $as_bits:ident) => {
+        $(#[$attr])*
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src is empty.
+        pub fn $name(src: &[$element]) -> ($element, usize) {
+            let (value, index) = raw::$name($as_bits(src));
+            (<$element>::from_bits(value), index)
+        }
+    };
+}
+
+extremum_with_index! {
+    /// Finds the maximum value in a block of floating-point values
+    max_f32, f32, arm_max_f32
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the maximum value in a block of Q1.31 values
+    ///
+    /// This function returns the value and its index. If several values are equal to the
+    /// result, the index of the first one is returned.
+    max_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the maximum value in a block of Q1.15 values
+    ///
+    /// This function returns the value and its index. If several values are equal to the
+    /// result, the index of the first one is returned.
+    max_q15, I1F15, q15_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the maximum value in a block of Q1.7 values
+    ///
+    /// This function returns the value and its index. If several values are equal to the
+    /// result, the index of the first one is returned.
+    max_q7, I1F7, q7_as_bits
+}
+extremum_with_index! {
+    /// Finds the minimum value in a block of floating-point values
+    min_f32, f32, arm_min_f32
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the minimum value in a block of Q1.31 values
+    ///
+    /// This function returns the value and its index. If several values are equal to the
+    /// result, the index of the first one is returned.
+    min_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the minimum value in a block of Q1.15 values
+    ///
+    /// This function returns the value and its index. If several values are equal to the
+    /// result, the index of the first one is returned.
+    min_q15, I1F15, q15_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the minimum value in a block of Q1.7 values
+    ///
+    /// This function returns the value and its index. If several values are equal to the
+    /// result, the index of the first one is returned.
+    min_q7, I1F7, q7_as_bits
+}
+
+/// Defines functions that find the values with the largest and smallest absolute values in a
+/// block
+macro_rules! absolute_extrema {
+    ($element:ty, $description:expr, $absmax:ident, $absmin:ident, $abs:expr) => {
+        #[doc = concat!("Finds the largest absolute value in a block of ", $description, " values")]
+        ///
+        /// This function returns the absolute value and its index. If several values have the
+        /// same absolute value, the index of the first one is returned.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src is empty.
+        pub fn $absmax(src: &[$element]) -> ($element, usize) {
+            absolute_extremum(src, $abs, |value, result| value > result)
+        }
+
+        #[doc = concat!("Finds the smallest absolute value in a block of ", $description, " values")]
+        ///
+        /// This function returns the absolute value and its index. If several values have the
+        /// same absolute value, the index of the first one is returned.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src is empty.
+        pub fn $absmin(src: &[$element]) -> ($element, usize) {
+            absolute_extremum(src, $abs, |value, result| value < result)
+        }
+    };
+}
+
+absolute_extrema!(f32, "floating-point", absmax_f32, absmin_f32, |value| value
+    .abs());
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the largest absolute value in a block of Q1.31 values
+    ///
+    /// This function returns the absolute value and its index. If several values have the same
+    /// absolute value, the index of the first one is returned.
+    absmax_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the smallest absolute value in a block of Q1.31 values
+    ///
+    /// This function returns the absolute value and its index. If several values have the same
+    /// absolute value, the index of the first one is returned.
+    absmin_q31, I1F31, q31_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the largest absolute value in a block of Q1.15 values
+    ///
+    /// This function returns the absolute value and its index. If several values have the same
+    /// absolute value, the index of the first one is returned.
+    absmax_q15, I1F15, q15_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the smallest absolute value in a block of Q1.15 values
+    ///
+    /// This function returns the absolute value and its index. If several values have the same
+    /// absolute value, the index of the first one is returned.
+    absmin_q15, I1F15, q15_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the largest absolute value in a block of Q1.7 values
+    ///
+    /// This function returns the absolute value and its index. If several values have the same
+    /// absolute value, the index of the first one is returned.
+    absmax_q7, I1F7, q7_as_bits
+}
+#[cfg(feature = "fixed")]
+fixed_with_index! {
+    /// Finds the smallest absolute value in a block of Q1.7 values
+    ///
+    /// This function returns the absolute value and its index. If several values have the same
+    /// absolute value, the index of the first one is returned.
+    absmin_q7, I1F7, q7_as_bits
+}
+
+/// Finds the maximum value in a block of floating-point values, without its index
+///
+/// This can be faster than [`max_f32`].
+///
+/// # Panics
+///
+/// This function panics if src is empty.
+pub fn max_no_idx_f32(src: &[f32]) -> f32 {
+    let length = check_nonempty(src.len());
+    let mut result = 0.0;
+    unsafe {
+        cmsis_dsp_sys::arm_max_no_idx_f32(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Defines a function that finds an extreme value without its index, for which CMSIS-DSP 5.7.0
+/// does not have a dedicated function
+macro_rules! extremum_no_idx {
+    ($element:ty, $description:expr, $kind:expr, $name:ident, $with_index:ident, $c_name:expr) => {
+        #[doc = concat!("Finds the ", $kind, " in a block of ", $description, " values, without its index")]
+        ///
+        #[doc = concat!("CMSIS-DSP 5.7.0 does not include `", $c_name, "`, so this function")]
+        #[doc = concat!("calls [`", stringify!($with_index), "`] and discards the index.")]
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src is empty.
+        pub fn $name(src: &[$element]) -> $element {
+            $with_index(src).0
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F31,
+    "Q1.31",
+    "maximum value",
+    max_no_idx_q31,
+    max_q31,
+    "arm_max_no_idx_q31"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F15,
+    "Q1.15",
+    "maximum value",
+    max_no_idx_q15,
+    max_q15,
+    "arm_max_no_idx_q15"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F7,
+    "Q1.7",
+    "maximum value",
+    max_no_idx_q7,
+    max_q7,
+    "arm_max_no_idx_q7"
+);
+extremum_no_idx!(
+    f32,
+    "floating-point",
+    "minimum value",
+    min_no_idx_f32,
+    min_f32,
+    "arm_min_no_idx_f32"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F31,
+    "Q1.31",
+    "minimum value",
+    min_no_idx_q31,
+    min_q31,
+    "arm_min_no_idx_q31"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F15,
+    "Q1.15",
+    "minimum value",
+    min_no_idx_q15,
+    min_q15,
+    "arm_min_no_idx_q15"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F7,
+    "Q1.7",
+    "minimum value",
+    min_no_idx_q7,
+    min_q7,
+    "arm_min_no_idx_q7"
+);
+extremum_no_idx!(
+    f32,
+    "floating-point",
+    "largest absolute value",
+    absmax_no_idx_f32,
+    absmax_f32,
+    "arm_absmax_no_idx_f32"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F31,
+    "Q1.31",
+    "largest absolute value",
+    absmax_no_idx_q31,
+    absmax_q31,
+    "arm_absmax_no_idx_q31"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F15,
+    "Q1.15",
+    "largest absolute value",
+    absmax_no_idx_q15,
+    absmax_q15,
+    "arm_absmax_no_idx_q15"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F7,
+    "Q1.7",
+    "largest absolute value",
+    absmax_no_idx_q7,
+    absmax_q7,
+    "arm_absmax_no_idx_q7"
+);
+extremum_no_idx!(
+    f32,
+    "floating-point",
+    "smallest absolute value",
+    absmin_no_idx_f32,
+    absmin_f32,
+    "arm_absmin_no_idx_f32"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F31,
+    "Q1.31",
+    "smallest absolute value",
+    absmin_no_idx_q31,
+    absmin_q31,
+    "arm_absmin_no_idx_q31"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F15,
+    "Q1.15",
+    "smallest absolute value",
+    absmin_no_idx_q15,
+    absmin_q15,
+    "arm_absmin_no_idx_q15"
+);
+#[cfg(feature = "fixed")]
+extremum_no_idx!(
+    I1F7,
+    "Q1.7",
+    "smallest absolute value",
+    absmin_no_idx_q7,
+    absmin_q7,
+    "arm_absmin_no_idx_q7"
+);
+
+/// Finds the absolute value in a block that is preferred over all the others, and its index
+///
+/// `better(value, result)` returns true if value should replace the current result. Because
+/// equal values do not replace the result, the index of the first one is returned.
+fn absolute_extremum<T: Copy>(src: &[T], abs: fn(T) -> T, better: fn(T, T) -> bool) -> (T, usize) {
+    check_nonempty(src.len());
+    let mut result = (abs(src[0]), 0);
+    for (i, &value) in src.iter().enumerate().skip(1) {
+        let value = abs(value);
+        if better(value, result.0) {
+            result = (value, i);
+        }
+    }
+    result
+}
+
+/// Checks that a length is not zero and fits into a u32, and returns it
+fn check_nonempty(length: usize) -> u32 {
+    assert_ne!(length, 0, "Empty input");
+    check_length(length)
+}
+
+pub mod raw;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn absolute_extrema_f32() {
+        let src = [0.5, -3.0, 2.0, 3.0, -0.25, 0.25];
+        assert_eq!(absmax_f32(&src), (3.0, 1));
+        assert_eq!(absmin_f32(&src), (0.25, 4));
+        assert_eq!(absmax_no_idx_f32(&src), 3.0);
+        assert_eq!(absmin_no_idx_f32(&src), 0.25);
+        assert_eq!(absmax_f32(&[-1.0]), (1.0, 0));
+    }
+
+    #[test]
+    fn absolute_extrema_fixed_bits() {
+        // The absolute value of the minimum value saturates
+        let src = [100, i16::MIN, -5, i16::MAX, 5];
+        assert_eq!(raw::absmax_q15(&src), (i16::MAX, 1));
+        assert_eq!(raw::absmin_q15(&src), (5, 2));
+        let src = [i32::MAX, -7, 7, i32::MIN];
+        assert_eq!(raw::absmax_q31(&src), (i32::MAX, 0));
+        assert_eq!(raw::absmin_q31(&src), (7, 1));
+        let src = [-1, 0, i8::MIN];
+        assert_eq!(raw::absmax_q7(&src), (i8::MAX, 2));
+        assert_eq!(raw::absmin_q7(&src), (0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Empty input")]
+    fn absmax_empty() {
+        absmax_f32(&[]);
+    }
+
+    #[test]
+    fn mse_f32_known_values() {
+        // Squared differences 0, 4, and 9
+        let mse = mse_f32(&[1.0, 2.0, 3.0], &[1.0, 0.0, 6.0]);
+        assert!((mse - 13.0 / 3.0).abs() < 1e-6);
+        assert_eq!(mse_f32(&[0.5, -0.25], &[0.5, -0.25]), 0.0);
+    }
+
+    #[test]
+    fn mse_q31_known_values() {
+        // Differences 0.5 and -1, with squares 0.25 and 1, so the mean is 0.625 (0.625 * 2^31)
+        assert_eq!(
+            raw::mse_q31(&[1 << 30, -(1 << 30)], &[0, 1 << 30]),
+            1_342_177_280
+        );
+        // A difference of -1 has a square of 1, which saturates
+        assert_eq!(raw::mse_q31(&[i32::MIN], &[0]), i32::MAX);
+        // A difference of almost 2 has a square of almost 4, which saturates
+        assert_eq!(raw::mse_q31(&[i32::MAX], &[i32::MIN]), i32::MAX);
+        // The squares have 62 fractional bits, and the mean is shifted right by 31 bits
+        assert_eq!(raw::mse_q31(&[1 << 15], &[0]), 0);
+        assert_eq!(raw::mse_q31(&[1 << 16], &[0]), 2);
+    }
+
+    #[test]
+    fn mse_q15_known_values() {
+        // Differences 0.5 and -1, with squares 0.25 and 1, so the mean is 0.625 (0.625 * 2^15)
+        assert_eq!(raw::mse_q15(&[1 << 14, -(1 << 14)], &[0, 1 << 14]), 20480);
+        assert_eq!(raw::mse_q15(&[i16::MIN], &[0]), i16::MAX);
+        assert_eq!(raw::mse_q15(&[i16::MAX], &[i16::MIN]), i16::MAX);
+        // 181^2 = 32761 and 182^2 = 33124, which are shifted right by 15 bits
+        assert_eq!(raw::mse_q15(&[181], &[0]), 0);
+        assert_eq!(raw::mse_q15(&[182], &[0]), 1);
+    }
+
+    #[test]
+    fn mse_q7_known_values() {
+        // Differences 0.5 and -0.5, with squares 0.25, so the mean is 0.25
+        assert_eq!(raw::mse_q7(&[64, -64], &[0, 0]), 32);
+        assert_eq!(raw::mse_q7(&[i8::MIN], &[0]), i8::MAX);
+        assert_eq!(raw::mse_q7(&[i8::MAX], &[i8::MIN]), i8::MAX);
+        // 12^2 = 144, which is 1.125 after shifting right by 7 bits and is truncated to 1
+        assert_eq!(raw::mse_q7(&[12], &[0]), 1);
+        // The mean of 144 and 0 is 72, which is truncated to 0
+        assert_eq!(raw::mse_q7(&[12, 0], &[0, 0]), 0);
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn mse_fixed_known_values() {
+        let src1 = [I1F15::from_num(0.5), I1F15::from_num(-0.25)];
+        let src2 = [I1F15::from_num(-0.5), I1F15::from_num(0.25)];
+        // Differences 1 and -0.5, with squares 1 and 0.25
+        assert_eq!(mse_q15(&src1, &src2), I1F15::from_num(0.625));
+        let src1 = [I1F31::from_num(0.5)];
+        let src2 = [I1F31::from_num(-0.5)];
+        assert_eq!(mse_q31(&src1, &src2), I1F31::MAX);
+        let src1 = [I1F7::from_num(0.5), I1F7::from_num(-0.5)];
+        let src2 = [I1F7::ZERO; 2];
+        assert_eq!(mse_q7(&src1, &src2), I1F7::from_num(0.25));
+    }
+}
@@ -0,0 +1,213 @@
+//! Statistics functions on fixed-point values stored as raw integers
+//!
+//! These functions have the same names and behavior as the fixed-point functions in the parent
+//! module, but they take `i32`, `i16`, and `i8` values that hold the bits of Q1.31, Q1.15, and
+//! Q1.7 values. They are available whether or not the `fixed` feature is enabled.
+
+use core::convert::TryInto;
+
+use super::{absolute_extremum, check_nonempty};
+use crate::check_length;
+
+/// Defines a function that calculates one statistic of a block of values
+macro_rules! raw_statistic {
+    ($(#[$attr:meta])* $name:ident, $element:ty, $function:ident) => {
+        $(#[$attr])*
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src is empty.
+        pub fn $name(src: &[$element]) -> $element {
+            let length = check_nonempty(src.len());
+            let mut result = 0;
+            unsafe {
+                cmsis_dsp_sys::$function(src.as_ptr(), length, &mut result);
+            }
+            result
+        }
+    };
+}
+
+raw_statistic! {
+    /// Calculates the mean of a block of Q1.31 values
+    ///
+    /// The values are accumulated in 64 bits, so the sum does not overflow.
+    mean_q31, i32, arm_mean_q31
+}
+raw_statistic! {
+    /// Calculates the mean of a block of Q1.15 values
+    ///
+    /// The values are accumulated in 32 bits, so the sum does not overflow for blocks of up to
+    /// 65536 values.
+    mean_q15, i16, arm_mean_q15
+}
+raw_statistic! {
+    /// Calculates the mean of a block of Q1.7 values
+    ///
+    /// The values are accumulated in 32 bits, so the sum does not overflow for blocks of up to
+    /// 2^24 values.
+    mean_q7, i8, arm_mean_q7
+}
+raw_statistic! {
+    /// Calculates the variance of a block of Q1.31 values
+    ///
+    /// This is the sample variance, which divides by `src.len() - 1`. The input values are
+    /// shifted right by 8 bits to Q1.23 format, so their 8 least significant bits are ignored.
+    /// The squares are accumulated in Q2.46 format in 64 bits, and the result is shifted back to
+    /// Q1.31 format. If src has only one value, the variance is zero.
+    variance_q31, i32, arm_var_q31
+}
+raw_statistic! {
+    /// Calculates the variance of a block of Q1.15 values
+    ///
+    /// This is the sample variance, which divides by `src.len() - 1`. The squares are
+    /// accumulated in 64 bits. If src has only one value, the variance is zero.
+    variance_q15, i16, arm_var_q15
+}
+raw_statistic! {
+    /// Calculates the standard deviation of a block of Q1.31 values
+    ///
+    /// This is the square root of the sample variance (see [`variance_q31`]).
+    standard_deviation_q31, i32, arm_std_q31
+}
+raw_statistic! {
+    /// Calculates the standard deviation of a block of Q1.15 values
+    ///
+    /// This is the square root of the sample variance (see [`variance_q15`]).
+    standard_deviation_q15, i16, arm_std_q15
+}
+raw_statistic! {
+    /// Calculates the root mean square of a block of Q1.31 values
+    ///
+    /// The squares are accumulated in 64 bits with only one guard bit, and the accumulator
+    /// wraps around on overflow. To avoid overflow, the input should be scaled down by
+    /// log2(src.len()) bits.
+    rms_q31, i32, arm_rms_q31
+}
+raw_statistic! {
+    /// Calculates the root mean square of a block of Q1.15 values
+    ///
+    /// The squares are accumulated in 64 bits, and the result saturates.
+    rms_q15, i16, arm_rms_q15
+}
+
+/// Calculates the sum of the squares of a block of Q1.31 values
+///
+/// The returned value is the sum of `src[i] * src[i]` over all values of i in range. The
+/// products are truncated to 48 fractional bits before accumulation, and the result has 48
+/// fractional bits.
+///
+/// # Panics
+///
+/// This function panics if the length of src does not fit into a u32.
+pub fn power_q31(src: &[i32]) -> i64 {
+    let length = check_length(src.len());
+    let mut result = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_power_q31(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the sum of the squares of a block of Q1.15 values
+///
+/// The returned value is the sum of `src[i] * src[i]` over all values of i in range. The result
+/// has 30 fractional bits.
+///
+/// # Panics
+///
+/// This function panics if the length of src does not fit into a u32.
+pub fn power_q15(src: &[i16]) -> i64 {
+    let length = check_length(src.len());
+    let mut result = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_power_q15(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Calculates the sum of the squares of a block of Q1.7 values
+///
+/// The returned value is the sum of `src[i] * src[i]` over all values of i in range. The result
+/// has 14 fractional bits.
+///
+/// # Panics
+///
+/// This function panics if the length of src does not fit into a u32.
+pub fn power_q7(src: &[i8]) -> i32 {
+    let length = check_length(src.len());
+    let mut result = 0;
+    unsafe {
+        cmsis_dsp_sys::arm_power_q7(src.as_ptr(), length, &mut result);
+    }
+    result
+}
+
+/// Defines a function that calculates the mean squared error between two blocks of values
+macro_rules! raw_mse {
+    ($element:ty, $description:expr, $name:ident, $frac_bits:expr) => {
+        #[doc = concat!("Calculates the mean squared error between two blocks of ", $description, " values")]
+        ///
+        /// The returned value is the mean of `(src1[i] - src2[i])^2` over all values of i in
+        /// range. The result saturates at the largest representable value.
+        ///
+        /// The differences are calculated and accumulated without loss of precision.
+        ///
+        /// # Panics
+        ///
+        /// This function panics if src1 and src2 do not have the same length, or if they are
+        /// empty.
+        pub fn $name(src1: &[$element], src2: &[$element]) -> $element {
+            let length: u32 = check_length((src1.len(), src2.len()));
+            check_nonempty(src1.len());
+            let sum: i128 = src1
+                .iter()
+                .zip(src2.iter())
+                .map(|(&a, &b)| {
+                    let difference = i128::from(a) - i128::from(b);
+                    difference * difference
+                })
+                .sum();
+            // The squared differences have twice as many fractional bits as the result
+            let mean = (sum / i128::from(length)) >> $frac_bits;
+            mean.try_into().unwrap_or(<$element>::MAX)
+        }
+    };
+}
+
+raw_mse!(i32, "Q1.31", mse_q31, 31);
+raw_mse!(i16, "Q1.15", mse_q15, 15);
+raw_mse!(i8, "Q1.7", mse_q7, 7);
+
+extremum_with_index! {
+    /// Finds the maximum value in a block of Q1.31 values
+    max_q31, i32, arm_max_q31
+}
+extremum_with_index! {
+    /// Finds the maximum value in a block of Q1.15 values
+    max_q15, i16, arm_max_q15
+}
+extremum_with_index! {
+    /// Finds the maximum value in a block of Q1.7 values
+    max_q7, i8, arm_max_q7
+}
+extremum_with_index! {
+    /// Finds the minimum value in a block of Q1.31 values
+    min_q31, i32, arm_min_q31
+}
+extremum_with_index! {
+    /// Finds the minimum value in a block of Q1.15 values
+    min_q15, i16, arm_min_q15
+}
+extremum_with_index! {
+    /// Finds the minimum value in a block of Q1.7 values
+    min_q7, i8, arm_min_q7
+}
+
+absolute_extrema!(i32, "Q1.31", absmax_q31, absmin_q31, |value| value
+    .saturating_abs());
+absolute_extrema!(i16, "Q1.15", absmax_q15, absmin_q15, |value| value
+    .saturating_abs());
+absolute_extrema!(i8, "Q1.7", absmax_q7, absmin_q7, |value| {
+    value.saturating_abs()
+});
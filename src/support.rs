@@ -0,0 +1,477 @@
+//! Support functions
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31, I1F7};
+
+use crate::check_length;
+
+pub mod raw;
+
+/// Scales a block of floating-point values to use the full Q1.15 range, and converts them into
+/// fixed-point values
+///
+/// The values in src are multiplied by a gain that makes the largest absolute value in the block
+/// map to the largest representable Q1.15 value. The scaled values are converted and stored in
+/// dst. src is not modified.
+///
+/// This function returns the gain that was applied. To recover the original scale after
+/// fixed-point processing, divide the results by the returned gain.
+///
+/// If src is empty or all its values are zero, the gain is 1.0 and no scaling is applied.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn float_to_q15_normalized(src: &[f32], dst: &mut [I1F15]) -> f32 {
+    raw::float_to_q15_normalized(src, q15_as_bits_mut(dst))
+}
+
+/// Copies floating-point values from src to dst
+///
+/// This is functionally equivalent to `dst.copy_from_slice(src)`, but may be faster on some
+/// processors.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn copy_f32(src: &[f32], dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_copy_f32(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Copies Q1.31 values from src to dst
+///
+/// This is functionally equivalent to `dst.copy_from_slice(src)`, but may be faster on some
+/// processors.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn copy_q31(src: &[I1F31], dst: &mut [I1F31]) {
+    raw::copy_q31(q31_as_bits(src), q31_as_bits_mut(dst))
+}
+
+/// Copies Q1.15 values from src to dst
+///
+/// This is functionally equivalent to `dst.copy_from_slice(src)`, but may be faster on some
+/// processors.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn copy_q15(src: &[I1F15], dst: &mut [I1F15]) {
+    raw::copy_q15(q15_as_bits(src), q15_as_bits_mut(dst))
+}
+
+/// Copies Q1.7 values from src to dst
+///
+/// This is functionally equivalent to `dst.copy_from_slice(src)`, but may be faster on some
+/// processors.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn copy_q7(src: &[I1F7], dst: &mut [I1F7]) {
+    raw::copy_q7(q7_as_bits(src), q7_as_bits_mut(dst))
+}
+
+/// Sets all floating-point values in dst to value
+///
+/// This is functionally equivalent to `dst.fill(value)`, but may be faster on some processors.
+pub fn fill_f32(value: f32, dst: &mut [f32]) {
+    let length = check_length(dst.len());
+    unsafe {
+        cmsis_dsp_sys::arm_fill_f32(value, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Sets all Q1.31 values in dst to value
+///
+/// This is functionally equivalent to `dst.fill(value)`, but may be faster on some processors.
+#[cfg(feature = "fixed")]
+pub fn fill_q31(value: I1F31, dst: &mut [I1F31]) {
+    raw::fill_q31(value.to_bits(), q31_as_bits_mut(dst))
+}
+
+/// Sets all Q1.15 values in dst to value
+///
+/// This is functionally equivalent to `dst.fill(value)`, but may be faster on some processors.
+#[cfg(feature = "fixed")]
+pub fn fill_q15(value: I1F15, dst: &mut [I1F15]) {
+    raw::fill_q15(value.to_bits(), q15_as_bits_mut(dst))
+}
+
+/// Sets all Q1.7 values in dst to value
+///
+/// This is functionally equivalent to `dst.fill(value)`, but may be faster on some processors.
+#[cfg(feature = "fixed")]
+pub fn fill_q7(value: I1F7, dst: &mut [I1F7]) {
+    raw::fill_q7(value.to_bits(), q7_as_bits_mut(dst))
+}
+
+/// Converts floating-point values into Q1.31 fixed-point values
+///
+/// Values outside the range [-1, 1) saturate to the minimum or maximum Q1.31 value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn float_to_q31(src: &[f32], dst: &mut [I1F31]) {
+    raw::float_to_q31(src, q31_as_bits_mut(dst))
+}
+
+/// Converts floating-point values into Q1.15 fixed-point values
+///
+/// Values outside the range [-1, 1) saturate to the minimum or maximum Q1.15 value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn float_to_q15(src: &[f32], dst: &mut [I1F15]) {
+    raw::float_to_q15(src, q15_as_bits_mut(dst))
+}
+
+/// Converts floating-point values into Q1.7 fixed-point values
+///
+/// Values outside the range [-1, 1) saturate to the minimum or maximum Q1.7 value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn float_to_q7(src: &[f32], dst: &mut [I1F7]) {
+    raw::float_to_q7(src, q7_as_bits_mut(dst))
+}
+
+/// Converts Q1.31 fixed-point values into floating-point values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q31_to_float(src: &[I1F31], dst: &mut [f32]) {
+    raw::q31_to_float(q31_as_bits(src), dst)
+}
+
+/// Converts Q1.15 fixed-point values into floating-point values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q15_to_float(src: &[I1F15], dst: &mut [f32]) {
+    raw::q15_to_float(q15_as_bits(src), dst)
+}
+
+/// Converts Q1.7 fixed-point values into floating-point values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q7_to_float(src: &[I1F7], dst: &mut [f32]) {
+    raw::q7_to_float(q7_as_bits(src), dst)
+}
+
+/// Converts Q1.31 fixed-point values into Q1.15 fixed-point values
+///
+/// The 16 least significant fractional bits of each value are discarded.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q31_to_q15(src: &[I1F31], dst: &mut [I1F15]) {
+    raw::q31_to_q15(q31_as_bits(src), q15_as_bits_mut(dst))
+}
+
+/// Converts Q1.31 fixed-point values into Q1.7 fixed-point values
+///
+/// The 24 least significant fractional bits of each value are discarded.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q31_to_q7(src: &[I1F31], dst: &mut [I1F7]) {
+    raw::q31_to_q7(q31_as_bits(src), q7_as_bits_mut(dst))
+}
+
+/// Converts Q1.15 fixed-point values into Q1.31 fixed-point values
+///
+/// This conversion is exact.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q15_to_q31(src: &[I1F15], dst: &mut [I1F31]) {
+    raw::q15_to_q31(q15_as_bits(src), q31_as_bits_mut(dst))
+}
+
+/// Converts Q1.15 fixed-point values into Q1.7 fixed-point values
+///
+/// The 8 least significant fractional bits of each value are discarded.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q15_to_q7(src: &[I1F15], dst: &mut [I1F7]) {
+    raw::q15_to_q7(q15_as_bits(src), q7_as_bits_mut(dst))
+}
+
+/// Converts Q1.7 fixed-point values into Q1.31 fixed-point values
+///
+/// This conversion is exact.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q7_to_q31(src: &[I1F7], dst: &mut [I1F31]) {
+    raw::q7_to_q31(q7_as_bits(src), q31_as_bits_mut(dst))
+}
+
+/// Converts Q1.7 fixed-point values into Q1.15 fixed-point values
+///
+/// This conversion is exact.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "fixed")]
+pub fn q7_to_q15(src: &[I1F7], dst: &mut [I1F15]) {
+    raw::q7_to_q15(q7_as_bits(src), q15_as_bits_mut(dst))
+}
+
+/// Sorting algorithms
+#[derive(Debug, Copy, Clone)]
+pub enum SortAlgorithm {
+    /// Bitonic sort, which only works when the number of values is a power of two
+    Bitonic = 0,
+    /// Bubble sort
+    Bubble = 1,
+    /// Heap sort
+    Heap = 2,
+    /// Insertion sort
+    Insertion = 3,
+    /// Quick sort
+    Quick = 4,
+    /// Selection sort
+    Selection = 5,
+}
+
+/// Sorting orders
+#[derive(Debug, Copy, Clone)]
+pub enum SortOrder {
+    /// Largest values first
+    Descending = 0,
+    /// Smallest values first
+    Ascending = 1,
+}
+
+/// Sorts floating-point values in place
+///
+/// # Panics
+///
+/// This function panics if algorithm is `Bitonic` and the number of values is not a power of two.
+pub fn sort_f32(data: &mut [f32], algorithm: SortAlgorithm, order: SortOrder) {
+    let length = check_length(data.len());
+    if length == 0 {
+        return;
+    }
+    if let SortAlgorithm::Bitonic = algorithm {
+        assert!(
+            data.len().is_power_of_two(),
+            "Bitonic sort length not a power of two"
+        );
+    }
+    let instance = cmsis_dsp_sys::arm_sort_instance_f32 {
+        alg: algorithm as _,
+        dir: order as _,
+    };
+    let ptr = data.as_mut_ptr();
+    unsafe {
+        cmsis_dsp_sys::arm_sort_f32(&instance, ptr, ptr, length);
+    }
+}
+
+/// Sorts floating-point values in place using the merge sort algorithm
+///
+/// The merge sort is stable and always runs in O(n log n) time. scratch is used as temporary
+/// storage.
+///
+/// # Panics
+///
+/// This function panics if data and scratch do not have the same length.
+pub fn merge_sort_f32(data: &mut [f32], order: SortOrder, scratch: &mut [f32]) {
+    let length = check_length((data.len(), scratch.len()));
+    if length == 0 {
+        return;
+    }
+    let instance = cmsis_dsp_sys::arm_merge_sort_instance_f32 {
+        dir: order as _,
+        buffer: scratch.as_mut_ptr(),
+    };
+    let ptr = data.as_mut_ptr();
+    unsafe {
+        cmsis_dsp_sys::arm_merge_sort_f32(&instance, ptr, ptr, length);
+    }
+}
+
+/// Calculates the weighted average of a block of values
+///
+/// The returned value is `sum(values[i] * weights[i]) / sum(weights[i])` over all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if values and weights do not have the same length.
+pub fn weighted_sum_f32(values: &[f32], weights: &[f32]) -> f32 {
+    let length = check_length((values.len(), weights.len()));
+    unsafe { cmsis_dsp_sys::arm_weighted_sum_f32(values.as_ptr(), weights.as_ptr(), length) }
+}
+
+/// Calculates the barycenter (weighted average) of several vectors
+///
+/// vectors contains `weights.len()` vectors, each with `dst.len()` values, one after another.
+/// This function calculates `dst[j] = sum(vectors[i][j] * weights[i]) / sum(weights[i])` for
+/// all dimensions j, where i goes over all vectors.
+///
+/// # Panics
+///
+/// This function panics if the length of vectors is not equal to the length of weights
+/// multiplied by the length of dst.
+pub fn barycenter_f32(vectors: &[f32], weights: &[f32], dst: &mut [f32]) {
+    let vector_count: u32 = check_length(weights.len());
+    let dimensions: u32 = check_length(dst.len());
+    assert_eq!(
+        Some(vectors.len()),
+        weights.len().checked_mul(dst.len()),
+        "Incorrect vectors length"
+    );
+    unsafe {
+        cmsis_dsp_sys::arm_barycenter_f32(
+            vectors.as_ptr(),
+            weights.as_ptr(),
+            dst.as_mut_ptr(),
+            vector_count,
+            dimensions,
+        );
+    }
+}
+
+/// Converts single-precision floating-point values into half-precision floating-point values
+///
+/// Values are rounded to the nearest representable half-precision value. Values too large to
+/// represent become infinity.
+///
+/// This function uses the conversion from the `half` crate.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "f16")]
+pub fn float_to_f16(src: &[f32], dst: &mut [half::f16]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+        *dst = half::f16::from_f32(src);
+    }
+}
+
+/// Converts half-precision floating-point values into single-precision floating-point values
+///
+/// This conversion is exact.
+///
+/// This function uses the conversion from the `half` crate.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+#[cfg(feature = "f16")]
+pub fn f16_to_float(src: &[half::f16], dst: &mut [f32]) {
+    let _: usize = check_length((src.len(), dst.len()));
+    for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+        *dst = src.to_f32();
+    }
+}
+
+/// Defines functions that reinterpret slices of raw integers as slices of fixed-point values,
+/// and the reverse
+#[cfg(feature = "fixed")]
+macro_rules! bits_views {
+    ($format:literal, $fixed:ty, $bits:ty, $from_bits:ident, $from_bits_mut:ident,
+        $as_bits:ident, $as_bits_mut:ident) => {
+        #[doc = concat!("Returns a slice of ", $format, " fixed-point values that refers to the same memory as")]
+        #[doc = concat!("a slice of raw `", stringify!($bits), "` values")]
+        ///
+        /// This does not copy or convert any values. It allows data from an ADC, DMA transfer, or
+        /// other source of raw integers to be used with the fixed-point functions in this crate.
+        pub fn $from_bits(values: &[$bits]) -> &[$fixed] {
+            // The fixed-point type is repr(transparent) with one field of the integer type
+            unsafe { core::slice::from_raw_parts(values.as_ptr() as *const $fixed, values.len()) }
+        }
+
+        #[doc = concat!("Returns a mutable slice of ", $format, " fixed-point values that refers to the same")]
+        #[doc = concat!("memory as a slice of raw `", stringify!($bits), "` values")]
+        pub fn $from_bits_mut(values: &mut [$bits]) -> &mut [$fixed] {
+            unsafe {
+                core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut $fixed, values.len())
+            }
+        }
+
+        #[doc = concat!("Returns a slice of raw `", stringify!($bits), "` values that refers to the same memory as a")]
+        #[doc = concat!("slice of ", $format, " fixed-point values")]
+        pub fn $as_bits(values: &[$fixed]) -> &[$bits] {
+            unsafe { core::slice::from_raw_parts(values.as_ptr() as *const $bits, values.len()) }
+        }
+
+        #[doc = concat!("Returns a mutable slice of raw `", stringify!($bits), "` values that refers to the same")]
+        #[doc = concat!("memory as a slice of ", $format, " fixed-point values")]
+        pub fn $as_bits_mut(values: &mut [$fixed]) -> &mut [$bits] {
+            unsafe {
+                core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut $bits, values.len())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+bits_views!(
+    "Q1.31",
+    I1F31,
+    i32,
+    q31_from_bits,
+    q31_from_bits_mut,
+    q31_as_bits,
+    q31_as_bits_mut
+);
+#[cfg(feature = "fixed")]
+bits_views!(
+    "Q1.15",
+    I1F15,
+    i16,
+    q15_from_bits,
+    q15_from_bits_mut,
+    q15_as_bits,
+    q15_as_bits_mut
+);
+#[cfg(feature = "fixed")]
+bits_views!(
+    "Q1.7",
+    I1F7,
+    i8,
+    q7_from_bits,
+    q7_from_bits_mut,
+    q7_as_bits,
+    q7_as_bits_mut
+);
@@ -0,0 +1,293 @@
+//! Support functions on fixed-point values stored as raw integers
+//!
+//! These functions have the same names and behavior as the fixed-point functions in the parent
+//! module, but they take `i32`, `i16`, and `i8` values that hold the bits of Q1.31, Q1.15, and
+//! Q1.7 values. They are available whether or not the `fixed` feature is enabled.
+
+use crate::check_length;
+
+/// Scales a block of floating-point values to use the full Q1.15 range, and converts them into
+/// fixed-point values
+///
+/// The values in src are multiplied by a gain that makes the largest absolute value in the block
+/// map to the largest representable Q1.15 value. The scaled values are converted and stored in
+/// dst. src is not modified.
+///
+/// This function returns the gain that was applied. To recover the original scale after
+/// fixed-point processing, divide the results by the returned gain.
+///
+/// If src is empty or all its values are zero, the gain is 1.0 and no scaling is applied.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn float_to_q15_normalized(src: &[f32], dst: &mut [i16]) -> f32 {
+    let length = check_length((src.len(), dst.len()));
+    if length == 0 {
+        return 1.0;
+    }
+    let mut max = 0.0;
+    let mut min = 0.0;
+    let mut index = 0u32;
+    unsafe {
+        cmsis_dsp_sys::arm_max_f32(src.as_ptr(), length, &mut max, &mut index);
+        cmsis_dsp_sys::arm_min_f32(src.as_ptr(), length, &mut min, &mut index);
+    }
+    let abs_max = if max > -min { max } else { -min };
+    let gain = if abs_max > 0.0 { 1.0 / abs_max } else { 1.0 };
+    // Scale each chunk into a temporary buffer so that src does not need to be mutable
+    let mut scaled = [0.0f32; NORMALIZE_CHUNK];
+    for (src, dst) in src
+        .chunks(NORMALIZE_CHUNK)
+        .zip(dst.chunks_mut(NORMALIZE_CHUNK))
+    {
+        let chunk_length = src.len() as u32;
+        unsafe {
+            cmsis_dsp_sys::arm_scale_f32(src.as_ptr(), gain, scaled.as_mut_ptr(), chunk_length);
+            // arm_float_to_q15 saturates, so a scaled value of exactly 1.0 becomes the maximum
+            // Q1.15 value
+            cmsis_dsp_sys::arm_float_to_q15(scaled.as_ptr(), dst.as_mut_ptr(), chunk_length);
+        }
+    }
+    gain
+}
+
+/// The number of values that `float_to_q15_normalized` scales at a time
+const NORMALIZE_CHUNK: usize = 64;
+
+/// Copies Q1.31 values from src to dst
+///
+/// This is functionally equivalent to `dst.copy_from_slice(src)`, but may be faster on some
+/// processors.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn copy_q31(src: &[i32], dst: &mut [i32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_copy_q31(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Copies Q1.15 values from src to dst
+///
+/// This is functionally equivalent to `dst.copy_from_slice(src)`, but may be faster on some
+/// processors.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn copy_q15(src: &[i16], dst: &mut [i16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_copy_q15(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Copies Q1.7 values from src to dst
+///
+/// This is functionally equivalent to `dst.copy_from_slice(src)`, but may be faster on some
+/// processors.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn copy_q7(src: &[i8], dst: &mut [i8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_copy_q7(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Sets all Q1.31 values in dst to value
+///
+/// This is functionally equivalent to `dst.fill(value)`, but may be faster on some processors.
+pub fn fill_q31(value: i32, dst: &mut [i32]) {
+    let length = check_length(dst.len());
+    unsafe {
+        cmsis_dsp_sys::arm_fill_q31(value, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Sets all Q1.15 values in dst to value
+///
+/// This is functionally equivalent to `dst.fill(value)`, but may be faster on some processors.
+pub fn fill_q15(value: i16, dst: &mut [i16]) {
+    let length = check_length(dst.len());
+    unsafe {
+        cmsis_dsp_sys::arm_fill_q15(value, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Sets all Q1.7 values in dst to value
+///
+/// This is functionally equivalent to `dst.fill(value)`, but may be faster on some processors.
+pub fn fill_q7(value: i8, dst: &mut [i8]) {
+    let length = check_length(dst.len());
+    unsafe {
+        cmsis_dsp_sys::arm_fill_q7(value, dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts floating-point values into Q1.31 fixed-point values
+///
+/// Values outside the range [-1, 1) saturate to the minimum or maximum Q1.31 value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn float_to_q31(src: &[f32], dst: &mut [i32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_float_to_q31(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts floating-point values into Q1.15 fixed-point values
+///
+/// Values outside the range [-1, 1) saturate to the minimum or maximum Q1.15 value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn float_to_q15(src: &[f32], dst: &mut [i16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_float_to_q15(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts floating-point values into Q1.7 fixed-point values
+///
+/// Values outside the range [-1, 1) saturate to the minimum or maximum Q1.7 value.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn float_to_q7(src: &[f32], dst: &mut [i8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_float_to_q7(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.31 fixed-point values into floating-point values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q31_to_float(src: &[i32], dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q31_to_float(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.15 fixed-point values into floating-point values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q15_to_float(src: &[i16], dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q15_to_float(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.7 fixed-point values into floating-point values
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q7_to_float(src: &[i8], dst: &mut [f32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q7_to_float(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.31 fixed-point values into Q1.15 fixed-point values
+///
+/// The 16 least significant fractional bits of each value are discarded.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q31_to_q15(src: &[i32], dst: &mut [i16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q31_to_q15(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.31 fixed-point values into Q1.7 fixed-point values
+///
+/// The 24 least significant fractional bits of each value are discarded.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q31_to_q7(src: &[i32], dst: &mut [i8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q31_to_q7(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.15 fixed-point values into Q1.31 fixed-point values
+///
+/// This conversion is exact.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q15_to_q31(src: &[i16], dst: &mut [i32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q15_to_q31(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.15 fixed-point values into Q1.7 fixed-point values
+///
+/// The 8 least significant fractional bits of each value are discarded.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q15_to_q7(src: &[i16], dst: &mut [i8]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q15_to_q7(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.7 fixed-point values into Q1.31 fixed-point values
+///
+/// This conversion is exact.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q7_to_q31(src: &[i8], dst: &mut [i32]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q7_to_q31(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
+
+/// Converts Q1.7 fixed-point values into Q1.15 fixed-point values
+///
+/// This conversion is exact.
+///
+/// # Panics
+///
+/// This function panics if src and dst do not have the same length.
+pub fn q7_to_q15(src: &[i8], dst: &mut [i16]) {
+    let length = check_length((src.len(), dst.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_q7_to_q15(src.as_ptr(), dst.as_mut_ptr(), length);
+    }
+}
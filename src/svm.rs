@@ -0,0 +1,163 @@
+//! Support vector machine classifiers
+//!
+//! These classifiers use models trained elsewhere, for example with the scikit-learn
+//! `sklearn.svm.SVC` class. The support vectors, dual coefficients, intercept, and kernel
+//! parameters of a trained model are the `support_vectors_`, `dual_coef_`, `intercept_`,
+//! `degree`, `coef0`, and `gamma` attributes. Each classifier distinguishes between two
+//! classes.
+
+use core::convert::TryInto;
+
+use crate::{check_buffers, Error, Result};
+
+/// Defines a support vector machine classifier type for one kernel
+macro_rules! svm_classifier {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $instance:ident, $predict:ident $(, $param:ident: $param_type:ty)*
+    ) => {
+        $(#[$attr])*
+        pub struct $name<V, D> {
+            /// Support vectors, one after another
+            support_vectors: V,
+            /// Dual coefficients, one for each support vector
+            dual_coefficients: D,
+            /// Intercept
+            intercept: f32,
+            /// The class identifiers that predict can return
+            classes: [i32; 2],
+            /// The number of values in each vector
+            dimension: usize,
+            $(
+                /// Kernel parameter
+                $param: $param_type,
+            )*
+        }
+
+        impl<V, D> $name<V, D>
+        where
+            V: AsRef<[f32]>,
+            D: AsRef<[f32]>,
+        {
+            /// Creates a classifier
+            ///
+            /// support_vectors contains all support vectors, one after another. There must be
+            /// one dual coefficient for each support vector. classes contains the identifiers of
+            /// the two classes, as in the `classes_` attribute of a scikit-learn model.
+            ///
+            /// This function returns an error if there are no dual coefficients or if the
+            /// number of values in support_vectors is not a multiple of the number of dual
+            /// coefficients.
+            pub fn new(
+                support_vectors: V,
+                dual_coefficients: D,
+                intercept: f32,
+                classes: [i32; 2],
+                $($param: $param_type,)*
+            ) -> Result<Self> {
+                let dimension = check_svm_lengths(
+                    support_vectors.as_ref().len(),
+                    dual_coefficients.as_ref().len(),
+                )?;
+                Ok($name {
+                    support_vectors,
+                    dual_coefficients,
+                    intercept,
+                    classes,
+                    dimension,
+                    $($param,)*
+                })
+            }
+
+            /// Classifies a vector and returns the identifier of its class
+            ///
+            /// # Panics
+            ///
+            /// This function panics if the length of input is not equal to the dimension of
+            /// this classifier.
+            pub fn predict(&self, input: &[f32]) -> i32 {
+                assert_eq!(input.len(), self.dimension, "Input length not equal to dimension");
+                let support_vectors = self.support_vectors.as_ref();
+                let dual_coefficients = self.dual_coefficients.as_ref();
+                check_buffers(
+                    check_svm_lengths(support_vectors.len(), dual_coefficients.len()).and_then(
+                        |dimension| {
+                            if dimension == self.dimension {
+                                Ok(())
+                            } else {
+                                Err(Error::Length)
+                            }
+                        },
+                    ),
+                );
+                let instance = cmsis_dsp_sys::$instance {
+                    nbOfSupportVectors: dual_coefficients.len() as u32,
+                    vectorDimension: self.dimension as u32,
+                    intercept: self.intercept,
+                    dualCoefficients: dual_coefficients.as_ptr(),
+                    supportVectors: support_vectors.as_ptr(),
+                    classes: self.classes.as_ptr(),
+                    $($param: self.$param,)*
+                };
+                let mut class = 0;
+                unsafe {
+                    cmsis_dsp_sys::$predict(&instance, input.as_ptr(), &mut class);
+                }
+                class
+            }
+
+            /// Returns the number of values in each vector
+            pub fn dimension(&self) -> usize {
+                self.dimension
+            }
+
+            /// Returns the identifiers of the two classes
+            pub fn classes(&self) -> [i32; 2] {
+                self.classes
+            }
+
+            /// Releases the support vectors and dual coefficients
+            pub fn into_inner(self) -> (V, D) {
+                (self.support_vectors, self.dual_coefficients)
+            }
+        }
+    };
+}
+
+svm_classifier! {
+    /// A support vector machine classifier with a linear kernel
+    SvmLinearF32, arm_svm_linear_instance_f32, arm_svm_linear_predict_f32
+}
+svm_classifier! {
+    /// A support vector machine classifier with a polynomial kernel
+    ///
+    /// The kernel is `(gamma * dot(x, y) + coef0) ^ degree`.
+    SvmPolynomialF32, arm_svm_polynomial_instance_f32, arm_svm_polynomial_predict_f32,
+    degree: i32, coef0: f32, gamma: f32
+}
+svm_classifier! {
+    /// A support vector machine classifier with a radial basis function kernel
+    ///
+    /// The kernel is `exp(-gamma * |x - y|^2)`.
+    SvmRbfF32, arm_svm_rbf_instance_f32, arm_svm_rbf_predict_f32,
+    gamma: f32
+}
+svm_classifier! {
+    /// A support vector machine classifier with a sigmoid kernel
+    ///
+    /// The kernel is `tanh(gamma * dot(x, y) + coef0)`.
+    SvmSigmoidF32, arm_svm_sigmoid_instance_f32, arm_svm_sigmoid_predict_f32,
+    coef0: f32, gamma: f32
+}
+
+/// Checks the lengths of the support vectors and dual coefficients, and returns the dimension
+/// of the vectors
+fn check_svm_lengths(support_vectors_length: usize, num_support_vectors: usize) -> Result<usize> {
+    let _: u32 = support_vectors_length
+        .try_into()
+        .map_err(|_| Error::Length)?;
+    if num_support_vectors == 0 || support_vectors_length % num_support_vectors != 0 {
+        return Err(Error::Length);
+    }
+    Ok(support_vectors_length / num_support_vectors)
+}
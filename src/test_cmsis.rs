@@ -0,0 +1,128 @@
+//! Rust implementations of the CMSIS-DSP functions used by host tests
+//!
+//! The CMSIS-DSP library is only built for Arm targets, so tests that run on the host cannot call
+//! it. This module defines the few CMSIS-DSP functions that tested code paths depend on.
+
+#[no_mangle]
+pub unsafe extern "C" fn arm_dot_prod_f32(
+    src_a: *const f32,
+    src_b: *const f32,
+    block_size: u32,
+    result: *mut f32,
+) {
+    let a = core::slice::from_raw_parts(src_a, block_size as usize);
+    let b = core::slice::from_raw_parts(src_b, block_size as usize);
+    *result = a.iter().zip(b).map(|(a, b)| a * b).sum();
+}
+
+mod matrix {
+    use cmsis_dsp_sys::arm_matrix_instance_f32 as Instance;
+    use cmsis_dsp_sys::arm_status::{self, ARM_MATH_SINGULAR, ARM_MATH_SUCCESS};
+    use core::slice;
+
+    /// Returns the dimensions and elements of a matrix
+    unsafe fn elements<'a>(matrix: *const Instance) -> (usize, usize, &'a mut [f32]) {
+        let rows = usize::from((*matrix).numRows);
+        let columns = usize::from((*matrix).numCols);
+        let data = slice::from_raw_parts_mut((*matrix).pData, rows * columns);
+        (rows, columns, data)
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn arm_mat_add_f32(
+        src_a: *const Instance,
+        src_b: *const Instance,
+        dst: *mut Instance,
+    ) -> arm_status::Type {
+        let ((_, _, a), (_, _, b), (_, _, dst)) = (elements(src_a), elements(src_b), elements(dst));
+        for ((dst, a), b) in dst.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *dst = a + b;
+        }
+        ARM_MATH_SUCCESS
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn arm_mat_sub_f32(
+        src_a: *const Instance,
+        src_b: *const Instance,
+        dst: *mut Instance,
+    ) -> arm_status::Type {
+        let ((_, _, a), (_, _, b), (_, _, dst)) = (elements(src_a), elements(src_b), elements(dst));
+        for ((dst, a), b) in dst.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *dst = a - b;
+        }
+        ARM_MATH_SUCCESS
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn arm_mat_mult_f32(
+        src_a: *const Instance,
+        src_b: *const Instance,
+        dst: *mut Instance,
+    ) -> arm_status::Type {
+        let ((rows, inner, a), (_, columns, b), (_, _, dst)) =
+            (elements(src_a), elements(src_b), elements(dst));
+        for i in 0..rows {
+            for j in 0..columns {
+                dst[i * columns + j] = (0..inner)
+                    .map(|k| a[i * inner + k] * b[k * columns + j])
+                    .sum();
+            }
+        }
+        ARM_MATH_SUCCESS
+    }
+
+    #[no_mangle]
+    pub unsafe extern "C" fn arm_mat_trans_f32(
+        src: *const Instance,
+        dst: *mut Instance,
+    ) -> arm_status::Type {
+        let ((rows, columns, src), (_, _, dst)) = (elements(src), elements(dst));
+        for i in 0..rows {
+            for j in 0..columns {
+                dst[j * rows + i] = src[i * columns + j];
+            }
+        }
+        ARM_MATH_SUCCESS
+    }
+
+    /// Gauss-Jordan elimination with partial pivoting, which uses src as working memory
+    #[no_mangle]
+    pub unsafe extern "C" fn arm_mat_inverse_f32(
+        src: *const Instance,
+        dst: *mut Instance,
+    ) -> arm_status::Type {
+        let ((n, _, a), (_, _, inverse)) = (elements(src), elements(dst));
+        for (i, value) in inverse.iter_mut().enumerate() {
+            *value = if i % (n + 1) == 0 { 1.0 } else { 0.0 };
+        }
+        for column in 0..n {
+            let pivot = (column..n)
+                .max_by(|&i, &j| {
+                    let (a_i, a_j) = (a[i * n + column].abs(), a[j * n + column].abs());
+                    a_i.partial_cmp(&a_j).unwrap()
+                })
+                .unwrap();
+            if a[pivot * n + column] == 0.0 {
+                return ARM_MATH_SINGULAR;
+            }
+            for k in 0..n {
+                a.swap(pivot * n + k, column * n + k);
+                inverse.swap(pivot * n + k, column * n + k);
+            }
+            let scale = a[column * n + column];
+            for k in 0..n {
+                a[column * n + k] /= scale;
+                inverse[column * n + k] /= scale;
+            }
+            for row in (0..n).filter(|&row| row != column) {
+                let factor = a[row * n + column];
+                for k in 0..n {
+                    a[row * n + k] -= factor * a[column * n + k];
+                    inverse[row * n + k] -= factor * inverse[column * n + k];
+                }
+            }
+        }
+        ARM_MATH_SUCCESS
+    }
+}
@@ -1,14 +1,36 @@
 //! Fast Fourier Transforms
 
+mod dct;
+#[cfg(feature = "f16")]
+mod f16_io;
+#[cfg(feature = "double-precision-fpu")]
+mod fft64;
+mod hilbert;
+mod mfcc;
+mod spectrum;
+mod windowed;
+
 use core::convert::TryInto;
 use core::fmt::Debug;
 use core::mem::MaybeUninit;
 use core::u16;
 
+use crate::complex::ComplexLayout;
+use crate::{Error, Result, StatusCode};
+#[cfg(feature = "fixed")]
 use fixed::types::{I1F15, I1F31};
-use num_complex::{Complex, Complex32};
 
-use crate::{Error, Result, StatusCode};
+pub use self::dct::Dct4F32;
+#[cfg(feature = "fixed")]
+pub use self::dct::{Dct4Q15, Dct4Q31};
+#[cfg(feature = "f16")]
+pub use self::f16_io::{FloatFftF16Io, FloatRealFftF16Io};
+#[cfg(feature = "double-precision-fpu")]
+pub use self::fft64::{FloatFft64, FloatRealFft64};
+pub use self::hilbert::AnalyticSignalF32;
+pub use self::mfcc::{mfcc_dct_coefficients, MelFilterBank, MfccF32};
+pub use self::spectrum::{bin_frequency, fft_shift, frequency_bin, ifft_shift, packed_bin};
+pub use self::windowed::WindowedFft;
 
 /// FFT directions
 #[derive(Debug, Copy, Clone)]
@@ -37,9 +59,22 @@ impl Default for OutputOrder {
 }
 
 /// Runs an FFT on floating-point real numbers
+///
+/// The CMSIS-DSP instance only refers to statically allocated twiddle factor and bit-reversal
+/// tables, so cloning an FFT is cheap and does not repeat the initialization. Because `run`
+/// does not modify the FFT, several channels can also share one FFT through a reference.
 pub struct FloatRealFft(cmsis_dsp_sys::arm_rfft_fast_instance_f32);
 
 unsafe impl Send for FloatRealFft {}
+// The CMSIS-DSP functions only read the instance and the tables that it refers to
+unsafe impl Sync for FloatRealFft {}
+
+impl Clone for FloatRealFft {
+    fn clone(&self) -> Self {
+        // The instance contains only integers and pointers to static tables
+        FloatRealFft(unsafe { core::ptr::read(&self.0) })
+    }
+}
 
 impl FloatRealFft {
     /// Initializes an FFT with the specified size
@@ -56,22 +91,188 @@ impl FloatRealFft {
 
     /// Runs a forward FFT on a set of values, placing the results in output
     ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    ///
     /// # Panics
     ///
     /// This function panics if input or output has a length not equal to the size of this FFT.
-    pub fn run(&self, input: &[f32], output: &mut [f32]) {
+    pub fn run(&self, input: &mut [f32], output: &mut [f32]) {
         self.run_inner(input, output, Direction::Forward);
     }
+
+    /// Runs a forward FFT on a set of values, placing the non-negative frequency half of the
+    /// spectrum in output as complex values
+    ///
+    /// The length of output must be one more than half the size of this FFT. `output[0]` is the
+    /// DC bin and the last element is the Nyquist frequency bin, both with zero imaginary parts.
+    /// Unlike the packed output of `run`, this can be used directly with complex functions.
+    ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input has a length not equal to the size of this FFT, or if
+    /// output has an incorrect length.
+    pub fn run_complex<C: ComplexLayout<Scalar = f32>>(&self, input: &mut [f32], output: &mut [C]) {
+        let bins = self.0.fftLenRFFT as usize / 2;
+        assert_eq!(
+            output.len(),
+            bins + 1,
+            "Output length not equal to half the FFT size plus one"
+        );
+        // Every complex type is layout-compatible with two f32 values, so the first bins
+        // complex values can hold the packed output
+        let packed =
+            unsafe { core::slice::from_raw_parts_mut(output.as_mut_ptr() as *mut f32, 2 * bins) };
+        self.run(input, packed);
+        // The imaginary part of the DC bin holds the real part of the Nyquist bin
+        output[bins] = C::from_parts(output[0].im(), 0.0);
+        output[0] = C::from_parts(output[0].re(), 0.0);
+    }
+
+    /// Copies a block of samples that may be shorter than this FFT into buffer, fills the rest
+    /// of buffer with zeros, and runs a forward FFT on it, placing the results in output
+    ///
+    /// Zero-padding interpolates the spectrum, and allows short captures to be analyzed with a
+    /// fixed FFT size.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input is longer than the size of this FFT, or if buffer or
+    /// output has a length not equal to the size of this FFT.
+    pub fn run_padded(&self, input: &[f32], buffer: &mut [f32], output: &mut [f32]) {
+        zero_pad(input, buffer, 0.0);
+        self.run(buffer, output);
+    }
+
     /// Runs an inverse FFT on a set of values, placing the results in output
     ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input or output has a length not equal to the size of this FFT.
+    pub fn run_inverse(&self, input: &mut [f32], output: &mut [f32]) {
+        self.run_inner(input, output, Direction::Inverse);
+    }
+
+    /// Runs a forward FFT on a set of values, placing the results in an uninitialized output
+    /// buffer
+    ///
+    /// This is the same as `run`, but the contents of output do not need to be initialized. The
+    /// returned slice refers to output, which holds the results. The transform uses input as
+    /// temporary storage, so its contents are changed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input or output has a length not equal to the size of this FFT.
+    pub fn run_uninit<'o>(
+        &self,
+        input: &mut [f32],
+        output: &'o mut [MaybeUninit<f32>],
+    ) -> &'o mut [f32] {
+        self.run_inner(input, output, Direction::Forward);
+        unsafe { crate::assume_init(output) }
+    }
+
+    /// Runs an inverse FFT on a set of values, placing the results in an uninitialized output
+    /// buffer
+    ///
+    /// This is the same as `run_inverse`, but the contents of output do not need to be
+    /// initialized. The returned slice refers to output, which holds the results. The transform
+    /// uses input as temporary storage, so its contents are changed.
+    ///
     /// # Panics
     ///
     /// This function panics if input or output has a length not equal to the size of this FFT.
-    pub fn run_inverse(&self, input: &[f32], output: &mut [f32]) {
+    pub fn run_inverse_uninit<'o>(
+        &self,
+        input: &mut [f32],
+        output: &'o mut [MaybeUninit<f32>],
+    ) -> &'o mut [f32] {
         self.run_inner(input, output, Direction::Inverse);
+        unsafe { crate::assume_init(output) }
     }
 
-    fn run_inner(&self, input: &[f32], output: &mut [f32], direction: Direction) {
+    /// Runs a forward FFT on a set of values, placing the magnitude of each frequency bin in
+    /// magnitudes
+    ///
+    /// scratch is used to hold the packed FFT output, and its length must be equal to the size of
+    /// this FFT. The length of magnitudes must be one more than half the size of this FFT.
+    /// `magnitudes[0]` is the DC magnitude, and the last element is the magnitude at the Nyquist
+    /// frequency.
+    ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input, scratch, or magnitudes has an incorrect length.
+    pub fn run_magnitude(&self, input: &mut [f32], scratch: &mut [f32], magnitudes: &mut [f32]) {
+        self.run_spectrum(input, scratch, magnitudes, cmsis_dsp_sys::arm_cmplx_mag_f32);
+        let last = magnitudes.len() - 1;
+        magnitudes[0] = scratch[0].abs();
+        magnitudes[last] = scratch[1].abs();
+    }
+
+    /// Runs a forward FFT on a set of values, placing the magnitude of each frequency bin in
+    /// decibels in magnitudes
+    ///
+    /// Each output value is `20 * log10(magnitude)`. A bin with zero magnitude produces negative
+    /// infinity. The lengths of scratch and magnitudes are the same as for `run_magnitude`.
+    ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input, scratch, or magnitudes has an incorrect length.
+    pub fn run_magnitude_db(&self, input: &mut [f32], scratch: &mut [f32], magnitudes: &mut [f32]) {
+        // Squared magnitudes avoid a square root, and 10 * log10(m^2) = 20 * log10(m)
+        self.run_spectrum(
+            input,
+            scratch,
+            magnitudes,
+            cmsis_dsp_sys::arm_cmplx_mag_squared_f32,
+        );
+        let last = magnitudes.len() - 1;
+        magnitudes[0] = scratch[0] * scratch[0];
+        magnitudes[last] = scratch[1] * scratch[1];
+        for value in magnitudes.iter_mut() {
+            *value = 10.0 * unsafe { crate::log10f(*value) };
+        }
+    }
+
+    /// Runs a forward FFT into scratch, and then applies a CMSIS-DSP complex magnitude function
+    /// to all bins except DC and Nyquist
+    ///
+    /// The packed FFT output stores the real parts of the DC and Nyquist bins in its first two
+    /// elements, so those bins must be handled by the caller.
+    fn run_spectrum(
+        &self,
+        input: &mut [f32],
+        scratch: &mut [f32],
+        magnitudes: &mut [f32],
+        magnitude: unsafe extern "C" fn(*const f32, *mut f32, u32),
+    ) {
+        let bins = self.0.fftLenRFFT as usize / 2;
+        assert_eq!(
+            magnitudes.len(),
+            bins + 1,
+            "Magnitude length not equal to half the FFT size plus one"
+        );
+        self.run(input, scratch);
+        unsafe {
+            magnitude(
+                scratch[2..].as_ptr(),
+                magnitudes[1..].as_mut_ptr(),
+                (bins - 1) as u32,
+            );
+        }
+    }
+
+    /// Runs the FFT, writing every element of output
+    ///
+    /// The output elements can be f32 or MaybeUninit<f32> values.
+    fn run_inner<O>(&self, input: &mut [f32], output: &mut [O], direction: Direction) {
         // Check length
         check_fft_size(self.0.fftLenRFFT, input.len());
         check_fft_size(self.0.fftLenRFFT, output.len());
@@ -79,8 +280,8 @@ impl FloatRealFft {
         unsafe {
             cmsis_dsp_sys::arm_rfft_fast_f32(
                 &self.0 as *const _ as *mut _,
-                input.as_ptr() as *mut _,
-                output.as_mut_ptr(),
+                input.as_mut_ptr(),
+                output.as_mut_ptr() as *mut f32,
                 direction as _,
             );
         }
@@ -88,10 +289,27 @@ impl FloatRealFft {
 }
 
 /// Runs an FFT on Q1.15 fixed-point real numbers
+///
+/// As with `FloatRealFft`, cloning this FFT is cheap, and several channels can share one FFT
+/// through a reference.
+#[cfg(feature = "fixed")]
 pub struct Q15RealFft(cmsis_dsp_sys::arm_rfft_instance_q15);
 
+#[cfg(feature = "fixed")]
 unsafe impl Send for Q15RealFft {}
+// The CMSIS-DSP functions only read the instance and the tables that it refers to
+#[cfg(feature = "fixed")]
+unsafe impl Sync for Q15RealFft {}
+
+#[cfg(feature = "fixed")]
+impl Clone for Q15RealFft {
+    fn clone(&self) -> Self {
+        // The instance contains only integers and pointers to static tables
+        Q15RealFft(unsafe { core::ptr::read(&self.0) })
+    }
+}
 
+#[cfg(feature = "fixed")]
 impl Q15RealFft {
     /// Initializes an FFT with the specified size
     ///
@@ -116,20 +334,40 @@ impl Q15RealFft {
     /// The output type depends on the size of the FFT. To determine how to interpret the output
     /// bits, refer to the table in the arm_rfft_q15 function documentation
     /// at https://www.keil.com/pack/doc/cmsis/DSP/html/group__RealFFT.html#ga00e615f5db21736ad5b27fb6146f3fc5 .
-    pub fn run(&self, input: &[I1F15], output: &mut [i16]) {
+    ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    pub fn run(&self, input: &mut [I1F15], output: &mut [i16]) {
         check_fft_size(self.0.fftLenReal, input.len());
         check_fft_size(self.0.fftLenReal, output.len());
 
         unsafe {
-            cmsis_dsp_sys::arm_rfft_q15(&self.0, input.as_ptr() as *mut _, output.as_mut_ptr());
+            cmsis_dsp_sys::arm_rfft_q15(&self.0, input.as_mut_ptr() as *mut _, output.as_mut_ptr());
         }
     }
 }
+
 /// Runs an FFT on Q1.31 fixed-point real numbers
+///
+/// As with `FloatRealFft`, cloning this FFT is cheap, and several channels can share one FFT
+/// through a reference.
+#[cfg(feature = "fixed")]
 pub struct Q31RealFft(cmsis_dsp_sys::arm_rfft_instance_q31);
 
+#[cfg(feature = "fixed")]
 unsafe impl Send for Q31RealFft {}
+// The CMSIS-DSP functions only read the instance and the tables that it refers to
+#[cfg(feature = "fixed")]
+unsafe impl Sync for Q31RealFft {}
+
+#[cfg(feature = "fixed")]
+impl Clone for Q31RealFft {
+    fn clone(&self) -> Self {
+        // The instance contains only integers and pointers to static tables
+        Q31RealFft(unsafe { core::ptr::read(&self.0) })
+    }
+}
 
+#[cfg(feature = "fixed")]
 impl Q31RealFft {
     /// Initializes an FFT with the specified size
     ///
@@ -154,12 +392,14 @@ impl Q31RealFft {
     /// The output type depends on the size of the FFT. To determine how to interpret the output
     /// bits, refer to the table in the arm_rfft_q31 function documentation
     /// at https://www.keil.com/pack/doc/cmsis/DSP/html/group__RealFFT.html#gabaeab5646aeea9844e6d42ca8c73fe3a .
-    pub fn run(&self, input: &[I1F31], output: &mut [i32]) {
+    ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    pub fn run(&self, input: &mut [I1F31], output: &mut [i32]) {
         check_fft_size(self.0.fftLenReal, input.len());
         check_fft_size(self.0.fftLenReal, output.len());
 
         unsafe {
-            cmsis_dsp_sys::arm_rfft_q31(&self.0, input.as_ptr() as *mut _, output.as_mut_ptr());
+            cmsis_dsp_sys::arm_rfft_q31(&self.0, input.as_mut_ptr() as *mut _, output.as_mut_ptr());
         }
     }
 }
@@ -167,10 +407,12 @@ impl Q31RealFft {
 /// Runs an FFT on floating-point complex numbers
 pub struct FloatFft {
     /// Data used by the CMSIS-DSP code
-    instance: *const cmsis_dsp_sys::arm_cfft_instance_f32,
+    instance: &'static cmsis_dsp_sys::arm_cfft_instance_f32,
 }
 
 unsafe impl Send for FloatFft {}
+// The instance and the tables that it refers to are static and never modified
+unsafe impl Sync for FloatFft {}
 
 impl FloatFft {
     /// Initializes an FFT with the specified size
@@ -178,29 +420,33 @@ impl FloatFft {
     /// Valid size values are 32, 64, 128, 256, 512, 1024, 2048, and 4096. This function returns
     /// an error if the size value is not valid.
     pub fn new(size: u16) -> Result<Self> {
-        let instance = unsafe {
-            match size {
-                16 => &cmsis_dsp_sys::arm_cfft_sR_f32_len16,
-                32 => &cmsis_dsp_sys::arm_cfft_sR_f32_len32,
-                64 => &cmsis_dsp_sys::arm_cfft_sR_f32_len64,
-                128 => &cmsis_dsp_sys::arm_cfft_sR_f32_len128,
-                256 => &cmsis_dsp_sys::arm_cfft_sR_f32_len256,
-                512 => &cmsis_dsp_sys::arm_cfft_sR_f32_len512,
-                1024 => &cmsis_dsp_sys::arm_cfft_sR_f32_len1024,
-                2048 => &cmsis_dsp_sys::arm_cfft_sR_f32_len2048,
-                4096 => &cmsis_dsp_sys::arm_cfft_sR_f32_len4096,
-                _ => return Err(Error::Argument),
-            }
-        };
+        let instance = cfft_instance_f32(size.into()).ok_or(Error::Argument)?;
         Ok(FloatFft { instance })
     }
 
+    /// Creates an FFT with a size known at compile time
+    ///
+    /// Because this is a `const fn`, it can be used to initialize a `static`. Valid sizes are
+    /// the same as for `new`. Using any other size causes a compile error.
+    pub const fn with_size<const N: usize>() -> Self {
+        let () = FftSize::<N>::SUPPORTED;
+        match cfft_instance_f32(N) {
+            Some(instance) => FloatFft { instance },
+            None => panic!("Unsupported FFT size"),
+        }
+    }
+
     /// Runs the FFT in-place on a buffer of values
-    pub fn run(&self, data: &mut [Complex32], direction: Direction, output_order: OutputOrder) {
+    pub fn run<C: ComplexLayout<Scalar = f32>>(
+        &self,
+        data: &mut [C],
+        direction: Direction,
+        output_order: OutputOrder,
+    ) {
         unsafe {
             // FFT size is number of complex values. arm_cfft_f32 expects size * 2 float values.
-            // Complex<f32> is layout-compatible.
-            check_fft_size((*self.instance).fftLen, data.len());
+            // Every complex type is layout-compatible.
+            check_fft_size(self.instance.fftLen, data.len());
             cmsis_dsp_sys::arm_cfft_f32(
                 self.instance,
                 data.as_mut_ptr() as *mut _,
@@ -209,13 +455,52 @@ impl FloatFft {
             );
         }
     }
+
+    /// Copies a block of values that may be shorter than this FFT into data, fills the rest of
+    /// data with zeros, and runs the FFT in-place on data
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input is longer than the size of this FFT, or if data has a
+    /// length not equal to the size of this FFT.
+    pub fn run_padded<C: ComplexLayout<Scalar = f32>>(
+        &self,
+        input: &[C],
+        data: &mut [C],
+        direction: Direction,
+        output_order: OutputOrder,
+    ) {
+        zero_pad(input, data, C::from_parts(0.0, 0.0));
+        self.run(data, direction, output_order);
+    }
+
+    /// Reorders the output of an FFT that was run with `OutputOrder::Raw` into the standard
+    /// order
+    ///
+    /// This performs the bit-reversal step that the FFT skips when the raw output order is
+    /// used, so the reordering can be done later, outside a time-critical section.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if data has a length not equal to the size of this FFT.
+    pub fn bit_reverse<C: ComplexLayout<Scalar = f32>>(&self, data: &mut [C]) {
+        unsafe {
+            let instance = self.instance;
+            check_fft_size(instance.fftLen, data.len());
+            bit_reverse(data, instance.pBitRevTable, instance.bitRevLength);
+        }
+    }
 }
 
 /// Runs a 128-bin FFT on floating-point data
 ///
 /// This can offer slightly better performance than FloatFft because it skips the data
 /// length check.
-pub fn float_fft_128(data: &mut [Complex32; 128], direction: Direction, output_order: OutputOrder) {
+pub fn float_fft_128<C: ComplexLayout<Scalar = f32>>(
+    data: &mut [C; 128],
+    direction: Direction,
+    output_order: OutputOrder,
+) {
     unsafe {
         cmsis_dsp_sys::arm_cfft_f32(
             &cmsis_dsp_sys::arm_cfft_sR_f32_len128,
@@ -227,6 +512,11 @@ pub fn float_fft_128(data: &mut [Complex32; 128], direction: Direction, output_o
 }
 
 /// Runs an FFT on a buffer of samples with a size known at compile time
+///
+/// The buffer can be an array of floating-point, Q1.31, or Q1.15 complex numbers of any type
+/// that implements `ComplexLayout`. The CMSIS-DSP instance for the array length is selected at
+/// compile time, so no length check is needed at run time. Valid lengths are 16, 32, 64, 128, 256, 512, 1024, 2048, and 4096. Using
+/// any other length causes a compile error.
 pub fn fft<D>(data: &mut D, direction: Direction, output_order: OutputOrder)
 where
     D: FftBuffer,
@@ -234,152 +524,246 @@ where
     data.run_fft(direction, output_order)
 }
 
+/// Runs an FFT on a copy of a buffer of complex values, and returns the results in the standard
+/// order
+///
+/// The values can be floating-point, Q1.31, or Q1.15 complex numbers of any type that
+/// implements `ComplexLayout`. This function is only available when the `alloc` feature is
+/// enabled.
+///
+/// Valid lengths are 16, 32, 64, 128, 256, 512, 1024, 2048, and 4096. This function returns an
+/// error if the length of input is not valid.
+#[cfg(feature = "alloc")]
+pub fn fft_owned<C>(input: &[C], direction: Direction) -> Result<alloc::vec::Vec<C>>
+where
+    C: ComplexLayout,
+    C::Scalar: FftScalar,
+{
+    if !is_cfft_size(input.len()) {
+        return Err(Error::Argument);
+    }
+    let mut data = input.to_vec();
+    unsafe {
+        C::Scalar::run_cfft(
+            data.as_mut_ptr() as *mut _,
+            data.len(),
+            direction,
+            OutputOrder::Standard,
+        );
+    }
+    Ok(data)
+}
+
+/// Runs a forward FFT on a set of real floating-point values, and returns the results in the
+/// same packed format as `FloatRealFft::run`
+///
+/// This creates a `FloatRealFft` for each call, and runs it on a copy of input, so input is not
+/// changed. This function is only available when the `alloc` feature is enabled.
+///
+/// Valid lengths are 32, 64, 128, 256, 512, 1024, 2048, and 4096. This function returns an
+/// error if the length of input is not valid.
+#[cfg(feature = "alloc")]
+pub fn real_fft_owned(input: &[f32]) -> Result<alloc::vec::Vec<f32>> {
+    let size = input.len().try_into().map_err(|_| Error::Argument)?;
+    let fft = FloatRealFft::new(size)?;
+    // The transform uses its input as temporary storage, so it runs on a copy
+    let mut scratch = input.to_vec();
+    let mut output = alloc::vec::Vec::with_capacity(input.len());
+    fft.run_uninit(
+        &mut scratch,
+        &mut output.spare_capacity_mut()[..input.len()],
+    );
+    unsafe { output.set_len(input.len()) };
+    Ok(output)
+}
+
 /// A fixed-length buffer on which an FFT can run
 pub trait FftBuffer {
     fn run_fft(&mut self, direction: Direction, output_order: OutputOrder);
 }
 
-impl FftBuffer for [Complex32; 16] {
+impl<C, const N: usize> FftBuffer for [C; N]
+where
+    C: ComplexLayout,
+    C::Scalar: FftScalar,
+{
     fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len16,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
+        let () = FftSize::<N>::SUPPORTED;
+        unsafe { C::Scalar::run_cfft(self.as_mut_ptr() as *mut _, N, direction, output_order) }
     }
 }
-impl FftBuffer for [Complex32; 32] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len32,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
-    }
+
+/// A type of the real and imaginary parts of complex values on which an FFT can run
+///
+/// This trait is implemented for `f32` and, when the `fixed` feature is enabled, for `I1F31`
+/// and `I1F15`. It is sealed.
+pub trait FftScalar: Copy + sealed::Sealed {
+    /// Runs the CMSIS-DSP complex FFT of the specified size on interleaved data
+    ///
+    /// # Safety
+    ///
+    /// data must point to 2 * size values, and size must be a supported FFT length.
+    #[doc(hidden)]
+    unsafe fn run_cfft(
+        data: *mut Self,
+        size: usize,
+        direction: Direction,
+        output_order: OutputOrder,
+    );
 }
-impl FftBuffer for [Complex32; 64] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len64,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
+
+impl FftScalar for f32 {
+    unsafe fn run_cfft(data: *mut f32, size: usize, direction: Direction, order: OutputOrder) {
+        let instance = cfft_instance_f32(size).expect("Unsupported FFT size");
+        cmsis_dsp_sys::arm_cfft_f32(instance, data, direction as _, order as _);
     }
 }
-impl FftBuffer for [Complex32; 128] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len128,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
+
+#[cfg(feature = "fixed")]
+impl FftScalar for I1F31 {
+    unsafe fn run_cfft(data: *mut I1F31, size: usize, direction: Direction, order: OutputOrder) {
+        let instance = cfft_instance_q31(size).expect("Unsupported FFT size");
+        cmsis_dsp_sys::arm_cfft_q31(instance, data as *mut _, direction as _, order as _);
     }
 }
-impl FftBuffer for [Complex32; 256] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len256,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
+
+#[cfg(feature = "fixed")]
+impl FftScalar for I1F15 {
+    unsafe fn run_cfft(data: *mut I1F15, size: usize, direction: Direction, order: OutputOrder) {
+        let instance = cfft_instance_q15(size).expect("Unsupported FFT size");
+        cmsis_dsp_sys::arm_cfft_q15(instance, data as *mut _, direction as _, order as _);
     }
 }
-impl FftBuffer for [Complex32; 512] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len512,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
-    }
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    #[cfg(feature = "fixed")]
+    impl Sealed for fixed::types::I1F31 {}
+    #[cfg(feature = "fixed")]
+    impl Sealed for fixed::types::I1F15 {}
 }
-impl FftBuffer for [Complex32; 1024] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len1024,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
-    }
+
+/// A compile-time check that an FFT length is supported by the CMSIS-DSP complex FFT tables
+struct FftSize<const N: usize>;
+
+impl<const N: usize> FftSize<N> {
+    /// Evaluating this constant fails if N is not a supported FFT length
+    const SUPPORTED: () = assert!(is_cfft_size(N), "Unsupported FFT size");
 }
-impl FftBuffer for [Complex32; 2048] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len2048,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
-        }
-    }
+
+/// Returns true if a length is supported by the CMSIS-DSP complex FFT tables
+const fn is_cfft_size(size: usize) -> bool {
+    matches!(size, 16 | 32 | 64 | 128 | 256 | 512 | 1024 | 2048 | 4096)
 }
-impl FftBuffer for [Complex32; 4096] {
-    fn run_fft(&mut self, direction: Direction, output_order: OutputOrder) {
-        unsafe {
-            cmsis_dsp_sys::arm_cfft_f32(
-                &cmsis_dsp_sys::arm_cfft_sR_f32_len4096,
-                self.as_mut_ptr() as *mut f32,
-                direction as _,
-                output_order as _,
-            );
+
+/// Defines a function that returns the CMSIS-DSP complex FFT instance for a length
+macro_rules! cfft_instance {
+    ($name:ident, $instance:ident, $len16:ident, $len32:ident, $len64:ident, $len128:ident,
+        $len256:ident, $len512:ident, $len1024:ident, $len2048:ident, $len4096:ident) => {
+        /// Returns the statically allocated complex FFT instance for a length, or None if the
+        /// length is not supported
+        const fn $name(size: usize) -> Option<&'static cmsis_dsp_sys::$instance> {
+            unsafe {
+                match size {
+                    16 => Some(&cmsis_dsp_sys::$len16),
+                    32 => Some(&cmsis_dsp_sys::$len32),
+                    64 => Some(&cmsis_dsp_sys::$len64),
+                    128 => Some(&cmsis_dsp_sys::$len128),
+                    256 => Some(&cmsis_dsp_sys::$len256),
+                    512 => Some(&cmsis_dsp_sys::$len512),
+                    1024 => Some(&cmsis_dsp_sys::$len1024),
+                    2048 => Some(&cmsis_dsp_sys::$len2048),
+                    4096 => Some(&cmsis_dsp_sys::$len4096),
+                    _ => None,
+                }
+            }
         }
-    }
+    };
 }
 
+cfft_instance!(
+    cfft_instance_f32,
+    arm_cfft_instance_f32,
+    arm_cfft_sR_f32_len16,
+    arm_cfft_sR_f32_len32,
+    arm_cfft_sR_f32_len64,
+    arm_cfft_sR_f32_len128,
+    arm_cfft_sR_f32_len256,
+    arm_cfft_sR_f32_len512,
+    arm_cfft_sR_f32_len1024,
+    arm_cfft_sR_f32_len2048,
+    arm_cfft_sR_f32_len4096
+);
+#[cfg(feature = "double-precision-fpu")]
+cfft_instance!(
+    cfft_instance_f64,
+    arm_cfft_instance_f64,
+    arm_cfft_sR_f64_len16,
+    arm_cfft_sR_f64_len32,
+    arm_cfft_sR_f64_len64,
+    arm_cfft_sR_f64_len128,
+    arm_cfft_sR_f64_len256,
+    arm_cfft_sR_f64_len512,
+    arm_cfft_sR_f64_len1024,
+    arm_cfft_sR_f64_len2048,
+    arm_cfft_sR_f64_len4096
+);
+#[cfg(feature = "fixed")]
+cfft_instance!(
+    cfft_instance_q31,
+    arm_cfft_instance_q31,
+    arm_cfft_sR_q31_len16,
+    arm_cfft_sR_q31_len32,
+    arm_cfft_sR_q31_len64,
+    arm_cfft_sR_q31_len128,
+    arm_cfft_sR_q31_len256,
+    arm_cfft_sR_q31_len512,
+    arm_cfft_sR_q31_len1024,
+    arm_cfft_sR_q31_len2048,
+    arm_cfft_sR_q31_len4096
+);
+#[cfg(feature = "fixed")]
+cfft_instance!(
+    cfft_instance_q15,
+    arm_cfft_instance_q15,
+    arm_cfft_sR_q15_len16,
+    arm_cfft_sR_q15_len32,
+    arm_cfft_sR_q15_len64,
+    arm_cfft_sR_q15_len128,
+    arm_cfft_sR_q15_len256,
+    arm_cfft_sR_q15_len512,
+    arm_cfft_sR_q15_len1024,
+    arm_cfft_sR_q15_len2048,
+    arm_cfft_sR_q15_len4096
+);
+
 /// Runs an FFT on Q1.15 fixed-point complex numbers
+#[cfg(feature = "fixed")]
 pub struct Q15Fft {
     /// Data used by the CMSIS-DSP code
-    instance: *const cmsis_dsp_sys::arm_cfft_instance_q15,
+    instance: &'static cmsis_dsp_sys::arm_cfft_instance_q15,
     /// Transform direction
     direction: Direction,
     /// Output order
     output_order: OutputOrder,
 }
 
+#[cfg(feature = "fixed")]
 unsafe impl Send for Q15Fft {}
+// The instance and the tables that it refers to are static and never modified
+#[cfg(feature = "fixed")]
+unsafe impl Sync for Q15Fft {}
 
+#[cfg(feature = "fixed")]
 impl Q15Fft {
     /// Initializes an FFT with the specified size
     ///
     /// Valid size values are 32, 64, 128, 256, 512, 1024, 2048, and 4096. This function returns
     /// an error if the size value is not valid.
     pub fn new(size: u16, direction: Direction, output_order: OutputOrder) -> Result<Self> {
-        let instance = unsafe {
-            match size {
-                16 => &cmsis_dsp_sys::arm_cfft_sR_q15_len16,
-                32 => &cmsis_dsp_sys::arm_cfft_sR_q15_len32,
-                64 => &cmsis_dsp_sys::arm_cfft_sR_q15_len64,
-                128 => &cmsis_dsp_sys::arm_cfft_sR_q15_len128,
-                256 => &cmsis_dsp_sys::arm_cfft_sR_q15_len256,
-                512 => &cmsis_dsp_sys::arm_cfft_sR_q15_len512,
-                1024 => &cmsis_dsp_sys::arm_cfft_sR_q15_len1024,
-                2048 => &cmsis_dsp_sys::arm_cfft_sR_q15_len2048,
-                4096 => &cmsis_dsp_sys::arm_cfft_sR_q15_len4096,
-                _ => return Err(Error::Argument),
-            }
-        };
+        let instance = cfft_instance_q15(size.into()).ok_or(Error::Argument)?;
 
         Ok(Q15Fft {
             instance,
@@ -388,12 +772,31 @@ impl Q15Fft {
         })
     }
 
+    /// Creates an FFT with a size known at compile time
+    ///
+    /// This can be used to initialize a `static`. Valid sizes are the same as for `new`.
+    /// Using any other size causes a compile error.
+    pub const fn with_size<const N: usize>(
+        direction: Direction,
+        output_order: OutputOrder,
+    ) -> Self {
+        let () = FftSize::<N>::SUPPORTED;
+        match cfft_instance_q15(N) {
+            Some(instance) => Q15Fft {
+                instance,
+                direction,
+                output_order,
+            },
+            None => panic!("Unsupported FFT size"),
+        }
+    }
+
     /// Runs the FFT in-place on a buffer of values
-    pub fn run(&self, data: &mut [Complex<I1F15>]) {
+    pub fn run<C: ComplexLayout<Scalar = I1F15>>(&self, data: &mut [C]) {
         unsafe {
             // FFT size is number of complex values. arm_cfft_q15 expects size * 2 u16 values.
-            // Complex<I1F15> is layout-compatible.
-            check_fft_size((*self.instance).fftLen, data.len());
+            // Every complex type is layout-compatible.
+            check_fft_size(self.instance.fftLen, data.len());
             cmsis_dsp_sys::arm_cfft_q15(
                 self.instance,
                 data.as_mut_ptr() as *mut _,
@@ -402,50 +805,72 @@ impl Q15Fft {
             );
         }
     }
+
+    /// Reorders the output of an FFT that was run with `OutputOrder::Raw` into the standard
+    /// order
+    ///
+    /// This performs the bit-reversal step that the FFT skips when the raw output order is
+    /// used, so the reordering can be done later, outside a time-critical section.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if data has a length not equal to the size of this FFT.
+    pub fn bit_reverse<C: ComplexLayout<Scalar = I1F15>>(&self, data: &mut [C]) {
+        unsafe {
+            let instance = self.instance;
+            check_fft_size(instance.fftLen, data.len());
+            bit_reverse(data, instance.pBitRevTable, instance.bitRevLength);
+        }
+    }
 }
 
 /// Runs an FFT on Q1.31 fixed-point complex numbers
+#[cfg(feature = "fixed")]
 pub struct Q31Fft {
     /// Data used by the CMSIS-DSP code
-    instance: *const cmsis_dsp_sys::arm_cfft_instance_q31,
+    instance: &'static cmsis_dsp_sys::arm_cfft_instance_q31,
 }
 
+#[cfg(feature = "fixed")]
 unsafe impl Send for Q31Fft {}
+// The instance and the tables that it refers to are static and never modified
+#[cfg(feature = "fixed")]
+unsafe impl Sync for Q31Fft {}
 
+#[cfg(feature = "fixed")]
 impl Q31Fft {
     /// Initializes an FFT with the specified size
     ///
     /// Valid size values are 32, 64, 128, 256, 512, 1024, 2048, and 4096. This function returns
     /// an error if the size value is not valid.
     pub fn new(size: u16) -> Result<Self> {
-        let instance = unsafe {
-            match size {
-                16 => &cmsis_dsp_sys::arm_cfft_sR_q31_len16,
-                32 => &cmsis_dsp_sys::arm_cfft_sR_q31_len32,
-                64 => &cmsis_dsp_sys::arm_cfft_sR_q31_len64,
-                128 => &cmsis_dsp_sys::arm_cfft_sR_q31_len128,
-                256 => &cmsis_dsp_sys::arm_cfft_sR_q31_len256,
-                512 => &cmsis_dsp_sys::arm_cfft_sR_q31_len512,
-                1024 => &cmsis_dsp_sys::arm_cfft_sR_q31_len1024,
-                2048 => &cmsis_dsp_sys::arm_cfft_sR_q31_len2048,
-                4096 => &cmsis_dsp_sys::arm_cfft_sR_q31_len4096,
-                _ => return Err(Error::Argument),
-            }
-        };
+        let instance = cfft_instance_q31(size.into()).ok_or(Error::Argument)?;
         Ok(Q31Fft { instance })
     }
 
+    /// Creates an FFT with a size known at compile time
+    ///
+    /// This can be used to initialize a `static`. Valid sizes are the same as for `new`.
+    /// Using any other size causes a compile error.
+    pub const fn with_size<const N: usize>() -> Self {
+        let () = FftSize::<N>::SUPPORTED;
+        match cfft_instance_q31(N) {
+            Some(instance) => Q31Fft { instance },
+            None => panic!("Unsupported FFT size"),
+        }
+    }
+
     /// Runs the FFT in-place on a buffer of values
-    pub fn run(
+    pub fn run<C: ComplexLayout<Scalar = I1F31>>(
         &self,
-        data: &mut [Complex<I1F31>],
+        data: &mut [C],
         direction: Direction,
         output_order: OutputOrder,
     ) {
         unsafe {
             // FFT size is number of complex values. arm_cfft_q31 expects size * 2 u32 values.
-            // Complex<I1F31> is layout-compatible.
-            check_fft_size((*self.instance).fftLen, data.len());
+            // Every complex type is layout-compatible.
+            check_fft_size(self.instance.fftLen, data.len());
             cmsis_dsp_sys::arm_cfft_q31(
                 self.instance,
                 data.as_mut_ptr() as *mut _,
@@ -454,6 +879,58 @@ impl Q31Fft {
             );
         }
     }
+
+    /// Reorders the output of an FFT that was run with `OutputOrder::Raw` into the standard
+    /// order
+    ///
+    /// This performs the bit-reversal step that the FFT skips when the raw output order is
+    /// used, so the reordering can be done later, outside a time-critical section.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if data has a length not equal to the size of this FFT.
+    pub fn bit_reverse<C: ComplexLayout<Scalar = I1F31>>(&self, data: &mut [C]) {
+        unsafe {
+            let instance = self.instance;
+            check_fft_size(instance.fftLen, data.len());
+            bit_reverse(data, instance.pBitRevTable, instance.bitRevLength);
+        }
+    }
+}
+
+/// Applies a CMSIS-DSP bit-reversal table to the output of a complex FFT
+///
+/// Each pair of table entries contains the offsets of two complex values to swap. This is
+/// equivalent to the `arm_bitreversal_16`, `arm_bitreversal_32`, and `arm_bitreversal_64`
+/// functions, which CMSIS-DSP does not export.
+///
+/// # Safety
+///
+/// table must point to a CMSIS-DSP bit-reversal table with at least length entries that
+/// corresponds to the length of data.
+unsafe fn bit_reverse<T>(data: &mut [T], table: *const u16, length: u16) {
+    let table = core::slice::from_raw_parts(table, length.into());
+    for pair in table.chunks_exact(2) {
+        // CMSIS-DSP shifts each offset right by 2 to get the index of a real part in an array
+        // of scalars, and each complex value contains two scalars
+        let a = usize::from(pair[0]) >> 3;
+        let b = usize::from(pair[1]) >> 3;
+        data.swap(a, b);
+    }
+}
+
+/// Copies input into the beginning of buffer and sets the remaining elements of buffer to zero
+///
+/// # Panics
+///
+/// This function panics if input is longer than buffer.
+fn zero_pad<T: Copy>(input: &[T], buffer: &mut [T], zero: T) {
+    assert!(input.len() <= buffer.len(), "Input longer than FFT buffer");
+    let (start, rest) = buffer.split_at_mut(input.len());
+    start.copy_from_slice(input);
+    for value in rest {
+        *value = zero;
+    }
 }
 
 /// Checks that an FFT size is equal to the number of values in an input or output slice
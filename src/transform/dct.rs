@@ -0,0 +1,119 @@
+//! Discrete cosine transforms
+
+use core::mem::MaybeUninit;
+
+#[cfg(feature = "fixed")]
+use fixed::types::{I1F15, I1F31};
+
+use super::check_fft_size;
+use crate::{Result, StatusCode};
+
+/// Defines a DCT4 type for one element type
+macro_rules! dct4 {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $element:ty, $instance:ident, $rfft:ident, $cfft:ident, $init:ident,
+        $run:ident, $to_bits:expr $(, $rfft_cfft_field:ident)?
+    ) => {
+        $(#[$attr])*
+        ///
+        /// Valid lengths are 128, 512, 2048, and 8192.
+        pub struct $name {
+            /// The CMSIS-DSP DCT4 instance
+            instance: cmsis_dsp_sys::$instance,
+            /// The real FFT instance that the DCT4 instance uses
+            rfft: cmsis_dsp_sys::$rfft,
+            /// The complex FFT instance that the DCT4 instance uses
+            cfft: cmsis_dsp_sys::$cfft,
+        }
+
+        unsafe impl Send for $name {}
+
+        impl $name {
+            /// Initializes a DCT4 with the specified length and normalization factor
+            ///
+            /// The output of the transform is multiplied by normalize. With a normalization
+            /// factor of `sqrt(2 / length)`, the transform is its own inverse.
+            ///
+            /// This function returns an error if the length is not valid.
+            pub fn new(length: u16, normalize: $element) -> Result<Self> {
+                unsafe {
+                    let mut instance = MaybeUninit::<cmsis_dsp_sys::$instance>::zeroed();
+                    let mut rfft = MaybeUninit::<cmsis_dsp_sys::$rfft>::zeroed();
+                    let mut cfft = MaybeUninit::<cmsis_dsp_sys::$cfft>::zeroed();
+                    cmsis_dsp_sys::$init(
+                        instance.as_mut_ptr(),
+                        rfft.as_mut_ptr(),
+                        cfft.as_mut_ptr(),
+                        length,
+                        length / 2,
+                        ($to_bits)(normalize),
+                    )
+                    .check_status()?;
+                    Ok($name {
+                        instance: instance.assume_init(),
+                        rfft: rfft.assume_init(),
+                        cfft: cfft.assume_init(),
+                    })
+                }
+            }
+
+            /// Returns the length of this transform
+            pub fn length(&self) -> usize {
+                usize::from(self.instance.N)
+            }
+
+            /// Runs the transform in place on a buffer of values
+            ///
+            /// state is used as temporary storage.
+            ///
+            /// # Panics
+            ///
+            /// This function panics if data or state has a length not equal to the length of
+            /// this transform.
+            pub fn run(&mut self, data: &mut [$element], state: &mut [$element]) {
+                check_fft_size(self.instance.N, data.len());
+                check_fft_size(self.instance.N, state.len());
+                // The instances refer to each other with pointers, which become invalid when
+                // this struct moves
+                self.instance.pRfft = &mut self.rfft;
+                self.instance.pCfft = &mut self.cfft;
+                $(self.rfft.$rfft_cfft_field = &mut self.cfft;)?
+                unsafe {
+                    cmsis_dsp_sys::$run(
+                        &self.instance,
+                        state.as_mut_ptr() as *mut _,
+                        data.as_mut_ptr() as *mut _,
+                    );
+                }
+            }
+        }
+    };
+}
+
+dct4! {
+    /// Runs a type IV discrete cosine transform on floating-point values
+    Dct4F32, f32, arm_dct4_instance_f32, arm_rfft_instance_f32, arm_cfft_radix4_instance_f32,
+    arm_dct4_init_f32, arm_dct4_f32, core::convert::identity, pCfft
+}
+#[cfg(feature = "fixed")]
+dct4! {
+    /// Runs a type IV discrete cosine transform on Q1.31 fixed-point values
+    ///
+    /// To avoid overflow, the transform scales down intermediate values. The output values
+    /// have the same representation as the input values, but a different format that depends
+    /// on the length: Q8.24 for length 128, Q10.22 for 512, Q12.20 for 2048, and Q14.18 for
+    /// 8192.
+    Dct4Q31, I1F31, arm_dct4_instance_q31, arm_rfft_instance_q31, arm_cfft_radix4_instance_q31,
+    arm_dct4_init_q31, arm_dct4_q31, I1F31::to_bits
+}
+#[cfg(feature = "fixed")]
+dct4! {
+    /// Runs a type IV discrete cosine transform on Q1.15 fixed-point values
+    ///
+    /// To avoid overflow, the transform scales down intermediate values. The output values
+    /// have the same representation as the input values, but a different format that depends
+    /// on the length: Q8.8 for length 128, Q10.6 for 512, Q12.4 for 2048, and Q14.2 for 8192.
+    Dct4Q15, I1F15, arm_dct4_instance_q15, arm_rfft_instance_q15, arm_cfft_radix4_instance_q15,
+    arm_dct4_init_q15, arm_dct4_q15, I1F15::to_bits
+}
@@ -0,0 +1,134 @@
+//! FFTs with half-precision floating-point input and output
+//!
+//! CMSIS-DSP 5.7.0 does not have half-precision FFTs. These types are wrappers around the
+//! single-precision FFTs: they convert the input to single precision in a caller-provided
+//! scratch buffer, run the single-precision transform, and convert the results back to half
+//! precision. They save memory only for the data that the caller keeps between transforms. The
+//! scratch buffer holds the whole transform in single precision, so these types are not faster
+//! than the single-precision FFTs and do not use less working memory.
+
+use super::{Direction, FloatFft, FloatRealFft, OutputOrder};
+use crate::complex::ComplexLayout;
+use crate::Result;
+use half::f16;
+
+/// Runs an FFT on half-precision floating-point complex numbers, using single precision for the
+/// calculation
+///
+/// The scratch buffer contains one single-precision complex number for each input value, so
+/// it takes twice as much memory as the data.
+pub struct FloatFftF16Io {
+    /// The single-precision FFT
+    fft: FloatFft,
+}
+
+impl FloatFftF16Io {
+    /// Initializes an FFT with the specified size
+    ///
+    /// Valid sizes are the same as for [`FloatFft`](super::FloatFft). This function returns
+    /// an error if the size value is not valid.
+    pub fn new(size: u16) -> Result<Self> {
+        Ok(FloatFftF16Io {
+            fft: FloatFft::new(size)?,
+        })
+    }
+
+    /// Runs the FFT in-place on a buffer of values
+    ///
+    /// scratch is used to calculate the transform in single precision.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if data or scratch has a length not equal to the size of this FFT.
+    pub fn run<C, S>(
+        &self,
+        data: &mut [C],
+        scratch: &mut [S],
+        direction: Direction,
+        output_order: OutputOrder,
+    ) where
+        C: ComplexLayout<Scalar = f16>,
+        S: ComplexLayout<Scalar = f32>,
+    {
+        assert_eq!(data.len(), scratch.len(), "Scratch length incorrect");
+        for (dst, src) in scratch.iter_mut().zip(data.iter()) {
+            *dst = S::from_parts(src.re().to_f32(), src.im().to_f32());
+        }
+        self.fft.run(scratch, direction, output_order);
+        for (dst, src) in data.iter_mut().zip(scratch.iter()) {
+            *dst = C::from_parts(f16::from_f32(src.re()), f16::from_f32(src.im()));
+        }
+    }
+}
+
+/// Runs an FFT on half-precision floating-point real numbers, using single precision for the
+/// calculation
+///
+/// The scratch buffer contains two single-precision values for each input value, one for the
+/// input and one for the output of the single-precision transform, so it takes four times as
+/// much memory as the input.
+pub struct FloatRealFftF16Io {
+    /// The single-precision FFT
+    fft: FloatRealFft,
+}
+
+impl FloatRealFftF16Io {
+    /// Initializes an FFT with the specified size
+    ///
+    /// Valid sizes are the same as for [`FloatRealFft`](super::FloatRealFft). This function
+    /// returns an error if the size value is not valid.
+    pub fn new(size: u16) -> Result<Self> {
+        Ok(FloatRealFftF16Io {
+            fft: FloatRealFft::new(size)?,
+        })
+    }
+
+    /// Runs a forward FFT on a set of values, placing the results in output
+    ///
+    /// The output has the same format as the output of `FloatRealFft::run`. scratch is used to
+    /// calculate the transform in single precision, and its length must be twice the size of
+    /// this FFT.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input or output has a length not equal to the size of this FFT,
+    /// or if scratch has the wrong length.
+    pub fn run(&self, input: &[f16], output: &mut [f16], scratch: &mut [f32]) {
+        self.run_inner(input, output, scratch, Direction::Forward)
+    }
+
+    /// Runs an inverse FFT on a set of values, placing the results in output
+    ///
+    /// scratch is used to calculate the transform in single precision, and its length must be
+    /// twice the size of this FFT.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input or output has a length not equal to the size of this FFT,
+    /// or if scratch has the wrong length.
+    pub fn run_inverse(&self, input: &[f16], output: &mut [f16], scratch: &mut [f32]) {
+        self.run_inner(input, output, scratch, Direction::Inverse)
+    }
+
+    fn run_inner(
+        &self,
+        input: &[f16],
+        output: &mut [f16],
+        scratch: &mut [f32],
+        direction: Direction,
+    ) {
+        assert_eq!(scratch.len(), input.len() * 2, "Scratch length incorrect");
+        let (scratch_in, scratch_out) = scratch.split_at_mut(input.len());
+        for (dst, src) in scratch_in.iter_mut().zip(input.iter()) {
+            *dst = src.to_f32();
+        }
+        match direction {
+            Direction::Forward => self.fft.run(scratch_in, scratch_out),
+            Direction::Inverse => self.fft.run_inverse(scratch_in, scratch_out),
+        }
+        assert_eq!(output.len(), scratch_out.len(), "Output length incorrect");
+        for (dst, &src) in output.iter_mut().zip(scratch_out.iter()) {
+            *dst = f16::from_f32(src);
+        }
+    }
+}
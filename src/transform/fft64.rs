@@ -0,0 +1,151 @@
+//! Double-precision floating-point FFTs
+//!
+//! These transforms are only available when the `double-precision-fpu` feature is enabled, like
+//! the double-precision functions in the basic module. Without a double-precision floating point
+//! unit, the operations would be emulated in software and would be much slower than the
+//! single-precision transforms.
+
+use core::mem::MaybeUninit;
+
+use super::{bit_reverse, cfft_instance_f64, check_fft_size, Direction, FftSize, OutputOrder};
+use crate::complex::ComplexLayout;
+use crate::{Error, Result, StatusCode};
+
+/// Runs an FFT on double-precision floating-point complex numbers
+pub struct FloatFft64 {
+    /// Data used by the CMSIS-DSP code
+    instance: &'static cmsis_dsp_sys::arm_cfft_instance_f64,
+}
+
+unsafe impl Send for FloatFft64 {}
+// The instance and the tables that it refers to are static and never modified
+unsafe impl Sync for FloatFft64 {}
+
+impl FloatFft64 {
+    /// Initializes an FFT with the specified size
+    ///
+    /// Valid size values are 16, 32, 64, 128, 256, 512, 1024, 2048, and 4096. This function
+    /// returns an error if the size value is not valid.
+    pub fn new(size: u16) -> Result<Self> {
+        let instance = cfft_instance_f64(size.into()).ok_or(Error::Argument)?;
+        Ok(FloatFft64 { instance })
+    }
+
+    /// Creates an FFT with a size known at compile time
+    ///
+    /// Because this is a `const fn`, it can be used to initialize a `static`. Valid sizes are
+    /// the same as for `new`. Using any other size causes a compile error.
+    pub const fn with_size<const N: usize>() -> Self {
+        let () = FftSize::<N>::SUPPORTED;
+        match cfft_instance_f64(N) {
+            Some(instance) => FloatFft64 { instance },
+            None => panic!("Unsupported FFT size"),
+        }
+    }
+
+    /// Runs the FFT in-place on a buffer of values
+    ///
+    /// # Panics
+    ///
+    /// This function panics if data has a length not equal to the size of this FFT.
+    pub fn run<C: ComplexLayout<Scalar = f64>>(
+        &self,
+        data: &mut [C],
+        direction: Direction,
+        output_order: OutputOrder,
+    ) {
+        unsafe {
+            // Every complex type is layout-compatible with two f64 values
+            check_fft_size(self.instance.fftLen, data.len());
+            cmsis_dsp_sys::arm_cfft_f64(
+                self.instance,
+                data.as_mut_ptr() as *mut _,
+                direction as _,
+                output_order as _,
+            );
+        }
+    }
+
+    /// Reorders the output of an FFT that was run with `OutputOrder::Raw` into the standard
+    /// order
+    ///
+    /// This performs the bit-reversal step that the FFT skips when the raw output order is
+    /// used, so the reordering can be done later, outside a time-critical section.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if data has a length not equal to the size of this FFT.
+    pub fn bit_reverse<C: ComplexLayout<Scalar = f64>>(&self, data: &mut [C]) {
+        unsafe {
+            let instance = self.instance;
+            check_fft_size(instance.fftLen, data.len());
+            bit_reverse(data, instance.pBitRevTable, instance.bitRevLength);
+        }
+    }
+}
+
+/// Runs an FFT on double-precision floating-point real numbers
+///
+/// As with [`FloatRealFft`](super::FloatRealFft), cloning an FFT is cheap and several channels
+/// can share one FFT through a reference.
+pub struct FloatRealFft64(cmsis_dsp_sys::arm_rfft_fast_instance_f64);
+
+unsafe impl Send for FloatRealFft64 {}
+// The CMSIS-DSP functions only read the instance and the tables that it refers to
+unsafe impl Sync for FloatRealFft64 {}
+
+impl Clone for FloatRealFft64 {
+    fn clone(&self) -> Self {
+        // The instance contains only integers and pointers to static tables
+        FloatRealFft64(unsafe { core::ptr::read(&self.0) })
+    }
+}
+
+impl FloatRealFft64 {
+    /// Initializes an FFT with the specified size
+    ///
+    /// Valid size values are 32, 64, 128, 256, 512, 1024, 2048, and 4096. This function returns
+    /// an error if the size value is not valid.
+    pub fn new(size: u16) -> Result<Self> {
+        let mut data = MaybeUninit::<cmsis_dsp_sys::arm_rfft_fast_instance_f64>::uninit();
+        unsafe {
+            cmsis_dsp_sys::arm_rfft_fast_init_f64(data.as_mut_ptr(), size).check_status()?;
+            Ok(FloatRealFft64(data.assume_init()))
+        }
+    }
+
+    /// Runs a forward FFT on a set of values, placing the results in output
+    ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input or output has a length not equal to the size of this FFT.
+    pub fn run(&self, input: &mut [f64], output: &mut [f64]) {
+        self.run_inner(input, output, Direction::Forward);
+    }
+
+    /// Runs an inverse FFT on a set of values, placing the results in output
+    ///
+    /// The transform uses input as temporary storage, so its contents are changed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input or output has a length not equal to the size of this FFT.
+    pub fn run_inverse(&self, input: &mut [f64], output: &mut [f64]) {
+        self.run_inner(input, output, Direction::Inverse);
+    }
+
+    fn run_inner(&self, input: &mut [f64], output: &mut [f64], direction: Direction) {
+        check_fft_size(self.0.fftLenRFFT, input.len());
+        check_fft_size(self.0.fftLenRFFT, output.len());
+        unsafe {
+            cmsis_dsp_sys::arm_rfft_fast_f64(
+                &self.0 as *const _ as *mut _,
+                input.as_mut_ptr(),
+                output.as_mut_ptr(),
+                direction as _,
+            );
+        }
+    }
+}
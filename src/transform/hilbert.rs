@@ -0,0 +1,67 @@
+//! Hilbert transforms
+
+use super::{Direction, FloatFft, OutputOrder};
+use crate::complex::ComplexLayout;
+use crate::{check_length, Result};
+
+/// Calculates the analytic signal of blocks of real floating-point samples
+///
+/// The real part of the analytic signal is the input signal, and the imaginary part is its
+/// Hilbert transform. The magnitude of each output value is the instantaneous amplitude
+/// (envelope) of the input, and the argument is the instantaneous phase.
+///
+/// This uses the FFT method: it calculates the spectrum of the input, sets the negative
+/// frequency components to zero and doubles the positive frequency components, and calculates
+/// the inverse FFT. Each block is processed independently, so values near the ends of a block
+/// are less accurate. Windowing or overlapping blocks can reduce this effect.
+pub struct AnalyticSignalF32 {
+    /// The FFT used in both directions
+    fft: FloatFft,
+    /// The number of samples in each block
+    size: usize,
+}
+
+impl AnalyticSignalF32 {
+    /// Creates an analytic signal generator that processes blocks of the specified size
+    ///
+    /// Valid sizes are the same as for [`FloatFft`](super::FloatFft). This function returns
+    /// an error if the size is not valid.
+    pub fn new(size: u16) -> Result<Self> {
+        Ok(AnalyticSignalF32 {
+            fft: FloatFft::new(size)?,
+            size: usize::from(size),
+        })
+    }
+
+    /// Returns the number of samples in each block
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Calculates the analytic signal of a block of samples
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input and output do not have the same length, or if the length
+    /// is not equal to the size of this generator.
+    pub fn process<C: ComplexLayout<Scalar = f32>>(&self, input: &[f32], output: &mut [C]) {
+        let _: u32 = check_length((input.len(), output.len()));
+        for (dst, &src) in output.iter_mut().zip(input.iter()) {
+            *dst = C::from_parts(src, 0.0);
+        }
+        self.fft
+            .run(output, Direction::Forward, OutputOrder::Standard);
+        // Keep the DC and Nyquist bins, double the positive frequencies, and remove the
+        // negative frequencies
+        let half = self.size / 2;
+        for bin in &mut output[1..half] {
+            *bin = C::from_parts(2.0 * bin.re(), 2.0 * bin.im());
+        }
+        for bin in &mut output[half + 1..] {
+            *bin = C::from_parts(0.0, 0.0);
+        }
+        // The inverse FFT includes the 1 / size scaling
+        self.fft
+            .run(output, Direction::Inverse, OutputOrder::Standard);
+    }
+}
@@ -0,0 +1,230 @@
+//! Mel-frequency cepstral coefficients
+
+use core::f32::consts::PI;
+
+use super::FloatRealFft;
+use crate::basic::dot_product_f32;
+use crate::fast_math::cos_f32;
+use crate::statistics::absmax_f32;
+use crate::{Error, Result};
+
+/// A value added to the mel filter outputs before calculating their logarithms, to avoid
+/// taking the logarithm of zero
+const LOG_OFFSET: f32 = 1.0e-6;
+
+/// A bank of filters on the mel frequency scale
+///
+/// Each filter applies to a range of consecutive FFT magnitude bins. Filter i starts at bin
+/// `positions[i]` and covers `lengths[i]` bins. The coefficients of all filters are stored
+/// one filter after another in coefficients.
+///
+/// These tables are usually generated offline, for example with the script that CMSIS-DSP
+/// provides for its MFCC functions.
+#[derive(Debug, Copy, Clone)]
+pub struct MelFilterBank<'a> {
+    /// The first FFT bin of each filter
+    positions: &'a [u32],
+    /// The number of FFT bins that each filter covers
+    lengths: &'a [u32],
+    /// The coefficients of all filters
+    coefficients: &'a [f32],
+}
+
+impl<'a> MelFilterBank<'a> {
+    /// Creates a filter bank
+    ///
+    /// This function returns an error if there are no filters, if positions and lengths do not
+    /// have the same length, or if the length of coefficients is not equal to the sum of the
+    /// filter lengths.
+    pub fn new(positions: &'a [u32], lengths: &'a [u32], coefficients: &'a [f32]) -> Result<Self> {
+        if positions.is_empty() || positions.len() != lengths.len() {
+            return Err(Error::Length);
+        }
+        let total_length = lengths
+            .iter()
+            .try_fold(0usize, |sum, &length| sum.checked_add(length as usize));
+        if total_length != Some(coefficients.len()) {
+            return Err(Error::Length);
+        }
+        Ok(MelFilterBank {
+            positions,
+            lengths,
+            coefficients,
+        })
+    }
+
+    /// Returns the number of filters
+    pub fn filters(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns an iterator over the first bin and the coefficients of each filter
+    fn iter(&self) -> impl Iterator<Item = (usize, &'a [f32])> + '_ {
+        let mut coefficients = self.coefficients;
+        self.positions
+            .iter()
+            .zip(self.lengths.iter())
+            .map(move |(&position, &length)| {
+                let (filter, rest) = coefficients.split_at(length as usize);
+                coefficients = rest;
+                (position as usize, filter)
+            })
+    }
+}
+
+/// Fills a matrix of discrete cosine transform coefficients for use with `MfccF32`
+///
+/// The matrix has one row for each output coefficient and one column for each mel filter, so
+/// the length of dst must be a multiple of filters. It contains the coefficients of an
+/// orthonormal DCT-II.
+///
+/// # Panics
+///
+/// This function panics if filters is zero or the length of dst is not a multiple of filters.
+pub fn mfcc_dct_coefficients(filters: usize, dst: &mut [f32]) {
+    assert_ne!(filters, 0, "Number of filters is zero");
+    assert!(
+        dst.len() % filters == 0,
+        "DCT matrix length not a multiple of the number of filters"
+    );
+    let n = filters as f32;
+    let first_scale = unsafe { crate::sqrtf(1.0 / n) };
+    let scale = unsafe { crate::sqrtf(2.0 / n) };
+    for (i, row) in dst.chunks_mut(filters).enumerate() {
+        let row_scale = if i == 0 { first_scale } else { scale };
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = row_scale * cos_f32(PI / n * (j as f32 + 0.5) * i as f32);
+        }
+    }
+}
+
+/// Calculates mel-frequency cepstral coefficients (MFCCs) of floating-point samples
+///
+/// For each block of samples, this normalizes the samples, applies a window, calculates the
+/// magnitude spectrum, applies a mel filter bank, takes the logarithm of each filter output,
+/// and applies a discrete cosine transform.
+///
+/// These are the same steps as `arm_mfcc_f32` in later versions of CMSIS-DSP.
+pub struct MfccF32<'a> {
+    /// The real FFT
+    fft: FloatRealFft,
+    /// The number of samples in each block
+    fft_length: usize,
+    /// Window coefficients, one for each sample
+    window: &'a [f32],
+    /// The mel filter bank
+    filter_bank: MelFilterBank<'a>,
+    /// DCT coefficients, with one row for each output and one column for each mel filter
+    dct: &'a [f32],
+}
+
+impl<'a> MfccF32<'a> {
+    /// Creates an MFCC calculator
+    ///
+    /// fft_length is the number of samples in each block. It must be a power of two between
+    /// 32 and 4096. window must contain fft_length values. The length of dct must be the number
+    /// of outputs multiplied by the number of mel filters. `mfcc_dct_coefficients` can be used
+    /// to calculate the DCT coefficients.
+    ///
+    /// This function returns an error if fft_length is not supported, if window or dct has
+    /// the wrong length, if dct is empty, or if any mel filter covers bins beyond
+    /// `fft_length / 2`.
+    pub fn new(
+        fft_length: u16,
+        window: &'a [f32],
+        filter_bank: MelFilterBank<'a>,
+        dct: &'a [f32],
+    ) -> Result<Self> {
+        let fft = FloatRealFft::new(fft_length)?;
+        let fft_length = usize::from(fft_length);
+        let bins = fft_length / 2 + 1;
+        let filters_in_range = filter_bank
+            .iter()
+            .all(|(position, filter)| position + filter.len() <= bins);
+        let dct_valid = !dct.is_empty() && dct.len() % filter_bank.filters() == 0;
+        if window.len() != fft_length || !filters_in_range || !dct_valid {
+            return Err(Error::Length);
+        }
+        Ok(MfccF32 {
+            fft,
+            fft_length,
+            window,
+            filter_bank,
+            dct,
+        })
+    }
+
+    /// Returns the number of samples in each block
+    pub fn fft_length(&self) -> usize {
+        self.fft_length
+    }
+
+    /// Returns the number of coefficients calculated for each block
+    pub fn outputs(&self) -> usize {
+        self.dct.len() / self.filter_bank.filters()
+    }
+
+    /// Calculates the MFCCs of a block of samples
+    ///
+    /// input is used as temporary storage, so its contents are changed. scratch must have a
+    /// length of `fft_length + 2`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the length of input is not equal to the FFT length, the length
+    /// of output is not equal to the number of outputs, or scratch has the wrong length.
+    pub fn process(&self, input: &mut [f32], output: &mut [f32], scratch: &mut [f32]) {
+        let fft_length = self.fft_length;
+        assert_eq!(
+            input.len(),
+            fft_length,
+            "Input length not equal to FFT length"
+        );
+        assert_eq!(output.len(), self.outputs(), "Output length incorrect");
+        assert_eq!(scratch.len(), fft_length + 2, "Scratch length incorrect");
+
+        // Normalize and apply the window
+        let (max_value, _) = absmax_f32(input);
+        let scale = if max_value != 0.0 {
+            1.0 / max_value
+        } else {
+            1.0
+        };
+        for (sample, window) in input.iter_mut().zip(self.window.iter()) {
+            *sample *= scale * window;
+        }
+
+        // Calculate the magnitude spectrum, moving the Nyquist value (packed into the
+        // imaginary part of the DC bin) into its own bin
+        self.fft.run(input, &mut scratch[..fft_length]);
+        scratch[fft_length] = scratch[1];
+        scratch[fft_length + 1] = 0.0;
+        scratch[1] = 0.0;
+        let bins = fft_length / 2 + 1;
+        unsafe {
+            cmsis_dsp_sys::arm_cmplx_mag_f32(scratch.as_ptr(), input.as_mut_ptr(), bins as u32);
+        }
+        let magnitudes = &mut input[..bins];
+        if max_value != 0.0 {
+            for magnitude in magnitudes.iter_mut() {
+                *magnitude *= max_value;
+            }
+        }
+
+        // Apply the mel filters and take logarithms
+        let filters = self.filter_bank.filters();
+        let mel = &mut scratch[..filters];
+        for (energy, (position, filter)) in mel.iter_mut().zip(self.filter_bank.iter()) {
+            *energy = dot_product_f32(&magnitudes[position..position + filter.len()], filter)
+                + LOG_OFFSET;
+        }
+        unsafe {
+            cmsis_dsp_sys::arm_vlog_f32(mel.as_ptr(), mel.as_mut_ptr(), filters as u32);
+        }
+
+        // Apply the DCT
+        for (out, row) in output.iter_mut().zip(self.dct.chunks(filters)) {
+            *out = dot_product_f32(row, mel);
+        }
+    }
+}
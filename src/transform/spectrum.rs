@@ -0,0 +1,96 @@
+//! Spectrum layout and frequency helpers
+
+use crate::complex::ComplexLayout;
+
+/// Rearranges a complex spectrum so that the zero-frequency bin is in the center
+///
+/// The input is in the order produced by a complex FFT with the standard output order:
+/// DC, positive frequencies, and then negative frequencies. The output has the negative
+/// frequencies first, then DC, then the positive frequencies. For a spectrum of length n, the
+/// DC bin moves to index n / 2.
+pub fn fft_shift<T>(spectrum: &mut [T]) {
+    let half = spectrum.len() / 2;
+    spectrum.rotate_right(half);
+}
+
+/// Reverses the effect of `fft_shift`, so that the zero-frequency bin is first
+///
+/// For a spectrum with an even length this is the same as `fft_shift`.
+pub fn ifft_shift<T>(spectrum: &mut [T]) {
+    let half = spectrum.len() / 2;
+    spectrum.rotate_left(half);
+}
+
+/// Returns the frequency of a bin in the output of a complex FFT
+///
+/// Bins in the first half of the spectrum have non-negative frequencies, and bins in the second
+/// half have negative frequencies. The frequency has the same units as sample_rate.
+///
+/// For a real FFT, the bins from 0 to fft_size / 2 - 1 have the same frequencies. Bin
+/// fft_size / 2 of a real FFT is at the Nyquist frequency, sample_rate / 2, but this function
+/// returns -sample_rate / 2 for it because it is the first negative-frequency bin of a complex
+/// FFT.
+///
+/// # Panics
+///
+/// This function panics if bin is not less than fft_size.
+pub fn bin_frequency(bin: usize, fft_size: usize, sample_rate: f32) -> f32 {
+    assert!(bin < fft_size, "Bin index out of range");
+    let resolution = sample_rate / fft_size as f32;
+    if bin < fft_size.div_ceil(2) {
+        bin as f32 * resolution
+    } else {
+        -((fft_size - bin) as f32) * resolution
+    }
+}
+
+/// Returns the index of the complex FFT bin with the frequency closest to a frequency
+///
+/// Negative frequencies map to bins in the second half of the spectrum. For a real FFT, a
+/// non-negative frequency maps to a bin from 0 to fft_size / 2 (inclusive), which can be read
+/// from packed output using `packed_bin`.
+///
+/// This function returns None if fft_size is zero or if the frequency is NaN or outside the
+/// range from -sample_rate / 2 to sample_rate / 2.
+pub fn frequency_bin(frequency: f32, fft_size: usize, sample_rate: f32) -> Option<usize> {
+    if fft_size == 0 {
+        return None;
+    }
+    let position = frequency * fft_size as f32 / sample_rate;
+    let half = fft_size as f32 / 2.0;
+    if position.is_nan() || position.abs() > half {
+        return None;
+    }
+    // Round to the nearest bin, but do not wrap around past the Nyquist frequency
+    let offset = ((position.abs() + 0.5) as usize).min(fft_size / 2);
+    if position < 0.0 && offset != 0 {
+        Some(fft_size - offset)
+    } else {
+        Some(offset)
+    }
+}
+
+/// Returns one bin of the packed output of a floating-point real FFT
+///
+/// The bin can be returned as any complex type, such as `[f32; 2]`.
+///
+/// A real FFT of size n produces n values. Elements 0 and 1 are the real parts of bins 0 (DC)
+/// and n / 2 (the Nyquist frequency), whose imaginary parts are always zero. For every other
+/// bin k from 1 to n / 2 - 1, elements 2k and 2k + 1 are the real and imaginary parts.
+///
+/// # Panics
+///
+/// This function panics if the length of spectrum is odd or if bin is greater than half the
+/// length of spectrum.
+pub fn packed_bin<C: ComplexLayout<Scalar = f32>>(spectrum: &[f32], bin: usize) -> C {
+    assert!(spectrum.len() % 2 == 0, "Spectrum length is odd");
+    let half = spectrum.len() / 2;
+    assert!(bin <= half, "Bin index out of range");
+    if bin == 0 {
+        C::from_parts(spectrum[0], 0.0)
+    } else if bin == half {
+        C::from_parts(spectrum[1], 0.0)
+    } else {
+        C::from_parts(spectrum[2 * bin], spectrum[2 * bin + 1])
+    }
+}
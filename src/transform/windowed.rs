@@ -0,0 +1,80 @@
+//! Windowed FFTs
+
+use core::convert::TryInto;
+
+use super::FloatRealFft;
+use crate::{check_length, Error, Result};
+
+/// Multiplies blocks of floating-point samples by a window and runs a real FFT on them
+///
+/// The window can be filled using [`fill_window`](crate::window::fill_window). The output is
+/// in the same packed format as the output of [`FloatRealFft`].
+pub struct WindowedFft<W> {
+    /// The window values, one for each sample
+    window: W,
+    /// The real FFT
+    fft: FloatRealFft,
+}
+
+impl<W> WindowedFft<W>
+where
+    W: AsRef<[f32]>,
+{
+    /// Creates a windowed FFT
+    ///
+    /// The FFT size is the length of the window. Valid sizes are the same as for
+    /// [`FloatRealFft`]. This function returns an error if the size is not valid.
+    pub fn new(window: W) -> Result<Self> {
+        let size: u16 = window
+            .as_ref()
+            .len()
+            .try_into()
+            .map_err(|_| Error::Argument)?;
+        let fft = FloatRealFft::new(size)?;
+        Ok(WindowedFft { window, fft })
+    }
+
+    /// Multiplies a block of samples by the window and runs a forward FFT on the result,
+    /// placing the spectrum in output
+    ///
+    /// scratch holds the windowed samples, and the FFT also uses it as temporary storage. input,
+    /// scratch, and output must all have the same length as the window.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if input, scratch, or output has a length not equal to the size of
+    /// this FFT.
+    pub fn process(&self, input: &[f32], scratch: &mut [f32], output: &mut [f32]) {
+        let window = self.window.as_ref();
+        let length = check_length((input.len(), window.len(), scratch.len()));
+        unsafe {
+            cmsis_dsp_sys::arm_mult_f32(
+                input.as_ptr(),
+                window.as_ptr(),
+                scratch.as_mut_ptr(),
+                length,
+            );
+        }
+        self.fft.run(scratch, output);
+    }
+
+    /// Returns the number of samples in each block
+    pub fn size(&self) -> usize {
+        self.window.as_ref().len()
+    }
+
+    /// Returns the window values
+    pub fn window(&self) -> &[f32] {
+        self.window.as_ref()
+    }
+
+    /// Returns the FFT that this windowed FFT uses
+    pub fn fft(&self) -> &FloatRealFft {
+        &self.fft
+    }
+
+    /// Releases the window
+    pub fn into_inner(self) -> W {
+        self.window
+    }
+}
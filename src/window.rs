@@ -0,0 +1,128 @@
+//! Window functions
+//!
+//! Windows are usually applied to a block of samples before an FFT, to reduce spectral
+//! leakage. For spectral analysis, use periodic windows. For filter design, use symmetric
+//! windows.
+
+use core::f32::consts::PI;
+
+use crate::check_length;
+use crate::fast_math::cos_f32;
+
+/// Window shapes
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Window {
+    /// All values are 1
+    Rectangular,
+    /// The Hann window, a raised cosine
+    Hann,
+    /// The Hamming window
+    Hamming,
+    /// The three-term Blackman window
+    Blackman,
+    /// The four-term Blackman-Harris window, which has very low sidelobes
+    BlackmanHarris,
+    /// The flat top window, which gives accurate amplitudes for sinusoids between bins
+    FlatTop,
+    /// The Bartlett (triangular) window
+    Bartlett,
+    /// The Welch (parabolic) window
+    Welch,
+}
+
+/// Window symmetry
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Symmetry {
+    /// A window of length N is the first N values of a symmetric window of length N + 1,
+    /// which is appropriate for spectral analysis with an FFT
+    Periodic,
+    /// The window is symmetric about its center, which is appropriate for filter design
+    Symmetric,
+}
+
+impl Window {
+    /// Returns the value of this window at position x, where x is in the range [0, 1]
+    fn value(&self, x: f32) -> f32 {
+        let cosine_sum = |coefficients: &[f32]| {
+            coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, coefficient)| {
+                    let term = coefficient * cos_f32(2.0 * PI * k as f32 * x);
+                    if k & 1 == 0 {
+                        term
+                    } else {
+                        -term
+                    }
+                })
+                .sum()
+        };
+        match self {
+            Window::Rectangular => 1.0,
+            Window::Hann => cosine_sum(&[0.5, 0.5]),
+            Window::Hamming => cosine_sum(&[0.54, 0.46]),
+            Window::Blackman => cosine_sum(&[0.42, 0.5, 0.08]),
+            Window::BlackmanHarris => cosine_sum(&[0.358_75, 0.488_29, 0.141_28, 0.011_68]),
+            Window::FlatTop => cosine_sum(&[
+                0.215_578_95,
+                0.416_631_58,
+                0.277_263_16,
+                0.083_578_95,
+                0.006_947_368,
+            ]),
+            Window::Bartlett => 1.0 - (2.0 * x - 1.0).abs(),
+            Window::Welch => 1.0 - (2.0 * x - 1.0) * (2.0 * x - 1.0),
+        }
+    }
+}
+
+/// Fills dst with a window
+///
+/// The length of the window is the length of dst.
+pub fn fill_window(window: Window, symmetry: Symmetry, dst: &mut [f32]) {
+    let denominator = match symmetry {
+        Symmetry::Periodic => dst.len(),
+        Symmetry::Symmetric => dst.len().saturating_sub(1),
+    };
+    if denominator == 0 {
+        // A window with one value
+        for value in dst.iter_mut() {
+            *value = 1.0;
+        }
+        return;
+    }
+    for (n, value) in dst.iter_mut().enumerate() {
+        *value = window.value(n as f32 / denominator as f32);
+    }
+}
+
+/// Returns a window with the provided length
+///
+/// This is the same as `fill_window`, but it allocates the values. This function is only
+/// available when the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn window_vec(window: Window, symmetry: Symmetry, length: usize) -> alloc::vec::Vec<f32> {
+    let mut values = alloc::vec![0.0; length];
+    fill_window(window, symmetry, &mut values);
+    values
+}
+
+/// Multiplies a block of samples by a window, in place
+///
+/// This is functionally equivalent to performing `signal[i] *= window[i]` for all values of i
+/// in range.
+///
+/// # Panics
+///
+/// This function panics if signal and window do not have the same length.
+pub fn apply_window(signal: &mut [f32], window: &[f32]) {
+    let length = check_length((signal.len(), window.len()));
+    unsafe {
+        cmsis_dsp_sys::arm_mult_f32(
+            signal.as_ptr(),
+            window.as_ptr(),
+            signal.as_mut_ptr(),
+            length,
+        );
+    }
+}